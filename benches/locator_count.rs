@@ -0,0 +1,55 @@
+//! Compares the round trip of `Locator::count`, which sends a single `queryCount`, with counting the element handles
+//! returned by `query_selector_all` on a 1000-element list.
+//!
+//! Run with `cargo bench --bench locator_count`. Needs the driver and Chromium, which are installed on first run.
+use playwright::{Error, Playwright};
+use std::time::{Duration, Instant};
+
+const ELEMENTS: usize = 1000;
+const ITERATIONS: u32 = 20;
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    let playwright = Playwright::initialize().await?;
+    playwright.install_chromium()?;
+    let browser = playwright
+        .chromium()
+        .launcher()
+        .headless(true)
+        .launch()
+        .await?;
+    let page = browser.new_page().await?;
+    let items = "<li>item</li>".repeat(ELEMENTS);
+    page.set_content_builder(&format!("<ul>{}</ul>", items))
+        .set_content()
+        .await?;
+
+    let (page, locator) = (&page, &page.locator("li")?);
+    let query_count = measure(|| async move {
+        assert_eq!(locator.count().await.unwrap(), ELEMENTS);
+    })
+    .await;
+    let handles = measure(|| async move {
+        assert_eq!(page.query_selector_all("li").await.unwrap().len(), ELEMENTS);
+    })
+    .await;
+
+    println!("queryCount:         {:?} per call", query_count);
+    println!("query_selector_all: {:?} per call", handles);
+    browser.close().await?;
+    Ok(())
+}
+
+async fn measure<F, Fut>(f: F) -> Duration
+where
+    F: Fn() -> Fut,
+    Fut: std::future::Future<Output = ()>,
+{
+    // warm up so the first call does not pay for selector engine initialization
+    f().await;
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f().await;
+    }
+    start.elapsed() / ITERATIONS
+}
@@ -42,7 +42,7 @@ impl FieldExtractor {
         page: &playwright::api::Page,
     ) -> Result<Vec<FormField>, Error> {
         // Create locator for all form input elements
-        let input_locator = page.locator("input, select, textarea").await?;
+        let input_locator = page.locator("input, select, textarea")?;
         let count = input_locator.count().await?;
 
         let mut fields = Vec::with_capacity(count);
@@ -110,7 +110,7 @@ impl FieldExtractor {
         page: &playwright::api::Page,
     ) -> Result<Vec<FormField>, Error> {
         // Create locator for all form input elements
-        let input_locator = page.locator("input, select, textarea").await?;
+        let input_locator = page.locator("input, select, textarea")?;
         let all_locators = input_locator.all().await?;
 
         let mut fields = Vec::with_capacity(all_locators.len());
@@ -167,9 +167,7 @@ impl FieldExtractor {
         page: &playwright::api::Page,
     ) -> Result<Vec<FormField>, Error> {
         // Get only visible, enabled input fields
-        let input_locator = page
-            .locator("input:visible, select:visible, textarea:visible")
-            .await?;
+        let input_locator = page.locator("input:visible, select:visible, textarea:visible")?;
         let visible_fields = input_locator.all().await?;
 
         let mut fields = Vec::new();
@@ -54,12 +54,14 @@ pub mod accessibility;
 pub mod browser;
 pub mod browser_context;
 pub mod browser_type;
+pub mod cdp_session;
 pub mod console_message;
 pub mod dialog;
 pub mod download;
 pub mod element_handle;
 pub mod file_chooser;
 pub mod frame;
+pub mod frame_locator;
 pub mod js_handle;
 pub mod locator;
 pub mod page;
@@ -67,6 +69,7 @@ pub mod request;
 pub mod response;
 pub mod route;
 pub mod selectors;
+pub mod service_worker;
 pub mod video;
 pub mod websocket;
 pub mod worker;
@@ -78,20 +81,23 @@ pub use accessibility::Accessibility;
 pub use browser::Browser;
 pub use browser_context::BrowserContext;
 pub use browser_type::BrowserType;
+pub use cdp_session::CdpSession;
 pub use console_message::ConsoleMessage;
 pub use dialog::Dialog;
 pub use download::Download;
 pub use element_handle::ElementHandle;
 pub use file_chooser::FileChooser;
 pub use frame::Frame;
-pub use input_device::{Keyboard, Mouse, TouchScreen};
+pub use frame_locator::FrameLocator;
+pub use input_device::{Key, Keyboard, Mouse, TouchScreen};
 pub use js_handle::JsHandle;
-pub use locator::Locator;
+pub use locator::{Locator, XPathFallbackMode};
 pub use page::Page;
 pub use request::Request;
 pub use response::Response;
 pub use route::Route;
 pub use selectors::Selectors;
+pub use service_worker::ServiceWorker;
 pub use video::Video;
 pub use websocket::WebSocket;
 pub use worker::Worker;
@@ -106,7 +112,6 @@ pub use worker::Worker;
 // androidsocket
 // androidwebview
 // browserserver
-// cdpsession
 // coverage
 // electron
 // electronapplication
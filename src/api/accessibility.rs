@@ -1,4 +1,6 @@
-pub use crate::imp::page::{AccessibilitySnapshotResponse as SnapshotResponse, Mixed, Val};
+#[allow(deprecated)]
+pub use crate::imp::page::AccessibilitySnapshotResponse as SnapshotResponse;
+pub use crate::imp::page::{AXNode, Mixed, Val};
 use crate::{
     api::ElementHandle,
     imp::{
@@ -68,6 +70,34 @@ impl Accessibility {
     pub fn snapshot_builder(&self) -> SnapshotBuilder {
         SnapshotBuilder::new(self.inner.clone())
     }
+
+    /// Captures the current state of the accessibility tree with `options`. See [`Accessibility::snapshot_builder`].
+    pub async fn snapshot(
+        &self,
+        options: AccessibilitySnapshotOptions<'_>,
+    ) -> Result<Option<AXNode>, Arc<Error>> {
+        let AccessibilitySnapshotOptions {
+            interesting_only,
+            root,
+        } = options;
+        let root = match root {
+            Some(x) => Some(OnlyGuid { guid: x.guid()? }),
+            None => None,
+        };
+        let args = SnapshotArgs {
+            interesting_only,
+            root,
+        };
+        upgrade(&self.inner)?.accessibility_snapshot(args).await
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AccessibilitySnapshotOptions<'a> {
+    /// Prune uninteresting nodes from the tree. Defaults to `true`.
+    pub interesting_only: Option<bool>,
+    /// The root DOM element for the snapshot. Defaults to the whole page.
+    pub root: Option<&'a ElementHandle>,
 }
 
 pub struct SnapshotBuilder {
@@ -81,7 +111,7 @@ impl SnapshotBuilder {
         Self { inner, args }
     }
 
-    pub async fn snapshot(self) -> ArcResult<Option<SnapshotResponse>> {
+    pub async fn snapshot(self) -> ArcResult<Option<AXNode>> {
         let Self { inner, args } = self;
         upgrade(&inner)?.accessibility_snapshot(args).await
     }
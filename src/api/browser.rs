@@ -1,16 +1,23 @@
-pub use crate::imp::browser_type::{RecordHar, RecordVideo};
+pub use crate::imp::{
+    browser::EventType,
+    browser_type::{RecordHar, RecordVideo},
+};
 use crate::{
-    api::BrowserContext,
+    api::{BrowserContext, Page},
     imp::{
         self,
-        browser::NewContextArgs,
+        browser::{Evt, NewContextArgs},
         core::*,
         playwright::DeviceDescriptor,
         prelude::*,
-        utils::{ColorScheme, Geolocation, HttpCredentials, ProxySettings, StorageState, Viewport},
+        utils::{
+            ColorScheme, Geolocation, HttpCredentials, ProxySettings, ServiceWorkerPolicy,
+            StorageState, Viewport,
+        },
     },
     Error,
 };
+use std::borrow::Cow;
 
 #[derive(Debug)]
 pub struct Browser {
@@ -59,12 +66,66 @@ impl Browser {
         self.inner.upgrade().is_some()
     }
 
+    /// Indicates that the browser is connected.
+    pub fn is_connected(&self) -> bool {
+        self.inner
+            .upgrade()
+            .map(|b| b.is_connected())
+            .unwrap_or_default()
+    }
+
+    /// Calls `handler` when Browser gets disconnected from the browser application. This might happen because of one of the
+    /// following:
+    /// - Browser application is closed or crashed.
+    /// - The [`method: Browser.close`] method was called.
+    pub async fn on_disconnected<F>(&self, handler: F) -> Result<(), Error>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let rx = upgrade(&self.inner)?.subscribe_event();
+        on_event(rx, move |e| match e {
+            Evt::Disconnected => handler(),
+        });
+        Ok(())
+    }
+
+    /// Same as [`Browser::on_disconnected`]; the browser is closed once it is disconnected.
+    pub async fn on_close<F>(&self, handler: F) -> Result<(), Error>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.on_disconnected(handler).await
+    }
+
+    subscribe_event! {}
+
     /// new_context [`BrowserContext`]
     /// Creates a new browser context. It won't share cookies/cache with other browser contexts.
     pub fn context_builder(&self) -> ContextBuilder<'_, '_, '_, '_, '_, '_, '_> {
         ContextBuilder::new(self.inner.clone())
     }
 
+    /// Creates a new context populated with the storage state saved at `path`, as returned by
+    /// [`BrowserContext::storage_state`] and serialized to JSON.
+    pub async fn new_context_with_storage_state(
+        &self,
+        path: &Path,
+    ) -> Result<BrowserContext, Error> {
+        let bytes = fs::read(path).await?;
+        let state: StorageState = serde_json::from_slice(&bytes)?;
+        Ok(self.context_builder().storage_state(state).build().await?)
+    }
+
+    /// Creates a new page in a new browser context. Closing this page will close the context as well.
+    ///
+    /// This is a convenience API that should only be used for the single-page scenarios and short snippets. Production code
+    /// and testing frameworks should explicitly create [`Browser::context_builder`] followed by the
+    /// [`BrowserContext::new_page`] to control their exact life times.
+    pub async fn new_page(&self) -> Result<Page, Arc<Error>> {
+        let p = upgrade(&self.inner)?.new_page().await?;
+        Ok(Page::new(p))
+    }
+
     /// All temporary browsers will be closed when the connection is terminated, but
     /// it needs to be called explicitly to close it at any given time.
     pub async fn close(&self) -> Result<(), Arc<Error>> {
@@ -106,8 +167,55 @@ impl<'e, 'f, 'g, 'h, 'i, 'j, 'k> ContextBuilder<'e, 'f, 'g, 'h, 'i, 'j, 'k> {
         DeviceDescriptor::set_context(device, self)
     }
 
+    /// Credentials for [HTTP authentication](https://developer.mozilla.org/en-US/docs/Web/HTTP/Authentication).
+    pub fn http_credentials(mut self, x: &'i HttpCredentials) -> Self {
+        self.args.http_credentials = Some(Cow::Borrowed(x));
+        self
+    }
+
+    /// Sends `username` and `password` for [HTTP authentication](https://developer.mozilla.org/en-US/docs/Web/HTTP/Authentication)
+    /// to any origin. Use [`ContextBuilder::http_credentials`] with [`HttpCredentials::with_origin`] to restrict them.
+    pub fn with_http_credentials(mut self, username: &str, password: &str) -> Self {
+        let credentials = HttpCredentials::new(username, password);
+        self.args.http_credentials = Some(Cow::Owned(credentials));
+        self
+    }
+
+    /// Records a [HAR](http://www.softwareishard.com/blog/har-12-spec) of all pages into `path`, which is written when
+    /// the context is closed with [`BrowserContext::close`]. A `.zip` path records a zipped HAR.
+    pub fn with_record_har_path(mut self, path: &'k Path) -> Self {
+        self.args
+            .record_har
+            .get_or_insert_with(|| RecordHar::new(path))
+            .path = path;
+        self
+    }
+
+    /// Only records requests whose url matches the glob `pattern` into the HAR set with
+    /// [`ContextBuilder::with_record_har_path`]. Has no effect unless a HAR path is set first.
+    pub fn with_record_har_url_filter(mut self, pattern: &'k str) -> Self {
+        if let Some(har) = &mut self.args.record_har {
+            har.url_filter = Some(pattern);
+        }
+        self
+    }
+
+    /// Emulates a `window.screen` of `width` x `height` pixels. Shortcut for [`ContextBuilder::screen`].
+    pub fn with_screen(mut self, width: i32, height: i32) -> Self {
+        self.args.screen = Some(Viewport { width, height });
+        self
+    }
+
+    pub fn clear_http_credentials(mut self) -> Self {
+        self.args.http_credentials = None;
+        self
+    }
+
     setter! {
-        /// Whether to automatically download all the attachments. Defaults to `false` where all the downloads are canceled.
+        /// Whether to automatically download all the attachments. Defaults to `true` where all the downloads are accepted.
+        /// They are saved into the directory the browser was launched with, see [`Launcher::with_downloads_path`].
+        ///
+        /// [`Launcher::with_downloads_path`]: crate::api::browser_type::Launcher::with_downloads_path
         accept_downloads: Option<bool>,
         /// Toggles bypassing page's Content-Security-Policy.
         bypass_csp: Option<bool>,
@@ -119,9 +227,8 @@ impl<'e, 'f, 'g, 'h, 'i, 'j, 'k> ContextBuilder<'e, 'f, 'g, 'h, 'i, 'j, 'k> {
         /// An object containing additional HTTP headers to be sent with every request. All header values must be strings.
         extra_http_headers: Option<HashMap<String, String>>,
         geolocation: Option<Geolocation>,
+        /// Specifies if viewport supports touch events. Defaults to false.
         has_touch: Option<bool>,
-        /// Credentials for [HTTP authentication](https://developer.mozilla.org/en-US/docs/Web/HTTP/Authentication).
-        http_credentials: Option<&'i HttpCredentials>,
         /// Whether to ignore HTTPS errors during navigation. Defaults to `false`.
         ignore_https_errors: Option<bool>,
         /// Whether the `meta viewport` tag is taken into account and touch events are enabled. Defaults to `false`. Not supported
@@ -141,6 +248,9 @@ impl<'e, 'f, 'g, 'h, 'i, 'j, 'k> ContextBuilder<'e, 'f, 'g, 'h, 'i, 'j, 'k> {
         /// Network proxy settings to use with this context. Note that browser needs to be launched with the global proxy for this
         /// option to work. If all contexts override the proxy, global proxy will be never used and can be any string, for example
         /// `launch({ proxy: { server: 'per-context' } })`.
+        ///
+        /// The proxy can't be changed afterwards or per page. To rotate proxies, open one context per proxy and create the
+        /// pages in the context of the proxy they should use.
         proxy: Option<ProxySettings>,
         /// Enables [HAR](http://www.softwareishard.com/blog/har-12-spec) recording for all pages into `recordHar.path` file. If not
         /// specified, the HAR is not recorded. Make sure to await [`method: BrowserContext.close`] for the HAR to be saved.
@@ -151,6 +261,9 @@ impl<'e, 'f, 'g, 'h, 'i, 'j, 'k> ContextBuilder<'e, 'f, 'g, 'h, 'i, 'j, 'k> {
         /// Emulates consistent window screen size available inside web page via `window.screen`. Is only used when the `viewport`
         /// is set.
         screen: Option<Viewport>,
+        /// Whether to allow sites to register Service workers. Defaults to [`ServiceWorkerPolicy::Allow`]. With
+        /// [`ServiceWorkerPolicy::Block`] no Service Worker is registered in this context.
+        service_workers: Option<ServiceWorkerPolicy>,
         /// Populates context with given storage state. This option can be used to initialize context with logged-in information
         /// obtained via [`method: BrowserContext.storageState`]. Either a path to the file with saved storage, or an object with
         /// the following fields:
@@ -167,3 +280,16 @@ impl<'e, 'f, 'g, 'h, 'i, 'j, 'k> ContextBuilder<'e, 'f, 'g, 'h, 'i, 'j, 'k> {
     ///// Logger sink for Playwright logging.
     // logger: Option<Logger>,
 }
+
+#[derive(Debug)]
+pub enum Event {
+    Disconnected,
+}
+
+impl From<Evt> for Event {
+    fn from(e: Evt) -> Self {
+        match e {
+            Evt::Disconnected => Self::Disconnected,
+        }
+    }
+}
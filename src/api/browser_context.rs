@@ -1,11 +1,16 @@
-pub use crate::imp::browser_context::EventType;
+pub use crate::{
+    api::route::{UnrouteAllOptions, UnrouteBehavior},
+    imp::browser_context::{ContextCloseOptions, EventType, RouteFromHarOptions},
+};
 use crate::{
-    api::{Browser, Page},
+    api::{
+        Browser, CdpSession, ConsoleMessage, Dialog, Page, Request, Response, Route, ServiceWorker,
+    },
     imp::{
         browser_context::{BrowserContext as Impl, Evt},
         core::*,
         prelude::*,
-        utils::{Cookie, Geolocation, StorageState},
+        utils::{Cookie, CookieFilter, Geolocation, StorageState},
     },
     Error,
 };
@@ -37,13 +42,21 @@ impl BrowserContext {
         Self { inner }
     }
 
-    /// Returns all open pages in the context.
+    /// Returns all open pages in the context, none once it is closed.
     pub fn pages(&self) -> Result<Vec<Page>, Error> {
+        let inner = match self.inner.upgrade() {
+            None => return Ok(Vec::new()),
+            Some(inner) => inner,
+        };
+        Ok(inner.pages().iter().cloned().map(Page::new).collect())
+    }
+
+    /// Returns all active Service Workers in the context. Only Chromium reports Service Workers.
+    pub fn service_workers(&self) -> Result<Vec<ServiceWorker>, Error> {
         Ok(upgrade(&self.inner)?
-            .pages()
-            .iter()
-            .cloned()
-            .map(Page::new)
+            .service_workers()
+            .into_iter()
+            .map(ServiceWorker::new)
             .collect())
     }
 
@@ -58,13 +71,13 @@ impl BrowserContext {
         Ok(Page::new(inner.new_page().await?))
     }
 
-    pub async fn set_default_navigation_timeout(&self, timeout: u32) -> ArcResult<()> {
+    pub async fn set_default_navigation_timeout(&self, timeout: f64) -> ArcResult<()> {
         upgrade(&self.inner)?
             .set_default_navigation_timeout(timeout)
             .await
     }
 
-    pub async fn set_default_timeout(&self, timeout: u32) -> ArcResult<()> {
+    pub async fn set_default_timeout(&self, timeout: f64) -> ArcResult<()> {
         upgrade(&self.inner)?.set_default_timeout(timeout).await
     }
 
@@ -81,7 +94,18 @@ impl BrowserContext {
 
     /// Clears context cookies.
     pub async fn clear_cookies(&self) -> ArcResult<()> {
-        upgrade(&self.inner)?.clear_cookies().await
+        upgrade(&self.inner)?
+            .clear_cookies(&CookieFilter::default())
+            .await
+    }
+
+    /// Clears the context cookies matching `filter`, keeping the others.
+    ///
+    /// ```js
+    /// await context.clearCookies({ domain: 'my-origin.com' });
+    /// ```
+    pub async fn clear_cookies_filter(&self, filter: CookieFilter) -> ArcResult<()> {
+        upgrade(&self.inner)?.clear_cookies(&filter).await
     }
 
     /// Grants specified permissions to the browser context. Only grants corresponding permissions to the given origin if
@@ -139,7 +163,8 @@ impl BrowserContext {
         upgrade(&self.inner)?.set_geolocation(geolocation).await
     }
 
-    /// Sets whether to emulate network being offline for the browser context.
+    /// Sets whether to emulate network being offline for the browser context. It applies to every page of the context;
+    /// the driver has no per-page switch, so use a separate context to take a single page offline.
     pub async fn set_offline(&self, offline: bool) -> ArcResult<()> {
         upgrade(&self.inner)?.set_offline(offline).await
     }
@@ -164,11 +189,20 @@ impl BrowserContext {
     ///  path: 'preload.js'
     /// });
     /// ```
-    /// > NOTE: The order of evaluation of multiple scripts installed via [`method: BrowserContext.addInitScript`] and
-    /// [`method: Page.addInitScript`] is not defined.
-    pub async fn add_init_script(&self, script: &str) -> ArcResult<()> {
+    /// Scripts of the context run before the ones added with [`method: Page.addInitScript`].
+    ///
+    /// When `path` is given, the content of the file is injected instead of `script`.
+    pub async fn add_init_script(&self, script: &str, path: Option<&Path>) -> Result<(), Error> {
         // arg not supported
-        upgrade(&self.inner)?.add_init_script(script).await
+        let source = match path {
+            Some(path) => format!(
+                "{}\n//# sourceURL={}",
+                fs::read_to_string(path).await?,
+                path.display()
+            ),
+            None => script.to_owned(),
+        };
+        Ok(upgrade(&self.inner)?.add_init_script(&source).await?)
     }
 
     /// The extra HTTP headers will be sent with every request initiated by any page in the context. These headers are merged
@@ -187,13 +221,57 @@ impl BrowserContext {
 
     // async fn expose_function(&mut self) -> Result<(), Error> { unimplemented!() }
 
-    // async fn route(&mut self) -> Result<(), Error> { unimplemented!() }
+    /// Routing provides the capability to modify network requests that are made by any page in the browser context. Once route
+    /// is enabled, every request matching the url pattern will stall unless it's continued, fulfilled or aborted.
+    ///
+    /// `url` is a glob pattern: `**` matches any characters, `*` matches any characters except `/`. `handler` is called
+    /// on the connection's event loop, so spawn a task for the async [`Route`] methods.
+    ///
+    /// > NOTE: Page routes (set up with [`method: Page.route`]) take precedence over browser context routes when
+    /// request matches both handlers.
+    pub async fn route<F>(&self, url: &str, handler: F) -> ArcResult<()>
+    where
+        F: Fn(Route) + Send + Sync + 'static,
+    {
+        let handler = Arc::new(move |r| handler(Route::new(r)));
+        upgrade(&self.inner)?.route(url, handler).await
+    }
+
+    /// Serves network requests of the pages in this context from the HAR file at `har_path`, e.g. one recorded with
+    /// [`ContextBuilder::with_record_har_path`](crate::api::browser::ContextBuilder::with_record_har_path). Requests
+    /// that have no entry in the HAR are aborted.
+    ///
+    /// With [`RouteFromHarOptions::update`], the requests are sent over the network and recorded into `har_path` instead;
+    /// the file is written on [`BrowserContext::close`].
+    pub async fn route_from_har(
+        &self,
+        har_path: &Path,
+        options: RouteFromHarOptions,
+    ) -> Result<(), Error> {
+        Ok(upgrade(&self.inner)?
+            .route_from_har(har_path, options)
+            .await?)
+    }
 
-    // async fn unroute(&mut self) -> Result<(), Error> { unimplemented!() }
+    /// Removes all routes created with [`BrowserContext::route`] for `url`.
+    pub async fn unroute(&self, url: &str) -> ArcResult<()> {
+        upgrade(&self.inner)?.unroute(url).await
+    }
+
+    /// Removes all routes created with [`BrowserContext::route`] and [`BrowserContext::route_from_har`].
+    pub async fn unroute_all(&self, options: UnrouteAllOptions) -> Result<(), Error> {
+        // no handler outlives its call, see `UnrouteBehavior`
+        let UnrouteAllOptions { behavior: _ } = options;
+        Ok(upgrade(&self.inner)?.unroute_all().await?)
+    }
 
     pub async fn expect_event(&self, evt: EventType) -> Result<Event, Error> {
-        let stream = upgrade(&self.inner)?.subscribe_event();
-        let timeout = upgrade(&self.inner)?.default_timeout();
+        let inner = upgrade(&self.inner)?;
+        if let Some(event) = evt.subscription() {
+            inner.subscribe(event).await?;
+        }
+        let stream = inner.subscribe_event();
+        let timeout = inner.default_timeout() as u32;
         expect_event(stream, evt, timeout).await.map(Event::from)
     }
 
@@ -207,29 +285,153 @@ impl BrowserContext {
     /// this struct has no Drop. it needs to be called explicitly to close it at any given time.
     /// > NOTE: The default browser context cannot be closed.
     pub async fn close(&self) -> ArcResult<()> {
+        self.close_with_options(ContextCloseOptions::default())
+            .await
+            .map_err(Arc::new)
+    }
+
+    /// Same as [`BrowserContext::close`], with a `reason` given to the operations it interrupts. Returns once every page
+    /// of the context has closed, after which [`BrowserContext::pages`] is empty and the context is no longer listed by
+    /// [`Browser::contexts`].
+    pub async fn close_with_options(&self, options: ContextCloseOptions) -> Result<(), Error> {
         let inner = match self.inner.upgrade() {
             None => return Ok(()),
             Some(inner) => inner,
         };
-        inner.close().await
+        inner.close(options).await.map_err(Error::unwrap_arc)
+    }
+
+    /// Whether the context has been closed, by [`BrowserContext::close`] or with its browser.
+    pub fn is_closed(&self) -> bool {
+        self.inner.upgrade().map(|c| c.is_closed()).unwrap_or(true)
     }
 
     subscribe_event! {}
 
+    /// Calls `handler` once the context is closed, either by [`BrowserContext::close`] or because the browser is closed or
+    /// crashed.
+    pub async fn on_close<F>(&self, handler: F) -> Result<(), Error>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let rx = upgrade(&self.inner)?.subscribe_event();
+        on_event(rx, move |e| {
+            if let Evt::Close = e {
+                handler();
+            }
+        });
+        Ok(())
+    }
+
+    /// Calls `handler` with every request that fails in any page of this context, for example by timing out or being
+    /// aborted. See [`Request::failure`] for the error text.
+    pub async fn on_request_failed<F>(&self, handler: F) -> Result<(), Error>
+    where
+        F: Fn(Request) + Send + Sync + 'static,
+    {
+        let inner = upgrade(&self.inner)?;
+        inner.subscribe("requestFailed").await?;
+        let rx = inner.subscribe_event();
+        on_event(rx, move |e| {
+            if let Evt::RequestFailed(x) = e {
+                handler(Request::new(x));
+            }
+        });
+        Ok(())
+    }
+
+    /// Calls `handler` with every request that finishes successfully after downloading the response body.
+    pub async fn on_request_finished<F>(&self, handler: F) -> Result<(), Error>
+    where
+        F: Fn(Request) + Send + Sync + 'static,
+    {
+        let inner = upgrade(&self.inner)?;
+        inner.subscribe("requestFinished").await?;
+        let rx = inner.subscribe_event();
+        on_event(rx, move |e| {
+            if let Evt::RequestFinished(x) = e {
+                handler(Request::new(x));
+            }
+        });
+        Ok(())
+    }
+
+    /// Calls `handler` when a JavaScript dialog appears in any page of this context. The handler must either
+    /// [`Dialog::accept`] or [`Dialog::dismiss`] it.
+    pub async fn on_dialog<F>(&self, handler: F) -> Result<(), Error>
+    where
+        F: Fn(Dialog) + Send + Sync + 'static,
+    {
+        let inner = upgrade(&self.inner)?;
+        inner.subscribe("dialog").await?;
+        let rx = inner.subscribe_event();
+        on_event(rx, move |e| {
+            if let Evt::Dialog(x) = e {
+                handler(Dialog::new(x));
+            }
+        });
+        Ok(())
+    }
+
+    /// Calls `handler` when JavaScript within any page of this context calls one of console API methods, e.g.
+    /// `console.log`. Runs before the handlers subscribed on the page itself.
+    pub async fn on_console<F>(&self, handler: F) -> Result<(), Error>
+    where
+        F: Fn(ConsoleMessage) + Send + Sync + 'static,
+    {
+        let inner = upgrade(&self.inner)?;
+        inner.subscribe("console").await?;
+        let rx = inner.subscribe_event();
+        on_event(rx, move |e| {
+            if let Evt::Console(x) = e {
+                handler(ConsoleMessage::new(x));
+            }
+        });
+        Ok(())
+    }
+
+    /// Calls `handler` with every Service Worker registered by a page of this context. Only Chromium reports Service
+    /// Workers.
+    pub async fn on_service_worker<F>(&self, handler: F) -> Result<(), Error>
+    where
+        F: Fn(ServiceWorker) + Send + Sync + 'static,
+    {
+        let rx = upgrade(&self.inner)?.subscribe_event();
+        on_event(rx, move |e| {
+            if let Evt::ServiceWorker(x) = e {
+                handler(ServiceWorker::new(x));
+            }
+        });
+        Ok(())
+    }
+
+    /// Creates a Chrome DevTools Protocol session attached to `page`, which must belong to this context. Fails with
+    /// [`Error::NotSupported`] on browsers other than Chromium.
+    pub async fn new_cdp_session(&self, page: &Page) -> Result<CdpSession, Error> {
+        let page = page.upgrade()?;
+        let session = upgrade(&self.inner)?
+            .new_cdp_session(&page)
+            .await
+            .map_err(Error::unwrap_arc)?;
+        Ok(CdpSession::new(session))
+    }
+
     // background_page for chromium
-    // new_cdp_session
-    // service_workers
 }
 
 #[derive(Debug, PartialEq)]
 pub enum Event {
     // BackgroundPage for chromium persistent
-    // ServiceWorker
     /// Emitted when Browser context gets closed. This might happen because of one of the following:
     /// - Browser context is closed.
     /// - Browser application is closed or crashed.
     /// - The [`method: Browser.close`] method was called.
     Close,
+    /// Emitted when JavaScript within any page of this context calls one of console API methods. See
+    /// [`page::Event::Console`](crate::api::page::Event::Console).
+    Console(ConsoleMessage),
+    /// Emitted when a JavaScript dialog appears in any page of this context. See [`Page::on_dialog`].
+    Dialog(Dialog),
     /// The event is emitted when a new Page is created in the BrowserContext. The page may still be loading. The event will
     /// also fire for popup pages. See also [`event: Page.popup`] to receive events about popups relevant to a specific page.
     ///
@@ -245,13 +447,33 @@ pub enum Event {
     /// console.log(await newPage.evaluate('location.href'));
     /// ```
     Page(Page),
+    /// Emitted when a request is issued from any pages created through this context.
+    Request(Request),
+    /// Emitted when response status and headers are received for a request.
+    Response(Response),
+    /// Emitted when a request fails, for example by timing out.
+    ///
+    /// > NOTE: HTTP Error responses, such as 404 or 503, are still successful responses from HTTP standpoint, so request will
+    /// complete with [`event: BrowserContext.requestFinished`] event and not with [`event: BrowserContext.requestFailed`].
+    RequestFailed(Request),
+    /// Emitted when a request finishes successfully after downloading the response body.
+    RequestFinished(Request),
+    /// Emitted when a page of this context registers a Service Worker. Only Chromium reports Service Workers.
+    ServiceWorker(ServiceWorker),
 }
 
 impl From<Evt> for Event {
     fn from(e: Evt) -> Event {
         match e {
             Evt::Close => Event::Close,
+            Evt::Console(w) => Event::Console(ConsoleMessage::new(w)),
+            Evt::Dialog(w) => Event::Dialog(Dialog::new(w)),
             Evt::Page(w) => Event::Page(Page::new(w)),
+            Evt::Request(w) => Event::Request(Request::new(w)),
+            Evt::Response(w) => Event::Response(Response::new(w)),
+            Evt::RequestFailed(w) => Event::RequestFailed(Request::new(w)),
+            Evt::RequestFinished(w) => Event::RequestFinished(Request::new(w)),
+            Evt::ServiceWorker(w) => Event::ServiceWorker(ServiceWorker::new(w)),
         }
     }
 }
@@ -8,11 +8,13 @@ use crate::{
         core::*,
         prelude::*,
         utils::{
-            BrowserChannel, ColorScheme, Geolocation, HttpCredentials, ProxySettings, Viewport,
+            BrowserChannel, ColorScheme, Geolocation, HttpCredentials, ProxySettings,
+            ServiceWorkerPolicy, Viewport,
         },
     },
     Error,
 };
+use std::borrow::Cow;
 
 #[derive(Debug, Clone)]
 pub struct BrowserType {
@@ -98,6 +100,36 @@ impl BrowserType {
     // launch_server
 }
 
+/// Switches that only Chromium understands. Other browsers are given any other flag unchanged.
+const CHROMIUM_ONLY_FLAGS: &[&str] = &[
+    "--allow-insecure-localhost",
+    "--disable-dev-shm-usage",
+    "--disable-extensions",
+    "--disable-gpu",
+    "--disable-web-security",
+    "--ignore-certificate-errors",
+    "--no-sandbox",
+    "--remote-debugging-port",
+    "--user-data-dir",
+];
+
+fn check_args(browser: &str, flags: &[String]) -> Result<(), Arc<Error>> {
+    if browser == "chromium" {
+        return Ok(());
+    }
+    let chromium_only = flags.iter().find(|f| {
+        let name = f.split('=').next().unwrap_or_default();
+        CHROMIUM_ONLY_FLAGS.contains(&name)
+    });
+    match chromium_only {
+        Some(flag) => Err(Error::InvalidArgument {
+            message: format!("{} is a Chromium flag, {} is not Chromium", flag, browser),
+        }
+        .into()),
+        None => Ok(()),
+    }
+}
+
 /// [`BrowserType::launcher`]
 pub struct Launcher<'a, 'b, 'c> {
     inner: Weak<Impl>,
@@ -107,7 +139,9 @@ pub struct Launcher<'a, 'b, 'c> {
 impl<'a, 'b, 'c> Launcher<'a, 'b, 'c> {
     pub async fn launch(self) -> Result<Browser, Arc<Error>> {
         let Self { inner, args } = self;
-        let r = upgrade(&inner)?.launch(args).await?;
+        let inner = upgrade(&inner)?;
+        check_args(inner.name(), args.args.as_deref().unwrap_or_default())?;
+        let r = inner.launch(args).await?;
         Ok(Browser::new(r))
     }
 
@@ -118,14 +152,58 @@ impl<'a, 'b, 'c> Launcher<'a, 'b, 'c> {
         }
     }
 
+    /// Additional arguments to pass to the browser instance. The list of Chromium flags can be found
+    /// [here](http://peter.sh/experiments/chromium-command-line-switches/). Well-known Chromium-only switches such as
+    /// `--disable-web-security` are rejected with [`Error::InvalidArgument`] when launching another browser.
+    pub fn args(mut self, x: &'b [String]) -> Self {
+        self.args.args = Some(Cow::Borrowed(x));
+        self
+    }
+
+    /// Same as [`Launcher::args`], taking ownership of `flags`.
+    pub fn with_args(mut self, flags: Vec<String>) -> Self {
+        self.args.args = Some(Cow::Owned(flags));
+        self
+    }
+
+    /// Appends `flag` to the arguments passed to the browser instance.
+    pub fn arg(mut self, flag: &str) -> Self {
+        self.args
+            .args
+            .get_or_insert_with(|| Cow::Owned(Vec::new()))
+            .to_mut()
+            .push(flag.to_owned());
+        self
+    }
+
+    pub fn clear_args(mut self) -> Self {
+        self.args.args = None;
+        self
+    }
+
+    /// If specified, accepted downloads are downloaded into this directory. Otherwise, temporary directory is created and is
+    /// deleted when browser is closed.
+    pub fn downloads(mut self, x: &'c Path) -> Self {
+        self.args.downloads = Some(Cow::Borrowed(x));
+        self
+    }
+
+    /// Same as [`Launcher::downloads`], taking ownership of `dir`.
+    pub fn with_downloads_path(mut self, dir: PathBuf) -> Self {
+        self.args.downloads = Some(Cow::Owned(dir));
+        self
+    }
+
+    pub fn clear_downloads(mut self) -> Self {
+        self.args.downloads = None;
+        self
+    }
+
     setter! {
         /// Path to a browser executable to run instead of the bundled one. If `executablePath` is a relative path, then it is
         /// resolved relative to the current working directory. Note that Playwright only works with the bundled Chromium, Firefox
         /// or WebKit, use at your own risk.
         executable: Option<&'a Path>,
-        /// Additional arguments to pass to the browser instance. The list of Chromium flags can be found
-        /// [here](http://peter.sh/experiments/chromium-command-line-switches/).
-        args: Option<&'b [String]>,
         /// If `true`, Playwright does not pass its own configurations args and only uses the ones from `args`. Dangerous option;
         /// use with care. Defaults to `false`.
         ignore_all_default_args: Option<bool>,
@@ -143,9 +221,6 @@ impl<'a, 'b, 'c> Launcher<'a, 'b, 'c> {
         devtools: Option<bool>,
         /// Network proxy settings.
         proxy: Option<ProxySettings>,
-        /// If specified, accepted downloads are downloaded into this directory. Otherwise, temporary directory is created and is
-        /// deleted when browser is closed.
-        downloads: Option<&'c Path>,
         /// Slows down Playwright operations by the specified amount of milliseconds. Useful so that you can see what is going on.
         slowmo: Option<f64>,
         /// Specify environment variables that will be visible to the browser. Defaults to `process.env`.
@@ -181,7 +256,9 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
 {
     pub async fn launch(self) -> Result<BrowserContext, Arc<Error>> {
         let Self { inner, args } = self;
-        let r = upgrade(&inner)?.launch_persistent_context(args).await?;
+        let inner = upgrade(&inner)?;
+        check_args(inner.name(), args.args.unwrap_or_default())?;
+        let r = inner.launch_persistent_context(args).await?;
         Ok(BrowserContext::new(r))
     }
 
@@ -196,13 +273,38 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
         DeviceDescriptor::set_persistent_context(device, self)
     }
 
+    /// If specified, accepted downloads are downloaded into this directory. Otherwise, temporary directory is created and is
+    /// deleted when browser is closed.
+    pub fn downloads(mut self, x: &'d Path) -> Self {
+        self.args.downloads = Some(Cow::Borrowed(x));
+        self
+    }
+
+    /// Same as [`PersistentContextLauncher::downloads`], taking ownership of `dir`.
+    pub fn with_downloads_path(mut self, dir: PathBuf) -> Self {
+        self.args.downloads = Some(Cow::Owned(dir));
+        self
+    }
+
+    /// Accepts every download and saves it into `dir`. Shortcut for [`PersistentContextLauncher::accept_downloads`] with
+    /// [`PersistentContextLauncher::with_downloads_path`].
+    pub fn with_accept_downloads(self, dir: PathBuf) -> Self {
+        self.accept_downloads(true).with_downloads_path(dir)
+    }
+
+    pub fn clear_downloads(mut self) -> Self {
+        self.args.downloads = None;
+        self
+    }
+
     setter! {
         /// Path to a browser executable to run instead of the bundled one. If `executablePath` is a relative path, then it is
         /// resolved relative to the current working directory. **BEWARE**: Playwright is only guaranteed to work with the bundled
         /// Chromium, Firefox or WebKit, use at your own risk.
         executable: Option<&'b Path>,
         /// Additional arguments to pass to the browser instance. The list of Chromium flags can be found
-        /// [here](http://peter.sh/experiments/chromium-command-line-switches/).
+        /// [here](http://peter.sh/experiments/chromium-command-line-switches/). Well-known Chromium-only switches are
+        /// rejected as in [`Launcher::args`].
         args: Option<&'c [String]>,
         /// If `true`, Playwright does not pass its own configurations args and only uses the ones from `args`. Dangerous option;
         /// use with care. Defaults to `false`.
@@ -228,9 +330,6 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
         devtools: Option<bool>,
         /// Network proxy settings.
         proxy: Option<ProxySettings>,
-        /// If specified, accepted downloads are downloaded into this directory. Otherwise, temporary directory is created and is
-        /// deleted when browser is closed.
-        downloads: Option<&'d Path>,
         /// Slows down Playwright operations by the specified amount of milliseconds. Useful so that you can see what is going on.
         /// Defaults to 0.
         slowmo: Option<f64>,
@@ -241,6 +340,9 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
         /// Emulates consistent window screen size available inside web page via `window.screen`. Is only used when the `viewport`
         /// is set.
         screen: Option<Viewport>,
+        /// Whether to allow sites to register Service workers. Defaults to [`ServiceWorkerPolicy::Allow`]. With
+        /// [`ServiceWorkerPolicy::Block`] no Service Worker is registered in this context.
+        service_workers: Option<ServiceWorkerPolicy>,
         /// Whether to ignore HTTPS errors during navigation. Defaults to `false`.
         ignore_https_errors: Option<bool>,
         /// Whether or not to enable JavaScript in the context. Defaults to `true`.
@@ -276,7 +378,7 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j, 'k>
         /// Emulates `'prefers-colors-scheme'` media feature, supported values are `'light'`, `'dark'`, `'no-preference'`. See
         /// [`method: Page.emulateMedia`] for more details. Defaults to `'light'`.
         color_scheme: Option<ColorScheme>,
-        /// Whether to automatically download all the attachments. Defaults to `false` where all the downloads are canceled.
+        /// Whether to automatically download all the attachments. Defaults to `true` where all the downloads are accepted.
         accept_downloads: Option<bool>,
         /// Enable Chromium sandboxing. Defaults to `true`.
         chromium_sandbox: Option<bool>,
@@ -0,0 +1,41 @@
+use crate::imp::{cdp_session::CdpSession as Impl, core::*, prelude::*};
+
+/// The `CdpSession` instances are used to talk raw Chrome Devtools Protocol:
+/// - protocol methods can be called with [`CdpSession::send`] method.
+///
+/// Useful links:
+/// - Documentation on DevTools Protocol can be found here:
+///  [DevTools Protocol Viewer](https://chromedevtools.github.io/devtools-protocol/).
+/// - Getting Started with DevTools Protocol:
+///  <https://github.com/aslushnikov/getting-started-with-cdp/blob/master/README.md>
+///
+/// Sessions are only available in Chromium, see [`BrowserContext::new_cdp_session`](crate::api::BrowserContext::new_cdp_session).
+#[derive(Debug, Clone)]
+pub struct CdpSession {
+    inner: Weak<Impl>,
+}
+
+impl CdpSession {
+    pub(crate) fn new(inner: Weak<Impl>) -> Self {
+        Self { inner }
+    }
+
+    /// Sends the protocol `method` with `params` and returns its result.
+    ///
+    /// ```js
+    /// await client.send('Animation.setPlaybackRate', { playbackRate: 0.5 });
+    /// ```
+    pub async fn send<P>(&self, method: &str, params: Option<P>) -> Result<Value, Error>
+    where
+        P: Serialize,
+    {
+        let params = params.map(serde_json::to_value).transpose()?;
+        Ok(upgrade(&self.inner)?.send(method, params).await?)
+    }
+
+    /// Detaches the session from the target. Once detached, the session won't emit any events and can't be used to send
+    /// messages.
+    pub async fn detach(&self) -> Result<(), Error> {
+        Ok(upgrade(&self.inner)?.detach().await?)
+    }
+}
@@ -4,13 +4,19 @@ use crate::{
 };
 
 /// `ConsoleMessage` objects are dispatched by page via the [page::Event::Console](crate::api::page::Event::Console) event.
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct ConsoleMessage {
-    inner: Weak<Impl>,
+    inner: Arc<Impl>,
+}
+
+impl PartialEq for ConsoleMessage {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
 }
 
 impl ConsoleMessage {
-    pub(crate) fn new(inner: Weak<Impl>) -> Self {
+    pub(crate) fn new(inner: Arc<Impl>) -> Self {
         Self { inner }
     }
 
@@ -18,22 +24,23 @@ impl ConsoleMessage {
     /// `'trace'`, `'clear'`, `'startGroup'`, `'startGroupCollapsed'`, `'endGroup'`, `'assert'`, `'profile'`, `'profileEnd'`,
     /// `'count'`, `'timeEnd'`.
     pub fn r#type(&self) -> Result<String, Error> {
-        Ok(upgrade(&self.inner)?.r#type().into())
+        Ok(self.inner.r#type().into())
     }
 
     /// The text of the console message.
     pub fn text(&self) -> Result<String, Error> {
-        Ok(upgrade(&self.inner)?.text().into())
+        Ok(self.inner.text().into())
     }
 
     /// URL of the resource followed by 0-based line and column numbers in the resource formatted as `URL:line:column`.
     pub fn location(&self) -> Result<SourceLocation, Error> {
-        Ok(upgrade(&self.inner)?.location().to_owned())
+        Ok(self.inner.location().to_owned())
     }
 
     /// List of arguments passed to a `console` function call.
     pub fn args(&self) -> Result<Vec<JsHandle>, Error> {
-        Ok(upgrade(&self.inner)?
+        Ok(self
+            .inner
             .args()
             .iter()
             .map(|x| JsHandle::new(x.clone()))
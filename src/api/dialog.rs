@@ -1,4 +1,8 @@
-use crate::imp::{dialog::Dialog as Impl, prelude::*};
+use crate::{
+    api::Page,
+    imp::{core::*, dialog::Dialog as Impl, prelude::*},
+    Error,
+};
 
 /// `Dialog` objects are dispatched by page via the [page::Event::Dialog](crate::api::page::Event::Dialog) event.
 ///
@@ -23,24 +27,55 @@ use crate::imp::{dialog::Dialog as Impl, prelude::*};
 /// present, it **must** either [`method: Dialog.accept`] or [`method: Dialog.dismiss`] the dialog - otherwise the page will
 /// [freeze](https://developer.mozilla.org/en-US/docs/Web/JavaScript/EventLoop#never_blocking) waiting for the dialog, and
 /// actions like click will never finish.
+#[derive(Debug, Clone)]
 pub struct Dialog {
     inner: Weak<Impl>,
 }
 
+impl PartialEq for Dialog {
+    fn eq(&self, other: &Self) -> bool {
+        let a = self.inner.upgrade();
+        let b = other.inner.upgrade();
+        a.and_then(|a| b.map(|b| (a, b)))
+            .map(|(a, b)| a.guid() == b.guid())
+            .unwrap_or_default()
+    }
+}
+
 impl Dialog {
     pub(crate) fn new(inner: Weak<Impl>) -> Self {
         Self { inner }
     }
 
-    ///// Returns when the dialog has been accepted.
-    ///// A text to enter in prompt. Does not cause any effects if the dialog's `type` is not prompt. Optional.
-    // fn accept(&self, prompt_text: Option<String>) -> Result<(), Arc<Error>> { todo!() }
-    ///// If dialog is prompt, returns default prompt value. Otherwise, returns empty string.
-    // fn default_value(&self) -> Result<String, Error> { todo!() }
-    ///// Returns when the dialog has been dismissed.
-    // fn dismiss(&self) -> Result<(), Arc<Error>> { todo!() }
-    ///// A message displayed in the dialog.
-    // fn message(&self) -> Result<String, Error> { todo!() }
-    ///// Returns dialog's type, can be one of `alert`, `beforeunload`, `confirm` or `prompt`.
-    // fn r#type(&self) -> Result<String, Error> { todo!() }
+    /// Returns when the dialog has been accepted.
+    ///
+    /// `prompt_text` is a text to enter in prompt. Does not cause any effects if the dialog's `type` is not prompt.
+    pub async fn accept(&self, prompt_text: Option<&str>) -> ArcResult<()> {
+        upgrade(&self.inner)?.accept(prompt_text).await
+    }
+
+    /// If dialog is prompt, returns default prompt value. Otherwise, returns empty string.
+    pub fn default_value(&self) -> Result<String, Error> {
+        Ok(upgrade(&self.inner)?.default_value().to_owned())
+    }
+
+    /// Returns when the dialog has been dismissed.
+    pub async fn dismiss(&self) -> ArcResult<()> {
+        upgrade(&self.inner)?.dismiss().await
+    }
+
+    /// A message displayed in the dialog.
+    pub fn message(&self) -> Result<String, Error> {
+        Ok(upgrade(&self.inner)?.message().to_owned())
+    }
+
+    /// Returns dialog's type, can be one of `alert`, `beforeunload`, `confirm` or `prompt`.
+    pub fn r#type(&self) -> Result<String, Error> {
+        Ok(upgrade(&self.inner)?.r#type().to_owned())
+    }
+
+    /// The page that initiated this dialog, if available.
+    pub fn page(&self) -> Result<Option<Page>, Error> {
+        Ok(upgrade(&self.inner)?.page().map(Page::new))
+    }
 }
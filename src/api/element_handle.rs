@@ -1,5 +1,5 @@
 use crate::{
-    api::Frame,
+    api::{Frame, JsHandle},
     imp::{
         core::*,
         element_handle::{
@@ -9,8 +9,8 @@ use crate::{
         },
         prelude::*,
         utils::{
-            ElementState, File, FloatRect, KeyboardModifier, MouseButton, Position, ScreenshotType,
-            WaitForSelectorState,
+            BoundingBox, ElementState, File, KeyboardModifier, MouseButton, Position,
+            ScreenshotType, WaitForSelectorState,
         },
     },
 };
@@ -89,6 +89,11 @@ impl ElementHandle {
         upgrade(&self.inner)?.inner_text().await
     }
 
+    /// Returns `input.value` for `<input>`, `<textarea>` or `<select>` element. Throws for non-input elements.
+    pub async fn input_value(&self) -> ArcResult<String> {
+        upgrade(&self.inner)?.input_value().await
+    }
+
     /// Returns the `element.innerHTML`.
     pub async fn inner_html(&self) -> ArcResult<String> {
         upgrade(&self.inner)?.inner_html().await
@@ -301,7 +306,7 @@ impl ElementHandle {
     /// const box = await elementHandle.boundingBox();
     /// await page.mouse.click(box.x + box.width / 2, box.y + box.height / 2);
     /// ```
-    pub async fn bounding_box(&self) -> ArcResult<Option<FloatRect>> {
+    pub async fn bounding_box(&self) -> ArcResult<Option<BoundingBox>> {
         upgrade(&self.inner)?.bounding_box().await
     }
 
@@ -419,6 +424,46 @@ impl ElementHandle {
         SetInputFilesBuilder::new(self.inner.clone(), file)
     }
 
+    /// Returns the return value of `expression`, a function called with this element as its first argument.
+    ///
+    /// ```js
+    /// const tweetHandle = await page.$('.tweet .retweets');
+    /// expect(await tweetHandle.evaluate(node => node.innerText)).toBe('10 retweets');
+    /// ```
+    pub async fn eval<U>(&self, expression: &str) -> ArcResult<U>
+    where
+        U: DeserializeOwned,
+    {
+        upgrade(&self.inner)?.eval(expression).await
+    }
+
+    /// Same as [`ElementHandle::eval`], passing `arg` as the second argument of `expression`.
+    pub async fn evaluate<T, U>(&self, expression: &str, arg: Option<T>) -> ArcResult<U>
+    where
+        T: Serialize,
+        U: DeserializeOwned,
+    {
+        upgrade(&self.inner)?.evaluate(expression, arg).await
+    }
+
+    /// Same as [`ElementHandle::eval`], but returns the result as a [`JsHandle`].
+    pub async fn eval_handle(&self, expression: &str) -> ArcResult<JsHandle> {
+        upgrade(&self.inner)?
+            .eval_handle(expression)
+            .await
+            .map(JsHandle::new)
+    }
+
+    pub async fn evaluate_handle<T>(&self, expression: &str, arg: Option<T>) -> ArcResult<JsHandle>
+    where
+        T: Serialize,
+    {
+        upgrade(&self.inner)?
+            .evaluate_handle(expression, arg)
+            .await
+            .map(JsHandle::new)
+    }
+
     // eval_on_selector
     // eval_on_selector_all
 }
@@ -1,15 +1,22 @@
-pub use crate::imp::frame::{FrameNavigatedEvent, FrameState, Polling};
+pub use crate::api::locator::LocatorOptions;
+pub use crate::imp::frame::{
+    FrameNavigatedEvent, FrameState, GetByRoleOptions, Polling, RoleName, SetContentOptions,
+    WaitForFunctionOptions, WaitForSelectorOptions,
+};
 use crate::{
-    api::{ElementHandle, JsHandle, Locator, Page, Response},
+    api::{ElementHandle, FrameLocator, JsHandle, Locator, Page, Response},
     imp::{
         core::*,
         frame::{
-            AddScriptTagArgs, CheckArgs, ClickArgs, Evt, FillArgs, Frame as Impl, GotoArgs,
-            HoverArgs, Opt, PressArgs, SelectOptionArgs, SetContentArgs, SetInputFilesArgs,
-            TapArgs, TypeArgs, WaitForFunctionArgs, WaitForSelectorArgs,
+            build_alt_text_selector, build_title_selector, AddScriptTagArgs, CheckArgs, ClickArgs,
+            Evt, FillArgs, Frame as Impl, GotoArgs, HoverArgs, Opt, PressArgs, SelectOptionArgs,
+            SetContentArgs, SetInputFilesArgs, TapArgs, TypeArgs, WaitForFunctionArgs,
+            WaitForSelectorArgs,
         },
         prelude::*,
-        utils::{DocumentLoadState, File, KeyboardModifier, MouseButton, Position},
+        utils::{
+            DocumentLoadState, EvalSource, File, KeyboardModifier, MouseButton, NoArg, Position,
+        },
     },
 };
 
@@ -227,6 +234,19 @@ impl Frame {
             .map(ElementHandle::new))
     }
 
+    /// Like [`Frame::query_selector`], but fails with [`Error::AmbiguousMatch`] instead of returning the first element
+    /// when more than one element matches `selector`.
+    pub async fn query_selector_strict(
+        &self,
+        selector: &str,
+    ) -> Result<Option<ElementHandle>, Error> {
+        Ok(upgrade(&self.inner)?
+            .query_selector_strict(selector)
+            .await
+            .map_err(Error::unwrap_arc)?
+            .map(ElementHandle::new))
+    }
+
     pub async fn query_selector_all(&self, selector: &str) -> ArcResult<Vec<ElementHandle>> {
         let es = upgrade(&self.inner)?.query_selector_all(selector).await?;
         Ok(es.into_iter().map(ElementHandle::new).collect())
@@ -277,63 +297,107 @@ impl Frame {
         WaitForSelectorBuilder::new(self.inner.clone(), selector)
     }
 
+    /// Same as [`Frame::wait_for_selector_builder`] with options given at once.
+    ///
+    /// With `strict` set, fails with [`Error::AmbiguousMatch`] when more than one element matches `selector`.
+    pub async fn wait_for_selector(
+        &self,
+        selector: &str,
+        options: WaitForSelectorOptions,
+    ) -> Result<Option<ElementHandle>, Error> {
+        let WaitForSelectorOptions {
+            state,
+            timeout,
+            strict,
+        } = options;
+        let mut args = WaitForSelectorArgs::new(selector);
+        args.state = state;
+        args.timeout = timeout;
+        args.strict = strict;
+        let e = upgrade(&self.inner)?
+            .wait_for_selector(args)
+            .await
+            .map_err(Error::unwrap_arc)?;
+        Ok(e.map(ElementHandle::new))
+    }
+
     // Locator methods
 
     /// Create a locator that can be used to perform actions on elements matching the selector.
-    pub async fn locator(&self, selector: &str) -> Result<Locator, Error> {
+    pub fn locator(&self, selector: &str) -> Result<Locator, Error> {
         let locator_impl = upgrade(&self.inner)?
             .locator(selector)
-            .await
             .map_err(|_| Error::ObjectNotFound)?;
         Ok(Locator::new(locator_impl))
     }
 
+    /// When working with iframes, you can create a frame locator that will enter the iframe and allow selecting
+    /// elements in that iframe.
+    pub fn frame_locator(&self, selector: &str) -> FrameLocator {
+        FrameLocator::new(self.inner.clone(), selector.to_owned())
+    }
+
+    /// Same as [`Frame::locator`], narrowed down by `options` like `frame.locator(selector, { hasText })` in
+    /// TypeScript. Fails with [`Error::InvalidArgument`] if a `has` or `has_not` locator belongs to another frame.
+    pub fn locator_with_options(
+        &self,
+        selector: &str,
+        options: LocatorOptions<'_>,
+    ) -> Result<Locator, Error> {
+        self.locator(&options.selector(&self.inner, selector)?)
+    }
+
     /// Create a locator for elements matching the specified accessibility role.
-    pub async fn get_by_role(&self, role: &str) -> Result<Locator, Error> {
+    pub fn get_by_role(&self, role: &str, options: GetByRoleOptions) -> Result<Locator, Error> {
         let frame_impl = upgrade(&self.inner)?;
         let locator_impl = frame_impl
-            .get_by_role(role, None)
-            .await
+            .get_by_role(role, &options)
             .map_err(|_| Error::ObjectNotFound)?;
         Ok(Locator::new(locator_impl))
     }
 
     /// Create a locator for elements containing the specified text.
-    pub async fn get_by_text(&self, text: &str) -> Result<Locator, Error> {
+    pub fn get_by_text(&self, text: &str) -> Result<Locator, Error> {
         let locator_impl = upgrade(&self.inner)?
             .get_by_text(text, None)
-            .await
             .map_err(|_| Error::ObjectNotFound)?;
         Ok(Locator::new(locator_impl))
     }
 
     /// Create a locator for elements matching the specified label.
-    pub async fn get_by_label(&self, text: &str) -> Result<Locator, Error> {
+    pub fn get_by_label(&self, text: &str) -> Result<Locator, Error> {
         let locator_impl = upgrade(&self.inner)?
             .get_by_label(text, None)
-            .await
             .map_err(|_| Error::ObjectNotFound)?;
         Ok(Locator::new(locator_impl))
     }
 
     /// Create a locator for elements matching the specified placeholder.
-    pub async fn get_by_placeholder(&self, text: &str) -> Result<Locator, Error> {
+    pub fn get_by_placeholder(&self, text: &str) -> Result<Locator, Error> {
         let locator_impl = upgrade(&self.inner)?
             .get_by_placeholder(text, None)
-            .await
             .map_err(|_| Error::ObjectNotFound)?;
         Ok(Locator::new(locator_impl))
     }
 
     /// Create a locator for elements matching the specified test ID.
-    pub async fn get_by_test_id(&self, test_id: &str) -> Result<Locator, Error> {
+    pub fn get_by_test_id(&self, test_id: &str) -> Result<Locator, Error> {
         let locator_impl = upgrade(&self.inner)?
             .get_by_test_id(test_id)
-            .await
             .map_err(|_| Error::ObjectNotFound)?;
         Ok(Locator::new(locator_impl))
     }
 
+    /// Create a locator for elements with the specified alt text, e.g. images.
+    pub fn get_by_alt_text(&self, text: &str) -> Result<Locator, Error> {
+        self.locator(&build_alt_text_selector(text, None))
+    }
+
+    /// Create a locator for elements with the specified title attribute.
+    pub fn get_by_title(&self, text: &str) -> Result<Locator, Error> {
+        self.locator(&build_title_selector(text, None))
+    }
+
     pub async fn title(&self) -> ArcResult<String> {
         upgrade(&self.inner)?.title().await
     }
@@ -401,6 +465,20 @@ impl Frame {
         SetContentBuilder::new(self.inner.clone(), html)
     }
 
+    /// Replaces the whole document of the frame with `html` without navigating.
+    ///
+    /// Same as [`Frame::set_content_builder`] with options given at once.
+    pub async fn set_content(&self, html: &str, options: SetContentOptions) -> ArcResult<()> {
+        let SetContentOptions {
+            timeout,
+            wait_until,
+        } = options;
+        let mut args = SetContentArgs::new(html);
+        args.timeout = timeout;
+        args.wait_until = wait_until;
+        upgrade(&self.inner)?.set_content(args).await
+    }
+
     /// This method checks an element matching `selector` by performing the following steps:
     /// 1. Find an element matching `selector`. If there is none, wait until a matching element is attached to the DOM.
     /// 1. Ensure that matched element is a checkbox or a radio input. If not, this method throws. If the element is already
@@ -435,9 +513,17 @@ impl Frame {
         UncheckBuilder::new(self.inner.clone(), selector)
     }
 
-    // = |timeout| async { sleep(timeout).await }
-    pub async fn wait_for_timeout(&self, timeout: f64) {
-        sleep(std::time::Duration::from_millis(timeout as u64)).await
+    /// Waits for the given `timeout` in milliseconds.
+    ///
+    /// Fails with [`Error::PageClosed`] if the frame's page is closed before the timeout elapses.
+    pub async fn wait_for_timeout(&self, timeout: f64) -> Result<(), Error> {
+        match upgrade(&self.inner)?.page() {
+            Some(page) => Page::new(page).wait_for_timeout(timeout).await,
+            None => {
+                sleep(std::time::Duration::from_millis(timeout as u64)).await;
+                Ok(())
+            }
+        }
     }
 
     /// Returns the added tag when the stylesheet's onload fires or when the CSS content was injected into frame.
@@ -553,6 +639,93 @@ impl Frame {
         upgrade(&self.inner)?.evaluate(expression, Some(arg)).await
     }
 
+    /// Same as [`Frame::evaluate`], but the result type is usually inferred from the binding. Pass [`NoArg`] when
+    /// `expression` takes no argument.
+    ///
+    /// ```ignore
+    /// let sum: i32 = frame.evaluate_expression("([a, b]) => a + b", (1, 2)).await?;
+    /// ```
+    pub async fn evaluate_expression<A, R>(&self, expression: &str, arg: A) -> Result<R, Error>
+    where
+        A: Serialize,
+        R: DeserializeOwned,
+    {
+        Ok(upgrade(&self.inner)?
+            .evaluate(expression, Some(arg))
+            .await?)
+    }
+
+    /// Same as [`Frame::evaluate_expression`], but fails with [`Error::Timeout`] if the expression has not returned
+    /// after `timeout` milliseconds, e.g. because it loops forever.
+    pub async fn evaluate_with_timeout<A, R>(
+        &self,
+        expression: &str,
+        arg: A,
+        timeout: f64,
+    ) -> Result<R, Error>
+    where
+        A: Serialize,
+        R: DeserializeOwned,
+    {
+        upgrade(&self.inner)?
+            .evaluate_with_timeout(expression, Some(arg), timeout)
+            .await
+            .map_err(Error::unwrap_arc)
+    }
+
+    /// Zero-argument shorthand for [`Frame::evaluate_expression`].
+    pub async fn eval_script<R>(&self, script: &str) -> Result<R, Error>
+    where
+        R: DeserializeOwned,
+    {
+        self.evaluate_expression(script, NoArg).await
+    }
+
+    /// Same as [`Frame::eval_script`] for results of unknown or varying shape, returned as JSON.
+    ///
+    /// ```ignore
+    /// assert_eq!(frame.eval_value("1 + 1").await?, serde_json::json!(2));
+    /// ```
+    pub async fn eval_value(&self, script: &str) -> Result<Value, Error> {
+        self.eval_script(script).await
+    }
+
+    /// Same as [`Frame::evaluate_expression`] for results of unknown or varying shape, returned as JSON.
+    pub async fn eval_value_with_arg<A>(&self, expression: &str, arg: A) -> Result<Value, Error>
+    where
+        A: Serialize,
+    {
+        self.evaluate_expression(expression, arg).await
+    }
+
+    /// Same as [`Frame::evaluate`], but the expression is given by an [`EvalSource`].
+    pub async fn evaluate_source<A, R>(
+        &self,
+        source: EvalSource,
+        arg: Option<A>,
+    ) -> Result<R, Error>
+    where
+        A: Serialize,
+        R: DeserializeOwned,
+    {
+        let expression = source.into_expression().await?;
+        Ok(upgrade(&self.inner)?.evaluate(&expression, arg).await?)
+    }
+
+    /// Evaluates the content of the script file at `path`, read when this method is called.
+    ///
+    /// ```ignore
+    /// let sum: i32 = frame.evaluate_file(Path::new("sum.js"), Some((1, 2))).await?;
+    /// ```
+    pub async fn evaluate_file<A, R>(&self, path: &Path, arg: Option<A>) -> Result<R, Error>
+    where
+        A: Serialize,
+        R: DeserializeOwned,
+    {
+        self.evaluate_source(EvalSource::File(path.to_owned()), arg)
+            .await
+    }
+
     /// Returns the return value of `expression`.
     ///
     /// The method finds an element matching the specified selector within the frame and passes it as a first argument to
@@ -710,6 +883,36 @@ impl Frame {
         WaitForFunctionBuilder::new(self.inner.clone(), expression)
     }
 
+    /// Returns when the `expression` returns a truthy value. It resolves to a JSHandle of the truthy value.
+    ///
+    /// Same as [`Frame::wait_for_function_builder`] with `arg` and options given at once.
+    ///
+    /// ```js
+    /// await frame.waitForFunction(() => window.ready);
+    /// ```
+    pub async fn wait_for_function<T>(
+        &self,
+        expression: &str,
+        arg: Option<T>,
+        options: WaitForFunctionOptions,
+    ) -> ArcResult<JsHandle>
+    where
+        T: Serialize,
+    {
+        let WaitForFunctionOptions { polling, timeout } = options;
+        let mut args = WaitForFunctionArgs::new(expression);
+        args.polling = polling;
+        args.timeout = timeout;
+        args.arg = match arg {
+            Some(x) => Some(ser::to_value(&x).map_err(Error::SerializationPwJson)?),
+            None => None,
+        };
+        upgrade(&self.inner)?
+            .wait_for_function(args)
+            .await
+            .map(JsHandle::new)
+    }
+
     subscribe_event! {}
 
     // wait_for_url
@@ -743,7 +946,10 @@ impl<'a, 'b> GotoBuilder<'a, 'b> {
 
     pub async fn goto(self) -> Result<Option<Response>, Arc<Error>> {
         let Self { inner, args } = self;
-        let r = upgrade(&inner)?.goto(args).await?;
+        let r = upgrade(&inner)?
+            .goto(args)
+            .await
+            .map_err(Error::navigation)?;
         Ok(r.map(Response::new))
     }
 
@@ -826,6 +1032,8 @@ impl<'a> WaitForSelectorBuilder<'a> {
     setter! {
         /// Defaults to `'visible'`.
         state: Option<FrameState>,
+        /// When true, fails with [`Error::AmbiguousMatch`] if more than one element matches the selector.
+        strict: Option<bool>,
         timeout: Option<f64>
     }
 }
@@ -0,0 +1,72 @@
+pub use crate::imp::frame::{GetByRoleOptions, RoleName};
+use crate::{
+    api::Locator,
+    imp::{
+        frame::{
+            build_role_selector, build_test_id_selector, build_text_selector, Frame as FrameImpl,
+        },
+        locator::Locator as LocatorImpl,
+        prelude::*,
+    },
+};
+
+/// FrameLocator represents a view to the `iframe` on the page. It captures the logic sufficient to retrieve the
+/// `iframe` and locate elements in that iframe. FrameLocator can be created with
+/// [`Page::frame_locator`](crate::api::Page::frame_locator).
+///
+/// ```js
+/// const locator = page.frameLocator('#my-frame').getByText('Submit');
+/// await locator.click();
+/// ```
+///
+/// Building a FrameLocator does not talk to the browser; the iframe is resolved when the resulting locator is used.
+#[derive(Debug, Clone)]
+pub struct FrameLocator {
+    frame: Weak<FrameImpl>,
+    selector: String,
+}
+
+impl FrameLocator {
+    pub(crate) fn new(frame: Weak<FrameImpl>, selector: String) -> Self {
+        Self { frame, selector }
+    }
+
+    /// Returns the selector of the `iframe` element.
+    pub fn selector(&self) -> &str {
+        &self.selector
+    }
+
+    fn enter(&self, selector: &str) -> String {
+        format!(
+            "{} >> internal:control=enter-frame >> {}",
+            self.selector, selector
+        )
+    }
+
+    /// The method finds an element matching the specified selector in the FrameLocator's subtree.
+    pub fn locator(&self, selector: &str) -> Locator {
+        let selector = self.enter(selector);
+        Locator::new(LocatorImpl::client_side(self.frame.clone(), selector))
+    }
+
+    /// When working with iframes, you can create a frame locator that will enter the iframe and allow selecting
+    /// elements in that iframe.
+    pub fn frame_locator(&self, selector: &str) -> FrameLocator {
+        FrameLocator::new(self.frame.clone(), self.enter(selector))
+    }
+
+    /// Allows locating elements by their [ARIA role](https://www.w3.org/TR/wai-aria-1.2/#roles) inside the iframe.
+    pub fn get_by_role(&self, role: &str, options: GetByRoleOptions) -> Locator {
+        self.locator(&build_role_selector(role, &options))
+    }
+
+    /// Allows locating elements that contain given text inside the iframe.
+    pub fn get_by_text(&self, text: &str, exact: Option<bool>) -> Locator {
+        self.locator(&build_text_selector(text, exact))
+    }
+
+    /// Locate element by the test id inside the iframe.
+    pub fn get_by_test_id(&self, test_id: &str) -> Locator {
+        self.locator(&build_test_id_selector(test_id))
+    }
+}
@@ -4,6 +4,7 @@ use crate::imp::{
     prelude::*,
     utils::MouseButton,
 };
+use std::{borrow::Cow, fmt, str::FromStr};
 
 /// Keyboard provides an api for managing a virtual keyboard. The high level api is [`method: Keyboard.type`], which takes
 /// raw characters and generates proper keydown, keypress/input, and keyup events on your page.
@@ -71,6 +72,222 @@ pub struct TouchScreen {
     inner: Weak<PageImpl>,
 }
 
+/// Name of a key for [`Keyboard::press`], such as `Enter`, `ArrowLeft` or `Control+a`.
+///
+/// The well-known names are available as constants like [`Key::ENTER`], and [`keys!`](crate::keys) checks a
+/// combination of them at compile time. [`Key::from_str`] validates a dynamic name, while `Key::from` accepts anything
+/// and leaves it to the driver to reject.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Key(Cow<'static, str>);
+
+macro_rules! known_keys {
+    ($($konst:ident => $name:ident),* $(,)?) => {
+        impl Key {
+            $(pub const $konst: Key = Key(Cow::Borrowed(stringify!($name)));)*
+
+            const KNOWN: &'static [&'static str] = &[$(stringify!($name)),*];
+        }
+
+        #[doc(hidden)]
+        #[allow(non_upper_case_globals)]
+        pub mod known_keys {
+            $(pub const $name: &str = stringify!($name);)*
+        }
+    };
+}
+
+known_keys! {
+    ESCAPE => Escape,
+    F1 => F1,
+    F2 => F2,
+    F3 => F3,
+    F4 => F4,
+    F5 => F5,
+    F6 => F6,
+    F7 => F7,
+    F8 => F8,
+    F9 => F9,
+    F10 => F10,
+    F11 => F11,
+    F12 => F12,
+    BACKQUOTE => Backquote,
+    DIGIT_0 => Digit0,
+    DIGIT_1 => Digit1,
+    DIGIT_2 => Digit2,
+    DIGIT_3 => Digit3,
+    DIGIT_4 => Digit4,
+    DIGIT_5 => Digit5,
+    DIGIT_6 => Digit6,
+    DIGIT_7 => Digit7,
+    DIGIT_8 => Digit8,
+    DIGIT_9 => Digit9,
+    MINUS => Minus,
+    EQUAL => Equal,
+    BACKSLASH => Backslash,
+    BACKSPACE => Backspace,
+    TAB => Tab,
+    KEY_A => KeyA,
+    KEY_B => KeyB,
+    KEY_C => KeyC,
+    KEY_D => KeyD,
+    KEY_E => KeyE,
+    KEY_F => KeyF,
+    KEY_G => KeyG,
+    KEY_H => KeyH,
+    KEY_I => KeyI,
+    KEY_J => KeyJ,
+    KEY_K => KeyK,
+    KEY_L => KeyL,
+    KEY_M => KeyM,
+    KEY_N => KeyN,
+    KEY_O => KeyO,
+    KEY_P => KeyP,
+    KEY_Q => KeyQ,
+    KEY_R => KeyR,
+    KEY_S => KeyS,
+    KEY_T => KeyT,
+    KEY_U => KeyU,
+    KEY_V => KeyV,
+    KEY_W => KeyW,
+    KEY_X => KeyX,
+    KEY_Y => KeyY,
+    KEY_Z => KeyZ,
+    BRACKET_LEFT => BracketLeft,
+    BRACKET_RIGHT => BracketRight,
+    CAPS_LOCK => CapsLock,
+    QUOTE => Quote,
+    SEMICOLON => Semicolon,
+    ENTER => Enter,
+    COMMA => Comma,
+    PERIOD => Period,
+    SLASH => Slash,
+    SPACE => Space,
+    SHIFT => Shift,
+    SHIFT_LEFT => ShiftLeft,
+    SHIFT_RIGHT => ShiftRight,
+    CONTROL => Control,
+    CONTROL_LEFT => ControlLeft,
+    CONTROL_RIGHT => ControlRight,
+    ALT => Alt,
+    ALT_LEFT => AltLeft,
+    ALT_RIGHT => AltRight,
+    META => Meta,
+    META_LEFT => MetaLeft,
+    META_RIGHT => MetaRight,
+    CONTROL_OR_META => ControlOrMeta,
+    ARROW_UP => ArrowUp,
+    ARROW_DOWN => ArrowDown,
+    ARROW_LEFT => ArrowLeft,
+    ARROW_RIGHT => ArrowRight,
+    INSERT => Insert,
+    DELETE => Delete,
+    HOME => Home,
+    END => End,
+    PAGE_UP => PageUp,
+    PAGE_DOWN => PageDown,
+    NUM_LOCK => NumLock,
+    NUMPAD_0 => Numpad0,
+    NUMPAD_1 => Numpad1,
+    NUMPAD_2 => Numpad2,
+    NUMPAD_3 => Numpad3,
+    NUMPAD_4 => Numpad4,
+    NUMPAD_5 => Numpad5,
+    NUMPAD_6 => Numpad6,
+    NUMPAD_7 => Numpad7,
+    NUMPAD_8 => Numpad8,
+    NUMPAD_9 => Numpad9,
+    NUMPAD_ADD => NumpadAdd,
+    NUMPAD_SUBTRACT => NumpadSubtract,
+    NUMPAD_MULTIPLY => NumpadMultiply,
+    NUMPAD_DIVIDE => NumpadDivide,
+    NUMPAD_DECIMAL => NumpadDecimal,
+    NUMPAD_ENTER => NumpadEnter,
+    PAUSE => Pause,
+    PRINT_SCREEN => PrintScreen,
+    SCROLL_LOCK => ScrollLock,
+    CONTEXT_MENU => ContextMenu,
+    HELP => Help,
+}
+
+impl Key {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn is_known(name: &str) -> bool {
+        name.chars().count() == 1 || Self::KNOWN.contains(&name)
+    }
+
+    fn is_valid(s: &str) -> bool {
+        let (modifiers, key) = match s.strip_suffix("++") {
+            Some(modifiers) => (modifiers, "+"),
+            None => match s.rfind('+') {
+                Some(i) if i > 0 => (&s[..i], &s[i + 1..]),
+                _ => ("", s),
+            },
+        };
+        Self::is_known(key) && (modifiers.is_empty() || modifiers.split('+').all(Self::is_known))
+    }
+}
+
+impl FromStr for Key {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if Self::is_valid(s) {
+            Ok(Self(Cow::Owned(s.to_owned())))
+        } else {
+            Err(Error::InvalidArgument {
+                message: format!("Unknown key: {:?}", s),
+            })
+        }
+    }
+}
+
+impl From<&str> for Key {
+    fn from(s: &str) -> Self {
+        Self(Cow::Owned(s.to_owned()))
+    }
+}
+
+impl From<String> for Key {
+    fn from(s: String) -> Self {
+        Self(Cow::Owned(s))
+    }
+}
+
+impl From<&Key> for Key {
+    fn from(k: &Key) -> Self {
+        k.clone()
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Builds a [`Key`] from well-known key names joined with `+`. A misspelled name fails to compile.
+///
+/// ```
+/// # use playwright::{api::Key, keys};
+/// assert_eq!(keys!(Enter), Key::ENTER);
+/// assert_eq!(keys!(Control + Shift + KeyT).as_str(), "Control+Shift+KeyT");
+/// ```
+#[macro_export]
+macro_rules! keys {
+    ($first:ident $(+ $rest:ident)*) => {
+        $crate::api::Key::from(
+            [
+                $crate::api::input_device::known_keys::$first,
+                $($crate::api::input_device::known_keys::$rest),*
+            ]
+            .join("+"),
+        )
+    };
+}
+
 impl Keyboard {
     pub(crate) fn new(inner: Weak<PageImpl>) -> Self {
         Self { inner }
@@ -140,9 +357,9 @@ impl Keyboard {
     }
 
     /// Shortcut for [`method: Keyboard.down`] and [`method: Keyboard.up`].
-    pub async fn press(&self, key: &str, delay: Option<f64>) -> Result<(), Arc<Error>> {
+    pub async fn press(&self, key: impl Into<Key>, delay: Option<f64>) -> Result<(), Arc<Error>> {
         let inner = upgrade(&self.inner)?;
-        inner.key_press(key, delay).await
+        inner.key_press(key.into().as_str(), delay).await
     }
 }
 
@@ -230,3 +447,31 @@ macro_rules! clicker {
 
 clicker!(ClickBuilder, click, mouse_click);
 clicker!(DblClickBuilder, dblclick, mouse_dblclick);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_names_are_validated() {
+        assert_eq!(Key::ENTER.as_str(), "Enter");
+        assert!(matches!(
+            Key::from_str("InvalidKey"),
+            Err(Error::InvalidArgument { .. })
+        ));
+        for k in &[
+            "a",
+            "+",
+            "ArrowLeft",
+            "Shift+KeyA",
+            "Control+Shift+T",
+            "Shift++",
+        ] {
+            assert_eq!(Key::from_str(k).unwrap().as_str(), *k);
+        }
+        assert!(Key::from_str("Shift+Foo").is_err());
+        assert!(Key::from_str("").is_err());
+        assert_eq!(Key::from("InvalidKey").as_str(), "InvalidKey");
+        assert_eq!(keys!(Control + ArrowLeft).as_str(), "Control+ArrowLeft");
+    }
+}
@@ -1,14 +1,24 @@
-use crate::api::File;
+use crate::api::{ElementHandle, File, Key};
+pub use crate::imp::{
+    frame::{GetByRoleOptions, RoleName},
+    locator::XPathFallbackMode,
+};
 use crate::{
     imp::{
         core::*,
         element_handle::SetInputFilesArgs,
+        frame::{
+            build_alt_text_selector, build_label_selector, build_placeholder_selector,
+            build_role_selector, build_test_id_selector, build_text_selector, build_title_selector,
+            Frame as FrameImpl,
+        },
         locator::{
-            CheckArgs, ClearArgs, ClickArgs, FillArgs, FilterOptions, HoverArgs,
+            append_filters, CheckArgs, ClearArgs, ClickArgs, FillArgs, FilterOptions, HoverArgs,
             Locator as LocatorImpl, PressArgs, SelectOptionArgs, TypeArgs,
         },
+        page::ScreenshotMask,
         prelude::*,
-        utils::{KeyboardModifier, MouseButton, Position},
+        utils::{FloatRect, KeyboardModifier, MouseButton, Position},
     },
     Error,
 };
@@ -16,29 +26,130 @@ use crate::{
 /// Locators are the central piece of Playwright's auto-waiting and retry-ability.
 /// In a nutshell, locators represent a way to find element(s) on the page at any moment.
 /// Locators are created with the page.locator() method.
+///
+/// Cloning a locator is cheap and the clone stays usable after the original is dropped.
 #[derive(Debug, Clone)]
 pub struct Locator {
-    inner: Weak<LocatorImpl>,
+    inner: Arc<LocatorImpl>,
+    xpath_fallback: Option<XPathFallbackMode>,
+}
+
+/// Options for [`Page::locator_with_options`](crate::api::Page::locator_with_options) that narrow down the matched
+/// elements without a separate `filter` call.
+#[derive(Debug, Default, Clone)]
+pub struct LocatorOptions<'a> {
+    /// Matches elements containing specified text somewhere inside, possibly in a child or a descendant element.
+    /// Matching is case-insensitive and searches for a substring.
+    pub has_text: Option<String>,
+    /// Matches elements that do not contain specified text somewhere inside.
+    pub has_not_text: Option<String>,
+    /// Matches elements containing an element that matches an inner locator. The inner locator is queried against the
+    /// outer one.
+    pub has: Option<&'a Locator>,
+    /// Matches elements that do not contain an element that matches an inner locator.
+    pub has_not: Option<&'a Locator>,
+}
+
+impl LocatorOptions<'_> {
+    /// Appends the filters to `selector`. The `has` and `has_not` locators must belong to `frame`.
+    pub(crate) fn selector(
+        &self,
+        frame: &Weak<FrameImpl>,
+        selector: &str,
+    ) -> Result<String, Error> {
+        let inner_selector =
+            |locator: Option<&Locator>| locator.map(|l| l.inner.inner_selector(frame)).transpose();
+        Ok(append_filters(
+            selector,
+            self.has_text.as_deref(),
+            self.has_not_text.as_deref(),
+            inner_selector(self.has)?.as_deref(),
+            inner_selector(self.has_not)?.as_deref(),
+        ))
+    }
 }
 
 impl PartialEq for Locator {
     fn eq(&self, other: &Self) -> bool {
-        let a = self.inner.upgrade();
-        let b = other.inner.upgrade();
-        a.and_then(|a| b.map(|b| (a, b)))
-            .map(|(a, b)| a.guid() == b.guid())
-            .unwrap_or_default()
+        self.inner.same_target(&other.inner)
     }
 }
 
 impl Locator {
-    pub(crate) fn new(inner: Weak<LocatorImpl>) -> Self {
-        Self { inner }
+    pub(crate) fn new(inner: Arc<LocatorImpl>) -> Self {
+        Self {
+            inner,
+            xpath_fallback: None,
+        }
+    }
+
+    /// Returns this locator with [`text_content`](Locator::text_content) handling XPath selectors that are known to
+    /// hang the driver as `mode` says, instead of as [`XPathFallbackMode::default`]. Locators created from the returned
+    /// one use the default again.
+    pub fn with_xpath_fallback(&self, mode: XPathFallbackMode) -> Locator {
+        Self {
+            inner: self.inner.clone(),
+            xpath_fallback: Some(mode),
+        }
     }
 
     /// Returns the locator selector.
     pub fn selector(&self) -> Result<String, Error> {
-        Ok(upgrade(&self.inner)?.selector().to_string())
+        Ok(self.inner.selector().to_string())
+    }
+
+    pub(crate) fn mask(&self) -> Result<ScreenshotMask, Error> {
+        self.inner.mask()
+    }
+
+    pub(crate) async fn clip(&self) -> ArcResult<FloatRect> {
+        self.inner.bounding_box().await
+    }
+
+    fn child(&self, selector: &str) -> Locator {
+        Locator::new(self.inner.child(selector))
+    }
+
+    /// Allows locating elements by their [ARIA role](https://www.w3.org/TR/wai-aria-1.2/#roles) within the subtree of
+    /// this locator.
+    ///
+    /// ```js
+    /// await page.locator('nav').getByRole('link', { name: 'Home' }).click();
+    /// ```
+    pub fn get_by_role(&self, role: &str, options: GetByRoleOptions) -> Locator {
+        self.child(&build_role_selector(role, &options))
+    }
+
+    /// Allows locating elements that contain given text within the subtree of this locator. By default, matching is
+    /// case-insensitive and searches for a substring, use `exact` to control this behavior.
+    pub fn get_by_text(&self, text: &str, exact: Option<bool>) -> Locator {
+        self.child(&build_text_selector(text, exact))
+    }
+
+    /// Allows locating input elements by the text of the associated `<label>` or `aria-labelledby` element, or by the
+    /// `aria-label` attribute.
+    pub fn get_by_label(&self, text: &str, exact: Option<bool>) -> Locator {
+        self.child(&build_label_selector(text, exact))
+    }
+
+    /// Allows locating input elements by the placeholder text.
+    pub fn get_by_placeholder(&self, text: &str, exact: Option<bool>) -> Locator {
+        self.child(&build_placeholder_selector(text, exact))
+    }
+
+    /// Locate element by the test id. By default, the `data-testid` attribute is used as a test id.
+    pub fn get_by_test_id(&self, test_id: &str) -> Locator {
+        self.child(&build_test_id_selector(test_id))
+    }
+
+    /// Allows locating elements by their alt text.
+    pub fn get_by_alt_text(&self, text: &str, exact: Option<bool>) -> Locator {
+        self.child(&build_alt_text_selector(text, exact))
+    }
+
+    /// Allows locating elements by their title attribute.
+    pub fn get_by_title(&self, text: &str, exact: Option<bool>) -> Locator {
+        self.child(&build_title_selector(text, exact))
     }
 
     // Action methods
@@ -53,7 +164,8 @@ impl Locator {
         LocatorDblClickBuilder::new(self.inner.clone())
     }
 
-    /// Fill a form control.
+    /// Fill a form control. On a `<select>` element this selects the option whose value is `value`, as
+    /// [`Locator::select_option_builder`] does.
     pub fn fill_builder<'a>(&self, value: &'a str) -> LocatorFillBuilder<'a> {
         LocatorFillBuilder::new(self.inner.clone(), value)
     }
@@ -73,8 +185,8 @@ impl Locator {
         LocatorUncheckBuilder::new(self.inner.clone())
     }
 
-    /// Press a key.
-    pub fn press_builder<'a>(&self, key: &'a str) -> LocatorPressBuilder<'a> {
+    /// Press a key, given either as a [`Key`] or as its name.
+    pub fn press_builder(&self, key: impl Into<Key>) -> LocatorPressBuilder {
         LocatorPressBuilder::new(self.inner.clone(), key)
     }
 
@@ -85,18 +197,18 @@ impl Locator {
 
     /// Focus on the element.
     pub async fn focus(&self, timeout: Option<f64>) -> Result<(), Error> {
-        upgrade(&self.inner)?
+        self.inner
             .focus(timeout)
             .await
-            .map_err(|_| Error::ObjectNotFound)
+            .map_err(|e| self.inner.error(e))
     }
 
     /// Remove focus from the element.
     pub async fn blur(&self, timeout: Option<f64>) -> Result<(), Error> {
-        upgrade(&self.inner)?
+        self.inner
             .blur(timeout)
             .await
-            .map_err(|_| Error::ObjectNotFound)
+            .map_err(|e| self.inner.error(e))
     }
 
     /// Clear the input field.
@@ -109,6 +221,21 @@ impl Locator {
         LocatorTypeBuilder::new(self.inner.clone(), text)
     }
 
+    /// Same as [`Locator::type_builder`] without the raw identifier: finish with
+    /// [`LocatorTypeBuilder::type_text`].
+    pub fn type_text_builder<'a>(&self, text: &'a str) -> LocatorTypeBuilder<'a> {
+        self.type_builder(text)
+    }
+
+    /// Focus the element and press a key for each character of `text`. Same as [`Locator::type_builder`] under
+    /// its current Playwright name.
+    pub fn press_sequentially_builder<'a>(
+        &self,
+        text: &'a str,
+    ) -> LocatorPressSequentiallyBuilder<'a> {
+        LocatorPressSequentiallyBuilder::new(self.inner.clone(), text)
+    }
+
     /// Select option(s) from a `<select>` element.
     pub fn select_option_builder(&self) -> LocatorSelectOptionBuilder {
         LocatorSelectOptionBuilder::new(self.inner.clone())
@@ -116,28 +243,30 @@ impl Locator {
 
     // Query methods
 
-    /// Get the text content of the element.
+    /// Get the text content of the element. XPath selectors that are known to hang the driver are handled as set by
+    /// [`Locator::with_xpath_fallback`].
     pub async fn text_content(&self, timeout: Option<f64>) -> Result<Option<String>, Error> {
-        upgrade(&self.inner)?
-            .text_content(timeout)
+        let xpath_fallback = self.xpath_fallback.unwrap_or_default();
+        self.inner
+            .text_content(timeout, xpath_fallback)
             .await
-            .map_err(|_e| Error::ObjectNotFound)
+            .map_err(|e| self.inner.error(e))
     }
 
     /// Get the inner text of the element.
     pub async fn inner_text(&self, timeout: Option<f64>) -> Result<String, Error> {
-        upgrade(&self.inner)?
+        self.inner
             .inner_text(timeout)
             .await
-            .map_err(|_| Error::ObjectNotFound)
+            .map_err(|e| self.inner.error(e))
     }
 
     /// Get the inner HTML of the element.
     pub async fn inner_html(&self, timeout: Option<f64>) -> Result<String, Error> {
-        upgrade(&self.inner)?
+        self.inner
             .inner_html(timeout)
             .await
-            .map_err(|_| Error::ObjectNotFound)
+            .map_err(|e| self.inner.error(e))
     }
 
     /// Get an attribute value.
@@ -146,105 +275,123 @@ impl Locator {
         name: &str,
         timeout: Option<f64>,
     ) -> Result<Option<String>, Error> {
-        upgrade(&self.inner)?
+        self.inner
             .get_attribute(name, timeout)
             .await
-            .map_err(|_| Error::ObjectNotFound)
+            .map_err(|e| self.inner.error(e))
     }
 
     /// Get the input value (for form controls).
     pub async fn input_value(&self, timeout: Option<f64>) -> Result<String, Error> {
-        upgrade(&self.inner)?
+        self.inner
             .input_value(timeout)
             .await
-            .map_err(|_| Error::ObjectNotFound)
+            .map_err(|e| self.inner.error(e))
+    }
+
+    /// Get the values of all selected options of a `<select multiple>`. For a single `<select>`, an `<input>` or a
+    /// `<textarea>` the only value is returned, the same as [`Locator::input_value`].
+    pub async fn input_values(&self, timeout: Option<f64>) -> Result<Vec<String>, Error> {
+        self.inner
+            .input_values(timeout)
+            .await
+            .map_err(|e| self.inner.error(e))
     }
 
     /// Get the count of matching elements.
     pub async fn count(&self) -> Result<usize, Error> {
-        upgrade(&self.inner)?
-            .count()
-            .await
-            .map_err(|_| Error::ObjectNotFound)
+        self.inner.count().await.map_err(|e| self.inner.error(e))
     }
 
     // State methods
 
     /// Check if the element is visible.
     pub async fn is_visible(&self, timeout: Option<f64>) -> Result<bool, Error> {
-        upgrade(&self.inner)?
+        self.inner
             .is_visible(timeout)
             .await
-            .map_err(|_| Error::ObjectNotFound)
+            .map_err(|e| self.inner.error(e))
     }
 
     /// Check if the element is hidden.
     pub async fn is_hidden(&self, timeout: Option<f64>) -> Result<bool, Error> {
-        upgrade(&self.inner)?
+        self.inner
             .is_hidden(timeout)
             .await
-            .map_err(|_| Error::ObjectNotFound)
+            .map_err(|e| self.inner.error(e))
     }
 
     /// Check if the element is enabled.
     pub async fn is_enabled(&self, timeout: Option<f64>) -> Result<bool, Error> {
-        upgrade(&self.inner)?
+        self.inner
             .is_enabled(timeout)
             .await
-            .map_err(|_| Error::ObjectNotFound)
+            .map_err(|e| self.inner.error(e))
     }
 
     /// Check if the element is disabled.
     pub async fn is_disabled(&self, timeout: Option<f64>) -> Result<bool, Error> {
-        upgrade(&self.inner)?
+        self.inner
             .is_disabled(timeout)
             .await
-            .map_err(|_| Error::ObjectNotFound)
+            .map_err(|e| self.inner.error(e))
     }
 
     /// Check if the element is checked.
     pub async fn is_checked(&self, timeout: Option<f64>) -> Result<bool, Error> {
-        upgrade(&self.inner)?
+        self.inner
             .is_checked(timeout)
             .await
-            .map_err(|_| Error::ObjectNotFound)
+            .map_err(|e| self.inner.error(e))
     }
 
     /// Check if the element is editable.
     pub async fn is_editable(&self, timeout: Option<f64>) -> Result<bool, Error> {
-        upgrade(&self.inner)?
+        self.inner
             .is_editable(timeout)
             .await
-            .map_err(|_| Error::ObjectNotFound)
+            .map_err(|e| self.inner.error(e))
     }
 
     // Chaining methods
 
     /// Select the first matching element.
     pub async fn first(&self) -> Result<Locator, Error> {
-        upgrade(&self.inner)?
+        self.inner
             .first()
             .await
             .map(Locator::new)
-            .map_err(|_| Error::ObjectNotFound)
+            .map_err(|e| self.inner.error(e))
     }
 
     /// Select the last matching element.
     pub async fn last(&self) -> Result<Locator, Error> {
-        upgrade(&self.inner)?
+        self.inner
             .last()
             .await
             .map(Locator::new)
-            .map_err(|_| Error::ObjectNotFound)
+            .map_err(|e| self.inner.error(e))
     }
 
     /// Select the nth matching element.
     pub async fn nth(&self, index: i32) -> Result<Locator, Error> {
-        upgrade(&self.inner)?
+        self.inner
             .nth(index)
             .await
             .map(Locator::new)
-            .map_err(|_| Error::ObjectNotFound)
+            .map_err(|e| self.inner.error(e))
+    }
+
+    /// Creates a locator matching all elements that match this locator, `locator`, or both. An element matched by both
+    /// is only counted once. Fails with [`Error::InvalidArgument`] if the locators belong to different frames.
+    ///
+    /// ```js
+    /// const newEmail = page.getByRole('button', { name: 'New' });
+    /// const dialog = page.getByText('Confirm security settings');
+    /// await expect(newEmail.or(dialog).first()).toBeVisible();
+    /// ```
+    pub fn or(&self, locator: &Locator) -> Result<Locator, Error> {
+        self.inner.or(&locator.inner).map(Locator::new)
     }
 
     /// Filter the locator to match only elements that meet certain criteria.
@@ -272,12 +419,12 @@ impl Locator {
 // Builder implementations
 
 pub struct LocatorClickBuilder {
-    inner: Weak<LocatorImpl>,
+    inner: Arc<LocatorImpl>,
     args: ClickArgs,
 }
 
 impl LocatorClickBuilder {
-    pub(crate) fn new(inner: Weak<LocatorImpl>) -> Self {
+    pub(crate) fn new(inner: Arc<LocatorImpl>) -> Self {
         Self {
             inner,
             args: ClickArgs::default(),
@@ -286,10 +433,7 @@ impl LocatorClickBuilder {
 
     pub async fn click(self) -> Result<(), Error> {
         let Self { inner, args } = self;
-        upgrade(&inner)?
-            .click(args)
-            .await
-            .map_err(|_| Error::ObjectNotFound)
+        inner.click(args).await.map_err(|e| inner.error(e))
     }
 
     setter! {
@@ -308,18 +452,22 @@ impl LocatorClickBuilder {
         /// Whether to skip waiting after the action
         no_wait_after: Option<bool>,
         /// Maximum time to wait for the action
-        timeout: Option<f64>
+        timeout: Option<f64>,
+        /// When set, this method only performs the actionability checks and skips the action
+        trial: Option<bool>,
+        /// When set, fails with [`Error::AmbiguousMatch`] if the locator resolves to more than one element
+        strict: Option<bool>
     }
 }
 
 pub struct LocatorFillBuilder<'a> {
-    inner: Weak<LocatorImpl>,
+    inner: Arc<LocatorImpl>,
     value: &'a str,
     args: FillArgs,
 }
 
 impl<'a> LocatorFillBuilder<'a> {
-    pub(crate) fn new(inner: Weak<LocatorImpl>, value: &'a str) -> Self {
+    pub(crate) fn new(inner: Arc<LocatorImpl>, value: &'a str) -> Self {
         Self {
             inner,
             value,
@@ -329,10 +477,7 @@ impl<'a> LocatorFillBuilder<'a> {
 
     pub async fn fill(self) -> Result<(), Error> {
         let Self { inner, value, args } = self;
-        upgrade(&inner)?
-            .fill(value, args)
-            .await
-            .map_err(|_| Error::ObjectNotFound)
+        inner.fill(value, args).await.map_err(|e| inner.error(e))
     }
 
     setter! {
@@ -341,17 +486,19 @@ impl<'a> LocatorFillBuilder<'a> {
         /// Whether to skip waiting after the action
         no_wait_after: Option<bool>,
         /// Maximum time to wait for the action
-        timeout: Option<f64>
+        timeout: Option<f64>,
+        /// When set, fails with [`Error::AmbiguousMatch`] if the locator resolves to more than one element
+        strict: Option<bool>
     }
 }
 
 pub struct LocatorHoverBuilder {
-    inner: Weak<LocatorImpl>,
+    inner: Arc<LocatorImpl>,
     args: HoverArgs,
 }
 
 impl LocatorHoverBuilder {
-    pub(crate) fn new(inner: Weak<LocatorImpl>) -> Self {
+    pub(crate) fn new(inner: Arc<LocatorImpl>) -> Self {
         Self {
             inner,
             args: HoverArgs::default(),
@@ -360,10 +507,7 @@ impl LocatorHoverBuilder {
 
     pub async fn hover(self) -> Result<(), Error> {
         let Self { inner, args } = self;
-        upgrade(&inner)?
-            .hover(args)
-            .await
-            .map_err(|_| Error::ObjectNotFound)
+        inner.hover(args).await.map_err(|e| inner.error(e))
     }
 
     setter! {
@@ -373,18 +517,24 @@ impl LocatorHoverBuilder {
         modifiers: Option<Vec<KeyboardModifier>>,
         /// Whether to bypass actionability checks
         force: Option<bool>,
+        /// Whether to skip waiting after the action
+        no_wait_after: Option<bool>,
         /// Maximum time to wait for the action
-        timeout: Option<f64>
+        timeout: Option<f64>,
+        /// When set, this method only performs the actionability checks and skips the action
+        trial: Option<bool>,
+        /// When set, fails with [`Error::AmbiguousMatch`] if the locator resolves to more than one element
+        strict: Option<bool>
     }
 }
 
 pub struct LocatorCheckBuilder {
-    inner: Weak<LocatorImpl>,
+    inner: Arc<LocatorImpl>,
     args: CheckArgs,
 }
 
 impl LocatorCheckBuilder {
-    pub(crate) fn new(inner: Weak<LocatorImpl>) -> Self {
+    pub(crate) fn new(inner: Arc<LocatorImpl>) -> Self {
         Self {
             inner,
             args: CheckArgs::default(),
@@ -393,10 +543,7 @@ impl LocatorCheckBuilder {
 
     pub async fn check(self) -> Result<(), Error> {
         let Self { inner, args } = self;
-        upgrade(&inner)?
-            .check(args)
-            .await
-            .map_err(|_| Error::ObjectNotFound)
+        inner.check(args).await.map_err(|e| inner.error(e))
     }
 
     setter! {
@@ -407,17 +554,22 @@ impl LocatorCheckBuilder {
         /// Whether to skip waiting after the action
         no_wait_after: Option<bool>,
         /// Maximum time to wait for the action
-        timeout: Option<f64>
+        timeout: Option<f64>,
+        /// When set, this method only performs the actionability checks and skips the action
+        trial: Option<bool>,
+        /// When set, fails with [`Error::AmbiguousMatch`] if the locator resolves to more than one element, and with
+        /// [`Error::ElementNotCheckable`] unless the element is a checkbox
+        strict: Option<bool>
     }
 }
 
 pub struct LocatorUncheckBuilder {
-    inner: Weak<LocatorImpl>,
+    inner: Arc<LocatorImpl>,
     args: CheckArgs,
 }
 
 impl LocatorUncheckBuilder {
-    pub(crate) fn new(inner: Weak<LocatorImpl>) -> Self {
+    pub(crate) fn new(inner: Arc<LocatorImpl>) -> Self {
         Self {
             inner,
             args: CheckArgs::default(),
@@ -426,10 +578,7 @@ impl LocatorUncheckBuilder {
 
     pub async fn uncheck(self) -> Result<(), Error> {
         let Self { inner, args } = self;
-        upgrade(&inner)?
-            .uncheck(args)
-            .await
-            .map_err(|_| Error::ObjectNotFound)
+        inner.uncheck(args).await.map_err(|e| inner.error(e))
     }
 
     setter! {
@@ -440,31 +589,36 @@ impl LocatorUncheckBuilder {
         /// Whether to skip waiting after the action
         no_wait_after: Option<bool>,
         /// Maximum time to wait for the action
-        timeout: Option<f64>
+        timeout: Option<f64>,
+        /// When set, this method only performs the actionability checks and skips the action
+        trial: Option<bool>,
+        /// When set, fails with [`Error::AmbiguousMatch`] if the locator resolves to more than one element, and with
+        /// [`Error::ElementNotCheckable`] unless the element is a checkbox
+        strict: Option<bool>
     }
 }
 
-pub struct LocatorPressBuilder<'a> {
-    inner: Weak<LocatorImpl>,
-    key: &'a str,
+pub struct LocatorPressBuilder {
+    inner: Arc<LocatorImpl>,
+    key: Key,
     args: PressArgs,
 }
 
-impl<'a> LocatorPressBuilder<'a> {
-    pub(crate) fn new(inner: Weak<LocatorImpl>, key: &'a str) -> Self {
+impl LocatorPressBuilder {
+    pub(crate) fn new(inner: Arc<LocatorImpl>, key: impl Into<Key>) -> Self {
         Self {
             inner,
-            key,
+            key: key.into(),
             args: PressArgs::default(),
         }
     }
 
     pub async fn press(self) -> Result<(), Error> {
         let Self { inner, key, args } = self;
-        upgrade(&inner)?
-            .press(key, args)
+        inner
+            .press(key.as_str(), args)
             .await
-            .map_err(|_| Error::ObjectNotFound)
+            .map_err(|e| inner.error(e))
     }
 
     setter! {
@@ -473,17 +627,19 @@ impl<'a> LocatorPressBuilder<'a> {
         /// Whether to skip waiting after the action
         no_wait_after: Option<bool>,
         /// Maximum time to wait for the action
-        timeout: Option<f64>
+        timeout: Option<f64>,
+        /// When set, fails with [`Error::AmbiguousMatch`] if the locator resolves to more than one element
+        strict: Option<bool>
     }
 }
 
 pub struct LocatorFilterBuilder {
-    inner: Weak<LocatorImpl>,
+    inner: Arc<LocatorImpl>,
     args: FilterOptions,
 }
 
 impl LocatorFilterBuilder {
-    pub(crate) fn new(inner: Weak<LocatorImpl>) -> Self {
+    pub(crate) fn new(inner: Arc<LocatorImpl>) -> Self {
         Self {
             inner,
             args: FilterOptions::default(),
@@ -492,11 +648,11 @@ impl LocatorFilterBuilder {
 
     pub async fn filter(self) -> Result<Locator, Error> {
         let Self { inner, args } = self;
-        upgrade(&inner)?
+        inner
             .filter(args)
             .await
             .map(Locator::new)
-            .map_err(|_| Error::ObjectNotFound)
+            .map_err(|e| inner.error(e))
     }
 
     setter! {
@@ -509,15 +665,39 @@ impl LocatorFilterBuilder {
         /// Filter to elements not matching this selector
         has_not: Option<String>
     }
+
+    /// Filter to elements containing an element matched by `locator`, relative to the filtered elements. `locator`
+    /// must belong to the same frame and replaces a `has` selector.
+    pub fn has_locator(mut self, locator: &Locator) -> Self {
+        self.args.has_locator = Some(locator.inner.clone());
+        self
+    }
+
+    pub fn clear_has_locator(mut self) -> Self {
+        self.args.has_locator = None;
+        self
+    }
+
+    /// Filter to elements not containing an element matched by `locator`. `locator` must belong to the same frame and
+    /// replaces a `has_not` selector.
+    pub fn has_not_locator(mut self, locator: &Locator) -> Self {
+        self.args.has_not_locator = Some(locator.inner.clone());
+        self
+    }
+
+    pub fn clear_has_not_locator(mut self) -> Self {
+        self.args.has_not_locator = None;
+        self
+    }
 }
 
 pub struct LocatorSetInputFilesBuilder {
-    inner: Weak<LocatorImpl>,
+    inner: Arc<LocatorImpl>,
     args: SetInputFilesArgs,
 }
 
 impl LocatorSetInputFilesBuilder {
-    pub(crate) fn new(inner: Weak<LocatorImpl>, file: File) -> Self {
+    pub(crate) fn new(inner: Arc<LocatorImpl>, file: File) -> Self {
         Self {
             inner,
             args: SetInputFilesArgs {
@@ -530,10 +710,10 @@ impl LocatorSetInputFilesBuilder {
 
     pub async fn set_input_files(self) -> Result<(), Error> {
         let Self { inner, args } = self;
-        upgrade(&inner)?
+        inner
             .set_input_files(args)
             .await
-            .map_err(|_| Error::ObjectNotFound)
+            .map_err(|e| inner.error(e))
     }
 
     setter! {
@@ -551,12 +731,12 @@ impl LocatorSetInputFilesBuilder {
 }
 
 pub struct LocatorDblClickBuilder {
-    inner: Weak<LocatorImpl>,
+    inner: Arc<LocatorImpl>,
     args: ClickArgs,
 }
 
 impl LocatorDblClickBuilder {
-    pub(crate) fn new(inner: Weak<LocatorImpl>) -> Self {
+    pub(crate) fn new(inner: Arc<LocatorImpl>) -> Self {
         Self {
             inner,
             args: ClickArgs::default(),
@@ -565,10 +745,7 @@ impl LocatorDblClickBuilder {
 
     pub async fn dblclick(self) -> Result<(), Error> {
         let Self { inner, args } = self;
-        upgrade(&inner)?
-            .dblclick(args)
-            .await
-            .map_err(|_| Error::ObjectNotFound)
+        inner.dblclick(args).await.map_err(|e| inner.error(e))
     }
 
     setter! {
@@ -587,17 +764,21 @@ impl LocatorDblClickBuilder {
         /// Whether to skip waiting after the action
         no_wait_after: Option<bool>,
         /// Maximum time to wait for the action
-        timeout: Option<f64>
+        timeout: Option<f64>,
+        /// When set, this method only performs the actionability checks and skips the action
+        trial: Option<bool>,
+        /// When set, fails with [`Error::AmbiguousMatch`] if the locator resolves to more than one element
+        strict: Option<bool>
     }
 }
 
 pub struct LocatorClearBuilder {
-    inner: Weak<LocatorImpl>,
+    inner: Arc<LocatorImpl>,
     args: ClearArgs,
 }
 
 impl LocatorClearBuilder {
-    pub(crate) fn new(inner: Weak<LocatorImpl>) -> Self {
+    pub(crate) fn new(inner: Arc<LocatorImpl>) -> Self {
         Self {
             inner,
             args: ClearArgs::default(),
@@ -606,10 +787,7 @@ impl LocatorClearBuilder {
 
     pub async fn clear(self) -> Result<(), Error> {
         let Self { inner, args } = self;
-        upgrade(&inner)?
-            .clear(args)
-            .await
-            .map_err(|_| Error::ObjectNotFound)
+        inner.clear(args).await.map_err(|e| inner.error(e))
     }
 
     setter! {
@@ -623,13 +801,13 @@ impl LocatorClearBuilder {
 }
 
 pub struct LocatorTypeBuilder<'a> {
-    inner: Weak<LocatorImpl>,
+    inner: Arc<LocatorImpl>,
     text: &'a str,
     args: TypeArgs,
 }
 
 impl<'a> LocatorTypeBuilder<'a> {
-    pub(crate) fn new(inner: Weak<LocatorImpl>, text: &'a str) -> Self {
+    pub(crate) fn new(inner: Arc<LocatorImpl>, text: &'a str) -> Self {
         Self {
             inner,
             text,
@@ -637,12 +815,15 @@ impl<'a> LocatorTypeBuilder<'a> {
         }
     }
 
-    pub async fn r#type(self) -> Result<(), Error> {
+    /// Focuses the element and sends a keydown, keypress/input and keyup event for each character of the text.
+    pub async fn type_text(self) -> Result<(), Error> {
         let Self { inner, text, args } = self;
-        upgrade(&inner)?
-            .r#type(text, args)
-            .await
-            .map_err(|_| Error::ObjectNotFound)
+        inner.r#type(text, args).await.map_err(|e| inner.error(e))
+    }
+
+    #[deprecated(note = "use `type_text`")]
+    pub async fn r#type(self) -> Result<(), Error> {
+        self.type_text().await
     }
 
     setter! {
@@ -651,29 +832,65 @@ impl<'a> LocatorTypeBuilder<'a> {
         /// Whether to skip waiting after the action
         no_wait_after: Option<bool>,
         /// Maximum time to wait for the action
-        timeout: Option<f64>
+        timeout: Option<f64>,
+        /// When set, fails with [`Error::AmbiguousMatch`] if the locator resolves to more than one element
+        strict: Option<bool>
+    }
+}
+
+pub struct LocatorPressSequentiallyBuilder<'a> {
+    inner: Arc<LocatorImpl>,
+    text: &'a str,
+    args: TypeArgs,
+}
+
+impl<'a> LocatorPressSequentiallyBuilder<'a> {
+    pub(crate) fn new(inner: Arc<LocatorImpl>, text: &'a str) -> Self {
+        Self {
+            inner,
+            text,
+            args: TypeArgs::default(),
+        }
+    }
+
+    pub async fn press_sequentially(self) -> Result<(), Error> {
+        let Self { inner, text, args } = self;
+        inner.r#type(text, args).await.map_err(|e| inner.error(e))
+    }
+
+    setter! {
+        /// Time to wait between key presses
+        delay: Option<f64>,
+        /// Whether to skip waiting after the action
+        no_wait_after: Option<bool>,
+        /// Maximum time to wait for the action
+        timeout: Option<f64>,
+        /// When set, fails with [`Error::AmbiguousMatch`] if the locator resolves to more than one element
+        strict: Option<bool>
     }
 }
 
 pub struct LocatorSelectOptionBuilder {
-    inner: Weak<LocatorImpl>,
+    inner: Arc<LocatorImpl>,
     args: SelectOptionArgs,
+    err: Option<Error>,
 }
 
 impl LocatorSelectOptionBuilder {
-    pub(crate) fn new(inner: Weak<LocatorImpl>) -> Self {
+    pub(crate) fn new(inner: Arc<LocatorImpl>) -> Self {
         Self {
             inner,
             args: SelectOptionArgs::default(),
+            err: None,
         }
     }
 
     pub async fn select_option(self) -> Result<Vec<String>, Error> {
-        let Self { inner, args } = self;
-        upgrade(&inner)?
-            .select_option(args)
-            .await
-            .map_err(|_| Error::ObjectNotFound)
+        let Self { inner, args, err } = self;
+        if let Some(e) = err {
+            return Err(e);
+        }
+        inner.select_option(args).await.map_err(|e| inner.error(e))
     }
 
     /// Select options by their values
@@ -694,6 +911,26 @@ impl LocatorSelectOptionBuilder {
         self
     }
 
+    /// Select every option of a `<select multiple>`, as listed when the action runs. The element must already be
+    /// attached then, since its options are read before waiting for actionability.
+    pub fn select_all(mut self) -> Self {
+        self.args.all = true;
+        self
+    }
+
+    /// Select the given `<option>` elements
+    pub fn element_handles(mut self, handles: Vec<ElementHandle>) -> Self {
+        let guids = handles
+            .iter()
+            .map(|h| h.guid().map(|guid| OnlyGuid { guid }))
+            .collect::<Result<Vec<_>, _>>();
+        match guids {
+            Ok(guids) => self.args.element_handles = Some(guids),
+            Err(e) => self.err = Some(e),
+        }
+        self
+    }
+
     setter! {
         /// Whether to bypass actionability checks
         force: Option<bool>,
@@ -1,19 +1,23 @@
 pub use crate::{
     api::{
+        browser_context::RouteFromHarOptions,
         frame::{
             AddScriptTagBuilder, CheckBuilder, ClickBuilder, DblClickBuilder, FillBuilder,
-            GotoBuilder, HoverBuilder, PressBuilder, SelectOptionBuilder, SetContentBuilder,
-            SetInputFilesBuilder, TapBuilder, TypeBuilder, UncheckBuilder, WaitForFunctionBuilder,
-            WaitForSelectorBuilder,
+            GetByRoleOptions, GotoBuilder, HoverBuilder, LocatorOptions, PressBuilder, RoleName,
+            SelectOptionBuilder, SetContentBuilder, SetContentOptions, SetInputFilesBuilder,
+            TapBuilder, TypeBuilder, UncheckBuilder, WaitForFunctionBuilder,
+            WaitForFunctionOptions, WaitForSelectorBuilder, WaitForSelectorOptions,
         },
+        route::{UnrouteAllOptions, UnrouteBehavior},
         Download, JsHandle, Request,
     },
-    imp::page::{EventType, Media},
+    imp::page::{EventType, Media, PageError},
 };
 use crate::{
     api::{
-        input_device::*, Accessibility, BrowserContext, ConsoleMessage, ElementHandle, Frame,
-        Keyboard, Locator, Response, TouchScreen, Video, WebSocket, Worker,
+        input_device::*, Accessibility, BrowserContext, ConsoleMessage, Dialog, ElementHandle,
+        FileChooser, Frame, FrameLocator, Keyboard, Locator, Response, Route, TouchScreen, Video,
+        WebSocket, Worker,
     },
     imp::{
         core::*,
@@ -21,8 +25,8 @@ use crate::{
         page::{EmulateMediaArgs, Evt, Page as Impl, PdfArgs, ReloadArgs, ScreenshotArgs},
         prelude::*,
         utils::{
-            ColorScheme, DocumentLoadState, File, FloatRect, Length, PdfMargins, ScreenshotType,
-            Viewport,
+            ColorScheme, DocumentLoadState, EvalSource, File, FloatRect, Length, PaperFormat,
+            PdfMargins, ScreenshotAnimations, ScreenshotType, Viewport,
         },
     },
     Error,
@@ -97,6 +101,32 @@ impl Page {
         }
     }
 
+    /// Inspects the accessibility tree of the page. Same as the `accessibility` field.
+    pub fn accessibility(&self) -> Accessibility {
+        self.accessibility.clone()
+    }
+
+    /// Indicates that the page has been closed. Also `true` once the page object has been released.
+    pub fn is_closed(&self) -> bool {
+        self.inner.upgrade().map(|p| p.is_closed()).unwrap_or(true)
+    }
+
+    /// Indicates that the page has crashed. Operations on a crashed page fail with [`Error::PageCrashed`].
+    pub fn is_crashed(&self) -> bool {
+        self.inner
+            .upgrade()
+            .map(|p| p.is_crashed())
+            .unwrap_or(false)
+    }
+
+    pub(crate) fn upgrade(&self) -> Result<Arc<Impl>, Error> {
+        let inner = upgrade(&self.inner)?;
+        if inner.is_crashed() {
+            return Err(Error::PageCrashed);
+        }
+        Ok(inner)
+    }
+
     pub fn context(&self) -> BrowserContext {
         BrowserContext::new(weak_and_then(&self.inner, |rc| rc.browser_context()))
     }
@@ -151,14 +181,14 @@ impl Page {
         GoForwardBuilder::new(self.inner.clone())
     }
 
-    pub async fn set_default_navigation_timeout(&self, timeout: u32) -> ArcResult<()> {
-        upgrade(&self.inner)?
+    pub async fn set_default_navigation_timeout(&self, timeout: f64) -> ArcResult<()> {
+        self.upgrade()?
             .set_default_navigation_timeout(timeout)
             .await
     }
 
-    pub async fn set_default_timeout(&self, timeout: u32) -> ArcResult<()> {
-        upgrade(&self.inner)?.set_default_timeout(timeout).await
+    pub async fn set_default_timeout(&self, timeout: f64) -> ArcResult<()> {
+        self.upgrade()?.set_default_timeout(timeout).await
     }
 
     pub fn viewport_size(&self) -> Result<Option<Viewport>, Error> {
@@ -171,7 +201,7 @@ impl Page {
     /// `page.setViewportSize` will resize the page. A lot of websites don't expect phones to change size, so you should set the
     /// viewport size before navigating to the page.
     pub async fn set_viewport_size(&self, viewport_size: Viewport) -> ArcResult<()> {
-        upgrade(&self.inner)?.set_viewport_size(viewport_size).await
+        self.upgrade()?.set_viewport_size(viewport_size).await
     }
 
     /// Video object associated with this page.
@@ -184,7 +214,7 @@ impl Page {
 
     /// Brings page to front (activates tab).
     pub async fn bring_to_front(&self) -> ArcResult<()> {
-        upgrade(&self.inner)?.bring_to_front().await
+        self.upgrade()?.bring_to_front().await
     }
 
     /// Adds a script which would be evaluated in one of the following scenarios:
@@ -210,7 +240,22 @@ impl Page {
     /// [`method: Page.addInitScript`] is not defined.
     pub async fn add_init_script(&self, source: &str) -> ArcResult<()> {
         // arg not supported
-        upgrade(&self.inner)?.add_init_script(source).await
+        self.upgrade()?.add_init_script(source).await
+    }
+
+    /// Same as [`Page::add_init_script`], but `script` is a function expression that gets called with `arg`. `arg` is
+    /// serialized as JSON, so it needs no escaping.
+    ///
+    /// ```js
+    /// (flags) => { window.flags = flags; }
+    /// ```
+    pub async fn add_init_script_with_arg<A>(&self, script: &str, arg: A) -> Result<(), Error>
+    where
+        A: Serialize,
+    {
+        let arg = serde_json::to_string(&arg)?;
+        let source = format!("({})({})", script, arg);
+        Ok(self.upgrade()?.add_init_script(&source).await?)
     }
 
     /// Returns the PDF buffer.
@@ -258,7 +303,7 @@ impl Page {
     ///
     /// > NOTE: `headerTemplate` and `footerTemplate` markup have the following limitations: > 1. Script tags inside templates
     /// are not evaluated. > 2. Page styles are not visible inside templates.
-    pub fn pdf_builder(&self) -> PdfBuilder<'_, '_, '_, '_, '_, '_, '_, '_, '_, '_> {
+    pub fn pdf_builder(&self) -> PdfBuilder<'_, '_, '_, '_, '_, '_, '_, '_, '_> {
         PdfBuilder::new(self.inner.clone())
     }
 
@@ -317,7 +362,7 @@ impl Page {
 
     /// Returns the opener for popup pages and `null` for others. If the opener has been closed already the returns `null`.
     pub async fn opener(&self) -> ArcResult<Option<Page>> {
-        Ok(upgrade(&self.inner)?.opener().await?.map(Page::new))
+        Ok(self.upgrade()?.opener().await?.map(Page::new))
     }
 
     /// The extra HTTP headers will be sent with every request the page initiates.
@@ -327,29 +372,396 @@ impl Page {
     where
         T: IntoIterator<Item = (String, String)>,
     {
-        upgrade(&self.inner)?.set_extra_http_headers(headers).await
+        self.upgrade()?.set_extra_http_headers(headers).await
     }
 
     pub async fn expect_event(&self, evt: EventType) -> Result<Event, Error> {
-        let stream = upgrade(&self.inner)?.subscribe_event();
-        let timeout = upgrade(&self.inner)?.default_timeout();
+        let inner = upgrade(&self.inner)?;
+        if let Some(event) = evt.subscription() {
+            inner.subscribe(event).await?;
+        }
+        let stream = inner.subscribe_event();
+        let timeout = inner.default_timeout() as u32;
         expect_event(stream, evt, timeout).await.map(Event::from)
     }
 
+    /// Waits for the next event of type `E` for which `predicate` returns `true`, e.g. the next [`Download`]. Fails with
+    /// [`Error::Timeout`] after `timeout` milliseconds, which defaults to the page's default timeout.
+    ///
+    /// Events are only caught once the returned future is polled, so join it with the action triggering the event:
+    ///
+    /// ```js
+    /// const [download] = await Promise.all([
+    ///  page.waitForEvent('download'),
+    ///  page.click('a')
+    /// ]);
+    /// ```
+    pub async fn wait_for_event<E>(
+        &self,
+        predicate: Option<&(dyn Fn(&E) -> bool + Send + Sync)>,
+        timeout: Option<f64>,
+    ) -> Result<E, Error>
+    where
+        E: PageEvent,
+    {
+        let (rx, timeout) = self.subscribe_page_event::<E>(timeout).await?;
+        next_page_event(rx, predicate, timeout).await
+    }
+
+    /// Runs `action` and waits for the next event of type `E`, returning both the event and the output of `action`.
+    /// The listener is registered before `action` starts, so an event caused right away is not missed. `timeout`
+    /// defaults to the page's default timeout.
+    ///
+    /// ```js
+    /// const [download] = await Promise.all([
+    ///  page.waitForEvent('download'),
+    ///  page.click('a')
+    /// ]);
+    /// ```
+    pub async fn expect_event_with<E, F>(
+        &self,
+        action: F,
+        timeout: Option<f64>,
+    ) -> Result<(E, F::Output), Error>
+    where
+        E: PageEvent,
+        F: std::future::Future,
+    {
+        let (rx, timeout) = self.subscribe_page_event::<E>(timeout).await?;
+        let (evt, output) = tokio::join!(next_page_event(rx, None, timeout), action);
+        Ok((evt?, output))
+    }
+
+    async fn subscribe_page_event<E>(
+        &self,
+        timeout: Option<f64>,
+    ) -> Result<(broadcast::Receiver<Evt>, f64), Error>
+    where
+        E: PageEvent,
+    {
+        let inner = upgrade(&self.inner)?;
+        let rx = inner.subscribe_event();
+        if let Some(event) = E::event_type().subscription() {
+            inner.subscribe(event).await?;
+        }
+        Ok((rx, timeout.unwrap_or_else(|| inner.default_timeout())))
+    }
+
+    /// Runs `action` and waits for the file chooser it opens.
+    ///
+    /// The listener is registered before `action` starts, so a chooser opened immediately is not missed. `timeout` defaults
+    /// to the page's default timeout.
+    ///
+    /// ```js
+    /// const [fileChooser] = await Promise.all([
+    ///  page.waitForEvent('filechooser'),
+    ///  page.click('upload')
+    /// ]);
+    /// ```
+    pub async fn wait_for_file_chooser<F>(
+        &self,
+        action: F,
+        timeout: Option<f64>,
+    ) -> Result<FileChooser, Error>
+    where
+        F: std::future::Future,
+    {
+        let inner = upgrade(&self.inner)?;
+        inner.subscribe("fileChooser").await?;
+        let stream = inner.subscribe_event();
+        let timeout = timeout.unwrap_or_else(|| inner.default_timeout()) as u32;
+        let (evt, _) = tokio::join!(
+            expect_event(stream, EventType::FileChooser, timeout),
+            action
+        );
+        match evt? {
+            Evt::FileChooser(x) => Ok(x),
+            _ => Err(Error::InvalidParams),
+        }
+    }
+
+    /// Calls `handler` when the page spawns a dedicated worker. With [`OnWorkerOptions::immediate`] it is also called
+    /// right away with every worker that already runs, each worker exactly once.
+    pub async fn on_worker<F>(&self, options: OnWorkerOptions, handler: F) -> Result<(), Error>
+    where
+        F: Fn(Worker) + Send + Sync + 'static,
+    {
+        let inner = upgrade(&self.inner)?;
+        // subscribing first so that no worker is missed between the snapshot and the subscription
+        let rx = inner.subscribe_event();
+        let known = if options.immediate {
+            inner.workers()
+        } else {
+            Vec::new()
+        };
+        for w in &known {
+            handler(Worker::new(w.clone()));
+        }
+        on_event(rx, move |e| {
+            if let Evt::Worker(x) = e {
+                if !known.iter().any(|w| w.ptr_eq(&x)) {
+                    handler(Worker::new(x));
+                }
+            }
+        });
+        Ok(())
+    }
+
+    /// Calls `handler` when a frame is attached to the page.
+    pub async fn on_frame_attached<F>(&self, handler: F) -> Result<(), Error>
+    where
+        F: Fn(Frame) + Send + Sync + 'static,
+    {
+        let rx = upgrade(&self.inner)?.subscribe_event();
+        on_event(rx, move |e| {
+            if let Evt::FrameAttached(x) = e {
+                handler(Frame::new(x));
+            }
+        });
+        Ok(())
+    }
+
+    /// Calls `handler` when a JavaScript dialog appears, such as `alert`, `prompt`, `confirm` or `beforeunload`.
+    /// The handler must either [`Dialog::accept`] or [`Dialog::dismiss`] it, otherwise the page will freeze.
+    pub async fn on_dialog<F>(&self, handler: F) -> Result<(), Error>
+    where
+        F: Fn(Dialog) + Send + Sync + 'static,
+    {
+        let inner = upgrade(&self.inner)?;
+        inner.subscribe("dialog").await?;
+        let rx = inner.subscribe_event();
+        on_event(rx, move |e| {
+            if let Evt::Dialog(x) = e {
+                handler(Dialog::new(x));
+            }
+        });
+        Ok(())
+    }
+
+    /// Calls `handler` once the page is closed, either by [`Page::close`], by closing its context or by the user closing
+    /// the tab in headed mode. [`Page::is_closed`] already returns `true` when `handler` runs.
+    pub async fn on_close<F>(&self, handler: F) -> Result<(), Error>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let rx = upgrade(&self.inner)?.subscribe_event();
+        on_event(rx, move |e| {
+            if let Evt::Close = e {
+                handler();
+            }
+        });
+        Ok(())
+    }
+
+    /// Calls `handler` when the page crashes, e.g. because it ran out of memory. Subsequent operations on the page fail
+    /// with [`Error::PageCrashed`].
+    pub async fn on_crash<F>(&self, handler: F) -> Result<(), Error>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let rx = upgrade(&self.inner)?.subscribe_event();
+        on_event(rx, move |e| {
+            if let Evt::Crash = e {
+                handler();
+            }
+        });
+        Ok(())
+    }
+
+    /// Calls `handler` when an uncaught exception happens within the page, e.g. a thrown `Error` or an unhandled
+    /// promise rejection.
+    pub async fn on_page_error<F>(&self, handler: F) -> Result<(), Error>
+    where
+        F: Fn(PageError) + Send + Sync + 'static,
+    {
+        let rx = upgrade(&self.inner)?.subscribe_event();
+        on_event(rx, move |e| {
+            if let Evt::PageError(x) = e {
+                handler(x);
+            }
+        });
+        Ok(())
+    }
+
+    /// Calls `handler` when a frame is detached from the page.
+    pub async fn on_frame_detached<F>(&self, handler: F) -> Result<(), Error>
+    where
+        F: Fn(Frame) + Send + Sync + 'static,
+    {
+        let rx = upgrade(&self.inner)?.subscribe_event();
+        on_event(rx, move |e| {
+            if let Evt::FrameDetached(x) = e {
+                handler(Frame::new(x));
+            }
+        });
+        Ok(())
+    }
+
+    /// Calls `handler` when a frame of the page is navigated to a new url.
+    pub async fn on_frame_navigated<F>(&self, handler: F) -> Result<(), Error>
+    where
+        F: Fn(Frame) + Send + Sync + 'static,
+    {
+        let rx = upgrade(&self.inner)?.subscribe_event();
+        on_event(rx, move |e| {
+            if let Evt::FrameNavigated(x) = e {
+                handler(Frame::new(x));
+            }
+        });
+        Ok(())
+    }
+
     subscribe_event! {}
 
+    /// Routing provides the capability to modify network requests that are made by a page. Once routing is enabled, every
+    /// request matching the url pattern will stall unless it's continued, fulfilled or aborted.
+    ///
+    /// `url` is a glob pattern: `**` matches any characters, `*` matches any characters except `/`. `handler` is called
+    /// on the connection's event loop, so spawn a task for the async [`Route`] methods.
+    ///
+    /// ```js
+    /// await page.route('**/*.{png,jpg,jpeg}', route => route.abort());
+    /// await page.goto('https://example.com');
+    /// ```
+    ///
+    /// > NOTE: Page routes take precedence over browser context routes (set up with [`method: BrowserContext.route`]) when
+    /// request matches both handlers.
+    pub async fn route<F>(&self, url: &str, handler: F) -> ArcResult<()>
+    where
+        F: Fn(Route) + Send + Sync + 'static,
+    {
+        let handler = Arc::new(move |r| handler(Route::new(r)));
+        self.upgrade()?.route(url, handler).await
+    }
+
+    /// Serves network requests of this page from the HAR file at `har_path`. Requests that have no entry in the HAR are
+    /// aborted. Takes precedence over [`BrowserContext::route_from_har`] like other page routes.
+    ///
+    /// With [`RouteFromHarOptions::update`], the requests are sent over the network and recorded into `har_path` instead;
+    /// the file is written when the browser context closes.
+    pub async fn route_from_har(
+        &self,
+        har_path: &Path,
+        options: RouteFromHarOptions,
+    ) -> Result<(), Error> {
+        Ok(self.upgrade()?.route_from_har(har_path, options).await?)
+    }
+
+    /// Removes all routes created with [`Page::route`] for `url`.
+    pub async fn unroute(&self, url: &str) -> ArcResult<()> {
+        self.upgrade()?.unroute(url).await
+    }
+
+    /// Removes all routes created with [`Page::route`] and [`Page::route_from_har`].
+    pub async fn unroute_all(&self, options: UnrouteAllOptions) -> Result<(), Error> {
+        // no handler outlives its call, see `UnrouteBehavior`
+        let UnrouteAllOptions { behavior: _ } = options;
+        Ok(self.upgrade()?.unroute_all().await?)
+    }
+
     // coverage
     // expose_binding
     // expose_function
-    // route
-    // unroute
     // once_dialog
 
-    pub async fn wait_for_timeout(&self, timeout: f64) {
-        sleep(std::time::Duration::from_millis(timeout as u64)).await
+    /// Waits for the given `timeout` in milliseconds.
+    ///
+    /// Fails with [`Error::PageClosed`] if the page is closed before the timeout elapses.
+    pub async fn wait_for_timeout(&self, timeout: f64) -> Result<(), Error> {
+        let inner = self.upgrade()?;
+        if inner.is_closed() {
+            return Err(Error::PageClosed);
+        }
+        let stream = inner.subscribe_event();
+        match expect_event(stream, EventType::Close, timeout as u32).await {
+            Err(Error::Timeout { .. }) => Ok(()),
+            // closed while waiting
+            Ok(_) => Err(Error::PageClosed),
+            Err(e) => Err(e),
+        }
     }
 }
 
+async fn next_page_event<E>(
+    mut rx: broadcast::Receiver<Evt>,
+    predicate: Option<&(dyn Fn(&E) -> bool + Send + Sync)>,
+    timeout: f64,
+) -> Result<E, Error>
+where
+    E: PageEvent,
+{
+    let event = async {
+        loop {
+            let e = match rx.recv().await {
+                Ok(e) => Event::from(e),
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(e) => break Err(Error::Event(e)),
+            };
+            if let Some(x) = E::from_event(e) {
+                if predicate.map_or(true, |p| p(&x)) {
+                    break Ok(x);
+                }
+            }
+        }
+    };
+    tokio::select! {
+        _ = sleep(Duration::from_millis(timeout as u64)) => Err(Error::Timeout {
+            message: format!("{}ms exceeded while waiting for event", timeout)
+        }),
+        x = event => x
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// An event of a page that [`Page::wait_for_event`] can wait for. [`Page`] stands for a popup.
+pub trait PageEvent: sealed::Sealed + Sized + Send + 'static {
+    #[doc(hidden)]
+    fn event_type() -> EventType;
+    #[doc(hidden)]
+    fn from_event(e: Event) -> Option<Self>;
+}
+
+macro_rules! page_event {
+    ($($t:ty => $v:ident),*) => {
+        $(
+            impl sealed::Sealed for $t {}
+
+            impl PageEvent for $t {
+                fn event_type() -> EventType {
+                    EventType::$v
+                }
+
+                fn from_event(e: Event) -> Option<Self> {
+                    match e {
+                        Event::$v(x) => Some(x),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+page_event! {
+    ConsoleMessage => Console,
+    Dialog => Dialog,
+    Download => Download,
+    FileChooser => FileChooser,
+    Page => Popup,
+    Request => Request,
+    Response => Response
+}
+
+/// Options of [`Page::on_worker`].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct OnWorkerOptions {
+    /// Also call the handler with the workers the page already runs.
+    pub immediate: bool,
+}
+
 #[derive(Clone)]
 pub enum Event {
     Close,
@@ -375,7 +787,7 @@ pub enum Event {
     /// actions like click will never finish.
     ///
     /// > NOTE: When no [`event: Page.dialog`] listeners are present, all dialogs are automatically dismissed.
-    Dialog,
+    Dialog(Dialog),
     DomContentLoaded,
     /// Emitted when attachment download started. User can access basic file operations on downloaded content via the passed
     /// `Download` instance.
@@ -392,12 +804,13 @@ pub enum Event {
     ///  await fileChooser.setFiles('/tmp/myfile.pdf');
     /// });
     /// ```
-    // FileChooser(FileChooser),
+    FileChooser(FileChooser),
     FrameAttached(Frame),
     FrameDetached(Frame),
     FrameNavigated(Frame),
     Load,
-    PageError,
+    /// Emitted when an uncaught exception happens within the page.
+    PageError(PageError),
     /// Emitted when the page opens a new tab or window. This event is emitted in addition to the
     /// [`event: BrowserContext.page`], but only for popups relevant to this page.
     ///
@@ -441,11 +854,11 @@ impl From<Evt> for Event {
             Evt::Close => Event::Close,
             Evt::Crash => Event::Crash,
             Evt::Console(x) => Event::Console(ConsoleMessage::new(x)),
-            Evt::Dialog => Event::Dialog,
+            Evt::Dialog(x) => Event::Dialog(Dialog::new(x)),
             Evt::Download(x) => Event::Download(Download::new(x)),
-            // Evt::FileChooser(x) => Event::FileChooser(x),
+            Evt::FileChooser(x) => Event::FileChooser(x),
             Evt::DomContentLoaded => Event::DomContentLoaded,
-            Evt::PageError => Event::PageError,
+            Evt::PageError(x) => Event::PageError(x),
             Evt::Request(x) => Event::Request(Request::new(x)),
             Evt::Response(x) => Event::Response(Response::new(x)),
             Evt::RequestFailed(x) => Event::RequestFailed(Request::new(x)),
@@ -470,11 +883,11 @@ impl IsEvent for Event {
             Self::Close => EventType::Close,
             Self::Crash => EventType::Crash,
             Self::Console(_) => EventType::Console,
-            Self::Dialog => EventType::Dialog,
+            Self::Dialog(_) => EventType::Dialog,
             Self::Download(_) => EventType::Download,
-            // Self::FileChooser(_) => EventType::FileChooser,
+            Self::FileChooser(_) => EventType::FileChooser,
             Self::DomContentLoaded => EventType::DomContentLoaded,
-            Self::PageError => EventType::PageError,
+            Self::PageError(_) => EventType::PageError,
             Self::Request(_) => EventType::Request,
             Self::Response(_) => EventType::Response,
             Self::RequestFailed(_) => EventType::RequestFailed,
@@ -506,6 +919,14 @@ impl Page {
         self.main_frame().query_selector(selector).await
     }
 
+    /// Shortcut for main frame's [`Frame::query_selector_strict`]
+    pub async fn query_selector_strict(
+        &self,
+        selector: &str,
+    ) -> Result<Option<ElementHandle>, Error> {
+        self.main_frame().query_selector_strict(selector).await
+    }
+
     pub async fn query_selector_all(&self, selector: &str) -> ArcResult<Vec<ElementHandle>> {
         self.main_frame().query_selector_all(selector).await
     }
@@ -514,36 +935,75 @@ impl Page {
         self.main_frame().wait_for_selector_builder(selector)
     }
 
+    /// Shortcut for main frame's [`Frame::wait_for_selector`]
+    pub async fn wait_for_selector(
+        &self,
+        selector: &str,
+        options: WaitForSelectorOptions,
+    ) -> Result<Option<ElementHandle>, Error> {
+        self.main_frame().wait_for_selector(selector, options).await
+    }
+
     // Locator methods
 
     /// Create a locator that can be used to perform actions on elements matching the selector.
-    pub async fn locator(&self, selector: &str) -> Result<Locator, Error> {
-        self.main_frame().locator(selector).await
+    pub fn locator(&self, selector: &str) -> Result<Locator, Error> {
+        self.main_frame().locator(selector)
+    }
+
+    /// When working with iframes, you can create a frame locator that will enter the iframe and allow selecting
+    /// elements in that iframe.
+    ///
+    /// ```js
+    /// const locator = page.frameLocator('#my-iframe').getByText('Submit');
+    /// await locator.click();
+    /// ```
+    pub fn frame_locator(&self, selector: &str) -> FrameLocator {
+        self.main_frame().frame_locator(selector)
+    }
+
+    /// Shortcut for main frame's [`Frame::locator_with_options`]
+    pub fn locator_with_options(
+        &self,
+        selector: &str,
+        options: LocatorOptions<'_>,
+    ) -> Result<Locator, Error> {
+        self.main_frame().locator_with_options(selector, options)
     }
 
     /// Create a locator for elements matching the specified accessibility role.
-    pub async fn get_by_role(&self, role: &str) -> Result<Locator, Error> {
-        self.main_frame().get_by_role(role).await
+    pub fn get_by_role(&self, role: &str, options: GetByRoleOptions) -> Result<Locator, Error> {
+        self.main_frame().get_by_role(role, options)
     }
 
     /// Create a locator for elements containing the specified text.
-    pub async fn get_by_text(&self, text: &str) -> Result<Locator, Error> {
-        self.main_frame().get_by_text(text).await
+    pub fn get_by_text(&self, text: &str) -> Result<Locator, Error> {
+        self.main_frame().get_by_text(text)
     }
 
     /// Create a locator for form controls associated with the specified label text.
-    pub async fn get_by_label(&self, text: &str) -> Result<Locator, Error> {
-        self.main_frame().get_by_label(text).await
+    pub fn get_by_label(&self, text: &str) -> Result<Locator, Error> {
+        self.main_frame().get_by_label(text)
     }
 
     /// Create a locator for input elements with the specified placeholder text.
-    pub async fn get_by_placeholder(&self, text: &str) -> Result<Locator, Error> {
-        self.main_frame().get_by_placeholder(text).await
+    pub fn get_by_placeholder(&self, text: &str) -> Result<Locator, Error> {
+        self.main_frame().get_by_placeholder(text)
     }
 
     /// Create a locator for elements with the specified test id attribute.
-    pub async fn get_by_test_id(&self, test_id: &str) -> Result<Locator, Error> {
-        self.main_frame().get_by_test_id(test_id).await
+    pub fn get_by_test_id(&self, test_id: &str) -> Result<Locator, Error> {
+        self.main_frame().get_by_test_id(test_id)
+    }
+
+    /// Create a locator for elements with the specified alt text, e.g. images.
+    pub fn get_by_alt_text(&self, text: &str) -> Result<Locator, Error> {
+        self.main_frame().get_by_alt_text(text)
+    }
+
+    /// Create a locator for elements with the specified title attribute.
+    pub fn get_by_title(&self, text: &str) -> Result<Locator, Error> {
+        self.main_frame().get_by_title(text)
     }
 
     is_checked! {is_checked, doc = "Errors if the element is not a checkbox or radio input."}
@@ -607,6 +1067,74 @@ impl Page {
         self.main_frame().evaluate(expression, arg).await
     }
 
+    /// Shortcut for main frame's [`Frame::evaluate_expression`]
+    pub async fn evaluate_expression<A, R>(&self, expression: &str, arg: A) -> Result<R, Error>
+    where
+        A: Serialize,
+        R: DeserializeOwned,
+    {
+        self.main_frame().evaluate_expression(expression, arg).await
+    }
+
+    /// Shortcut for main frame's [`Frame::evaluate_with_timeout`]
+    pub async fn evaluate_with_timeout<A, R>(
+        &self,
+        expression: &str,
+        arg: A,
+        timeout: f64,
+    ) -> Result<R, Error>
+    where
+        A: Serialize,
+        R: DeserializeOwned,
+    {
+        self.main_frame()
+            .evaluate_with_timeout(expression, arg, timeout)
+            .await
+    }
+
+    /// Shortcut for main frame's [`Frame::eval_script`]
+    pub async fn eval_script<R>(&self, script: &str) -> Result<R, Error>
+    where
+        R: DeserializeOwned,
+    {
+        self.main_frame().eval_script(script).await
+    }
+
+    /// Shortcut for main frame's [`Frame::eval_value`]
+    pub async fn eval_value(&self, script: &str) -> Result<Value, Error> {
+        self.main_frame().eval_value(script).await
+    }
+
+    /// Shortcut for main frame's [`Frame::eval_value_with_arg`]
+    pub async fn eval_value_with_arg<A>(&self, expression: &str, arg: A) -> Result<Value, Error>
+    where
+        A: Serialize,
+    {
+        self.main_frame().eval_value_with_arg(expression, arg).await
+    }
+
+    /// Shortcut for main frame's [`Frame::evaluate_source`]
+    pub async fn evaluate_source<A, R>(
+        &self,
+        source: EvalSource,
+        arg: Option<A>,
+    ) -> Result<R, Error>
+    where
+        A: Serialize,
+        R: DeserializeOwned,
+    {
+        self.main_frame().evaluate_source(source, arg).await
+    }
+
+    /// Shortcut for main frame's [`Frame::evaluate_file`]
+    pub async fn evaluate_file<A, R>(&self, path: &Path, arg: Option<A>) -> Result<R, Error>
+    where
+        A: Serialize,
+        R: DeserializeOwned,
+    {
+        self.main_frame().evaluate_file(path, arg).await
+    }
+
     pub async fn evaluate_on_selector<T, U>(
         &self,
         selector: &str,
@@ -662,6 +1190,11 @@ impl Page {
         self.main_frame().set_content_builder(html)
     }
 
+    /// Shortcut for main frame's [`Frame::set_content`]
+    pub async fn set_content(&self, html: &str, options: SetContentOptions) -> ArcResult<()> {
+        self.main_frame().set_content(html, options).await
+    }
+
     /// Returns the main resource response. In case of multiple redirects, the navigation will resolve with the response of the
     /// last redirect.
     ///
@@ -774,6 +1307,20 @@ impl Page {
     pub fn wait_for_function_builder<'a>(&self, expression: &'a str) -> WaitForFunctionBuilder<'a> {
         self.main_frame().wait_for_function_builder(expression)
     }
+
+    pub async fn wait_for_function<T>(
+        &self,
+        expression: &str,
+        arg: Option<T>,
+        options: WaitForFunctionOptions,
+    ) -> ArcResult<JsHandle>
+    where
+        T: Serialize,
+    {
+        self.main_frame()
+            .wait_for_function(expression, arg, options)
+            .await
+    }
     // expect_navigation
 }
 
@@ -812,12 +1359,12 @@ navigation!(ReloadBuilder, reload);
 navigation!(GoBackBuilder, go_back);
 navigation!(GoForwardBuilder, go_forward);
 
-pub struct PdfBuilder<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> {
+pub struct PdfBuilder<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> {
     inner: Weak<Impl>,
-    args: PdfArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j>,
+    args: PdfArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i>,
 }
 
-impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> PdfBuilder<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> {
+impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> PdfBuilder<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> {
     pub(crate) fn new(inner: Weak<Impl>) -> Self {
         let args = PdfArgs::default();
         Self { inner, args }
@@ -844,43 +1391,184 @@ impl<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> PdfBuilder<'a, 'b, 'c, 'd, 'e, 'f,
         header_template: Option<&'a str>,
         /// HTML template for the print footer. Should use the same format as the `headerTemplate`.
         footer_template: Option<&'b str>,
-        /// Print background graphics. Defaults to `false`.
+        /// Print background graphics. Needs to be `true` for background colors and images to appear in the PDF.
+        /// Defaults to `false`.
         print_background: Option<bool>,
         /// Paper orientation. Defaults to `false`.
         landscape: Option<bool>,
         /// Paper ranges to print, e.g., '1-5, 8, 11-13'. Defaults to the empty string, which means print all pages.
         page_ranges: Option<&'c str>,
-        /// Paper format. If set, takes priority over `width` or `height` options. Defaults to 'Letter'.
-        format: Option<&'d str>,
+        /// Paper format. Sets the default `width` and `height`, each of which overrides it when given. Defaults to
+        /// [`PaperFormat::Letter`].
+        format: Option<PaperFormat>,
         /// Paper width, accepts values labeled with units.
-        width: Option<Length<'e>>,
+        width: Option<Length<'d>>,
         /// Paper height, accepts values labeled with units.
-        height: Option<Length<'f>>,
+        height: Option<Length<'e>>,
         /// Give any CSS `@page` size declared in the page priority over what is declared in `width` and `height` or `format`
         /// options. Defaults to `false`, which will scale the content to fit the paper size.
         prefer_css_page_size: Option<bool>,
         /// Paper margins, defaults to none.
-        margin: Option<PdfMargins<'g, 'h, 'i, 'j>>,
+        margin: Option<PdfMargins<'f, 'g, 'h, 'i>>,
         /// The file path to save the PDF to. If `path` is a relative path, then it is resolved relative to the current working
         /// directory. If no path is provided, the PDF won't be saved to the disk.
-        path: Option<PathBuf>
+        path: Option<PathBuf>,
+        /// Whether to add the PDF/A-1b identification: XMP metadata matching the document information, an sRGB output
+        /// intent and a file identifier. Page transparency groups are removed, but the page content is kept as printed,
+        /// so the document is not guaranteed to conform; check it with a validator when that matters.
+        /// Fails with [`Error::NotSupported`] on browsers other than Chromium. Defaults to `false`.
+        pdf_a: Option<bool>
+    }
+}
+
+/// A captured screenshot with the requested format and the size read from its header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScreenshotResult {
+    /// The encoded image
+    pub bytes: Vec<u8>,
+    pub format: ScreenshotType,
+    /// Width in device pixels, `None` if the header could not be read
+    pub width: Option<u32>,
+    /// Height in device pixels, `None` if the header could not be read
+    pub height: Option<u32>,
+}
+
+impl ScreenshotResult {
+    pub(crate) fn new(bytes: Vec<u8>, format: ScreenshotType) -> Self {
+        let size = match format {
+            ScreenshotType::Png => png_size(&bytes),
+            ScreenshotType::Jpeg => jpeg_size(&bytes),
+        };
+        Self {
+            format,
+            width: size.map(|(w, _)| w),
+            height: size.map(|(_, h)| h),
+            bytes,
+        }
+    }
+
+    /// Writes the image to `path`.
+    pub fn save_to(&self, path: &Path) -> Result<(), Error> {
+        std::fs::write(path, &self.bytes)?;
+        Ok(())
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+impl std::ops::Deref for ScreenshotResult {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl AsRef<[u8]> for ScreenshotResult {
+    fn as_ref(&self) -> &[u8] {
+        &self.bytes
     }
 }
 
+/// Width and height of the IHDR chunk, which follows the signature
+fn png_size(png: &[u8]) -> Option<(u32, u32)> {
+    if !png.starts_with(b"\x89PNG\r\n\x1a\n") || png.get(12..16)? != b"IHDR" {
+        return None;
+    }
+    let be = |i: usize| Some(u32::from_be_bytes(png.get(i..i + 4)?.try_into().ok()?));
+    Some((be(16)?, be(20)?))
+}
+
+/// Height and width of the first start-of-frame segment
+fn jpeg_size(jpeg: &[u8]) -> Option<(u32, u32)> {
+    let be = |i: usize| Some(u16::from_be_bytes(jpeg.get(i..i + 2)?.try_into().ok()?) as u32);
+    if !jpeg.starts_with(&[0xff, 0xd8]) {
+        return None;
+    }
+    let mut i = 2;
+    while *jpeg.get(i)? == 0xff {
+        let marker = *jpeg.get(i + 1)?;
+        match marker {
+            0xc0..=0xcf if !matches!(marker, 0xc4 | 0xc8 | 0xcc) => {
+                return Some((be(i + 7)?, be(i + 5)?));
+            }
+            _ => i += 2 + be(i + 2)? as usize,
+        }
+    }
+    None
+}
+
 pub struct ScreenshotBuilder {
     inner: Weak<Impl>,
     args: ScreenshotArgs,
+    clip_locator: Option<Locator>,
+    err: Option<Error>,
 }
 
 impl ScreenshotBuilder {
     pub(crate) fn new(inner: Weak<Impl>) -> Self {
         let args = ScreenshotArgs::default();
-        Self { inner, args }
+        Self {
+            inner,
+            args,
+            clip_locator: None,
+            err: None,
+        }
     }
 
-    pub async fn screenshot(self) -> ArcResult<Vec<u8>> {
-        let Self { inner, args } = self;
-        upgrade(&inner)?.screenshot(args).await
+    pub async fn screenshot(self) -> Result<ScreenshotResult, Error> {
+        let Self {
+            inner,
+            mut args,
+            clip_locator,
+            err,
+        } = self;
+        if let Some(e) = err {
+            return Err(e);
+        }
+        if let Some(locator) = clip_locator {
+            if args.clip.is_some() {
+                return Err(Error::InvalidArgument {
+                    message: "clip and clip_locator are mutually exclusive".into(),
+                });
+            }
+            args.clip = Some(locator.clip().await.map_err(Error::unwrap_arc)?);
+        }
+        // the driver captures PNG unless told otherwise
+        let format = args.r#type.unwrap_or(ScreenshotType::Png);
+        let bytes = upgrade(&inner)?
+            .screenshot(args)
+            .await
+            .map_err(Error::unwrap_arc)?;
+        Ok(ScreenshotResult::new(bytes, format))
+    }
+
+    /// Clips the screenshot to the bounding box of the first element matched by `locator`. Fails with
+    /// [`Error::ElementNotFound`] if nothing matches and with [`Error::InvalidArgument`] if `clip` is also set.
+    pub fn clip_locator(mut self, locator: &Locator) -> Self {
+        self.clip_locator = Some(locator.clone());
+        self
+    }
+
+    pub fn clear_clip_locator(mut self) -> Self {
+        self.clip_locator = None;
+        self
+    }
+
+    /// Elements matched by these locators are overlaid with a box of `mask_color` in the screenshot.
+    pub fn mask(mut self, locators: &[Locator]) -> Self {
+        match locators.iter().map(Locator::mask).collect() {
+            Ok(mask) => self.args.mask = Some(mask),
+            Err(e) => self.err = Some(e),
+        }
+        self
+    }
+
+    pub fn clear_mask(mut self) -> Self {
+        self.args.mask = None;
+        self
     }
 
     pub fn r#type(mut self, x: ScreenshotType) -> Self {
@@ -894,6 +1582,10 @@ impl ScreenshotBuilder {
         /// When true, takes a screenshot of the full scrollable page, instead of the currently visible viewport. Defaults to
         /// `false`.
         full_page: Option<bool>,
+        /// With `full_page`, pages taller than this many CSS pixels are captured in slices of one viewport height and
+        /// stitched together here instead of in a single driver call, which can time out or run out of memory on very
+        /// long pages. Each slice spans the full scroll width. Only PNG screenshots without `clip` are stitched.
+        full_page_max_height: Option<u32>,
         /// Hides default white background and allows capturing screenshots with transparency. Not applicable to `jpeg` images.
         /// Defaults to `false`.
         omit_background: Option<bool>,
@@ -904,7 +1596,13 @@ impl ScreenshotBuilder {
         /// The file path to save the image to. The screenshot type will be inferred from file extension. If `path` is a relative
         /// path, then it is resolved relative to the current working directory. If no path is provided, the image won't be saved to
         /// the disk.
-        path: Option<PathBuf>
+        path: Option<PathBuf>,
+        /// Color of the boxes covering the elements given to [`ScreenshotBuilder::mask`], in CSS color format. Defaults to
+        /// pink `#FF00FF`.
+        mask_color: Option<String>,
+        /// When set to [`ScreenshotAnimations::Disabled`], stops CSS animations, CSS transitions and Web Animations before
+        /// capturing. Defaults to [`ScreenshotAnimations::Allow`].
+        animations: Option<ScreenshotAnimations>
     }
 
     pub fn clear_type(mut self) -> Self {
@@ -938,3 +1636,31 @@ impl EmulateMediaBuilder {
         media: Option<Media>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn screenshot_size_is_read_with_the_requested_format() {
+        let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        png.extend_from_slice(&[0, 0, 1, 0, 0, 0, 0, 2]);
+        let result = ScreenshotResult::new(png.clone(), ScreenshotType::Png);
+        assert_eq!((result.width, result.height), (Some(256), Some(2)));
+        // SOI, then a baseline start-of-frame segment of 8 bit samples, 3 high and 4 wide
+        let jpeg = vec![0xff, 0xd8, 0xff, 0xc0, 0, 17, 8, 0, 3, 0, 4];
+        let result = ScreenshotResult::new(jpeg, ScreenshotType::Jpeg);
+        assert_eq!((result.width, result.height), (Some(4), Some(3)));
+        let result = ScreenshotResult::new(png.clone(), ScreenshotType::Jpeg);
+        assert_eq!((result.width, result.height), (None, None));
+        assert_eq!(result.bytes, png);
+    }
+
+    #[test]
+    fn unreadable_screenshot_keeps_its_bytes() {
+        let result = ScreenshotResult::new(b"not an image".to_vec(), ScreenshotType::Png);
+        assert_eq!(result.format, ScreenshotType::Png);
+        assert_eq!((result.width, result.height), (None, None));
+        assert_eq!(result.into_bytes(), b"not an image");
+    }
+}
@@ -2,9 +2,9 @@ pub use crate::imp::playwright::DeviceDescriptor;
 use crate::{
     api::{browser_type::BrowserType, selectors::Selectors},
     imp::{core::*, playwright::Playwright as Impl, prelude::*},
-    Error,
+    DriverErrorKind, Error,
 };
-use std::{io, process::Command};
+use std::{fs, io, process::Command};
 
 /// Entry point
 pub struct Playwright {
@@ -13,8 +13,22 @@ pub struct Playwright {
     inner: Weak<Impl>,
 }
 
+/// Options of [`Playwright::prepare_with_options`]
+#[derive(Debug, Default, Clone)]
+pub struct PrepareOptions {
+    /// Directory to install the browsers into instead of the default Playwright cache. The driver looks for browsers in
+    /// `PLAYWRIGHT_BROWSERS_PATH`, which must point to the same directory when [`Playwright::initialize`] is called.
+    pub browser_path: Option<PathBuf>,
+    /// Skips the installation when `browser_path` already contains files, e.g. browsers restored from a CI cache.
+    pub skip_if_exists: bool,
+}
+
 fn run(driver: &Driver, args: &'static [&'static str]) -> io::Result<()> {
-    let status = Command::new(driver.executable()).args(args).status()?;
+    run_command(&mut Command::new(driver.executable()), args)
+}
+
+fn run_command(command: &mut Command, args: &'static [&'static str]) -> io::Result<()> {
+    let status = command.args(args).status()?;
     if !status.success() {
         return Err(io::Error::other(format!("Exit with {}", status)));
     }
@@ -30,7 +44,11 @@ impl Playwright {
 
     /// Constructs from installed playwright driver
     pub async fn with_driver(driver: Driver) -> Result<Playwright, Error> {
-        let conn = Connection::run(&driver.executable())?;
+        driver.check()?;
+        let conn = Connection::run(&driver.executable()).map_err(|e| Error::DriverError {
+            kind: DriverErrorKind::ExecFailed,
+            message: format!("failed to start {}: {}", driver.executable().display(), e),
+        })?;
         let p = Impl::wait_initial_object(&conn).await?;
         Ok(Self {
             driver,
@@ -44,6 +62,22 @@ impl Playwright {
         run(&self.driver, &["install"])
     }
 
+    /// Runs $ playwright install with `PLAYWRIGHT_BROWSERS_PATH` set to `browser_path`
+    pub fn prepare_with_options(&self, options: PrepareOptions) -> io::Result<()> {
+        let PrepareOptions {
+            browser_path,
+            skip_if_exists,
+        } = options;
+        let mut command = Command::new(self.driver.executable());
+        if let Some(path) = browser_path {
+            if skip_if_exists && fs::read_dir(&path).map_or(false, |mut d| d.next().is_some()) {
+                return Ok(());
+            }
+            command.env("PLAYWRIGHT_BROWSERS_PATH", path);
+        }
+        run_command(&mut command, &["install"])
+    }
+
     /// Runs $ playwright install chromium
     pub fn install_chromium(&self) -> io::Result<()> {
         run(&self.driver, &["install", "chromium"])
@@ -126,4 +160,42 @@ mod tests {
             assert_eq!(e.kind(), io::ErrorKind::Other);
         }
     });
+
+    crate::runtime_test!(prepare_should_skip_existing_browsers, {
+        let p = Playwright::initialize().await.unwrap();
+        let dir = std::env::temp_dir().join("playwright-rust-cached-browsers");
+        fs::create_dir_all(dir.join("chromium-0")).unwrap();
+        // returns before downloading anything into the cached directory
+        p.prepare_with_options(PrepareOptions {
+            browser_path: Some(dir),
+            skip_if_exists: true,
+        })
+        .unwrap();
+    });
+
+    crate::runtime_test!(missing_driver, {
+        let driver = Driver::new(std::env::temp_dir().join("playwright-rust-missing-driver"));
+        match Playwright::with_driver(driver).await {
+            Err(Error::DriverError { kind, .. }) => assert_eq!(kind, DriverErrorKind::NotFound),
+            r => panic!("expected DriverError, got {:?}", r.err()),
+        }
+    });
+
+    crate::runtime_test!(driver_version_mismatch, {
+        let dir = std::env::temp_dir().join("playwright-rust-old-driver");
+        let driver = Driver::new(&dir);
+        std::fs::create_dir_all(dir.join("package")).unwrap();
+        std::fs::write(driver.executable(), "").unwrap();
+        std::fs::write(
+            dir.join("package").join("package.json"),
+            r#"{"version": "0.0.1"}"#,
+        )
+        .unwrap();
+        match Playwright::with_driver(driver).await {
+            Err(Error::DriverError { kind, .. }) => {
+                assert_eq!(kind, DriverErrorKind::VersionMismatch)
+            }
+            r => panic!("expected DriverError, got {:?}", r.err()),
+        }
+    });
 }
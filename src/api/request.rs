@@ -16,7 +16,7 @@ use crate::{
 ///
 /// If request gets a 'redirect' response, the request is successfully finished with the 'requestfinished' event, and a new
 /// request is  issued to a redirected url.
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct Request {
     inner: Weak<Impl>,
 }
@@ -67,10 +67,32 @@ impl Request {
         Ok(upgrade(&self.inner)?.post_data())
     }
 
-    pub fn post_post_as_string(&self) -> Result<Option<String>, Error> {
+    /// Request's post body in a binary form, if any.
+    pub async fn post_data_buffer(&self) -> Result<Option<Vec<u8>>, Error> {
+        self.post_data()
+    }
+
+    /// Request's post body parsed as JSON, if any. Fails with [`Error::Serde`] if the body is not JSON.
+    pub fn post_data_json<T>(&self) -> Result<Option<T>, Error>
+    where
+        T: DeserializeOwned,
+    {
+        match self.post_data()? {
+            Some(body) => Ok(Some(serde_json::from_slice(&body)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Request's post body decoded as UTF-8, if any.
+    pub fn post_data_as_string(&self) -> Result<Option<String>, Error> {
         Ok(upgrade(&self.inner)?.post_data_as_string())
     }
 
+    #[deprecated(note = "use `post_data_as_string`")]
+    pub fn post_post_as_string(&self) -> Result<Option<String>, Error> {
+        self.post_data_as_string()
+    }
+
     /// An object with HTTP headers associated with the request. All header names are lower-case.
     pub fn headers(&self) -> Result<HashMap<String, String>, Error> {
         Ok(upgrade(&self.inner)?.headers().clone())
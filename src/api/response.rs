@@ -1,6 +1,11 @@
 use crate::{
     api::{Frame, Request},
-    imp::{core::*, prelude::*, response::Response as Impl, utils::Header},
+    imp::{
+        core::*,
+        prelude::*,
+        response::Response as Impl,
+        utils::{Header, RemoteAddress, SecurityDetails},
+    },
 };
 
 #[derive(Debug, Clone)]
@@ -61,7 +66,28 @@ impl Response {
 
     /// Returns the object with HTTP headers associated with the response. All header names are lower-case.
     pub async fn headers(&self) -> ArcResult<Vec<Header>> {
-        upgrade(&self.inner)?.headers().await
+        Ok(upgrade(&self.inner)?.headers().to_vec())
+    }
+
+    /// Returns the JSON representation of response body.
+    ///
+    /// This method will throw if the response body is not parsable via `JSON.parse`.
+    pub async fn json<T>(&self) -> ArcResult<T>
+    where
+        T: DeserializeOwned,
+    {
+        let body = upgrade(&self.inner)?.body().await?;
+        Ok(serde_json::from_slice(&body).map_err(Error::Serde)?)
+    }
+
+    /// Returns SSL and other security information. `None` for non-HTTPS responses.
+    pub async fn security_details(&self) -> ArcResult<Option<SecurityDetails>> {
+        upgrade(&self.inner)?.security_details().await
+    }
+
+    /// Returns the IP address and port of the server.
+    pub async fn server_addr(&self) -> ArcResult<Option<RemoteAddress>> {
+        upgrade(&self.inner)?.server_addr().await
     }
 
     /// Shortcut for [`Response::request`]'s  [`Request::frame`]
@@ -1,14 +1,16 @@
+pub use crate::imp::route::{RouteResponse, UnrouteAllOptions, UnrouteBehavior};
 use crate::{
     api::{Header, Request},
     imp::{
         core::*,
         prelude::*,
-        route::{ContinueArgs, FulfillArgs, Route as Impl},
+        route::{ContinueArgs, FetchArgs, FulfillArgs, Route as Impl},
     },
 };
 
 /// Whenever a network route is set up with [`method: Page.route`] or [`method: BrowserContext.route`], the `Route` object
 /// allows to handle the route.
+#[derive(Debug, Clone)]
 pub struct Route {
     inner: Weak<Impl>,
 }
@@ -24,7 +26,7 @@ impl PartialEq for Route {
 }
 
 impl Route {
-    fn new(inner: Weak<Impl>) -> Self {
+    pub(crate) fn new(inner: Weak<Impl>) -> Self {
         Self { inner }
     }
 
@@ -93,6 +95,21 @@ impl Route {
     pub async fn continue_builder(&self) -> ContinueBuilder<'_, '_, '_> {
         ContinueBuilder::new(self.inner.clone())
     }
+
+    /// Performs the request and fetches result without fulfilling it, so that the response could be modified and then
+    /// fulfilled.
+    ///
+    /// ```js
+    /// await page.route('https://dog.ceo/api/breeds/list/all', async route => {
+    ///  const response = await route.fetch();
+    ///  const json = await response.json();
+    ///  json.message['big_red_dog'] = [];
+    ///  await route.fulfill({ response, json });
+    /// });
+    /// ```
+    pub fn fetch_builder(&self) -> FetchBuilder<'_, '_, '_> {
+        FetchBuilder::new(self.inner.clone())
+    }
 }
 
 pub struct FulfillBuilder<'a, 'b> {
@@ -120,13 +137,30 @@ impl<'a, 'b> FulfillBuilder<'a, 'b> {
         self
     }
 
+    /// [`RouteResponse`] to fulfill route's request with. Individual fields of the response (such as headers) can be
+    /// overridden using fulfill options.
+    pub fn response(mut self, x: &RouteResponse) -> Self {
+        self.args.fetch_response_uid = Some(x.fetch_uid.clone());
+        self.args.status.get_or_insert(x.status);
+        self.args.headers.get_or_insert_with(|| x.headers.clone());
+        self
+    }
+
     setter! {
         /// If set, equals to setting `Content-Type` response header.
         content_type: Option<&'b str>,
+        /// File path to respond with. If `path` is a relative path, then it is resolved relative to the current working
+        /// directory. Takes precedence over the body.
+        path: Option<PathBuf>,
         /// Response status code, defaults to `200`.
         status: Option<i32>
     }
 
+    pub fn clear_response(mut self) -> Self {
+        self.args.fetch_response_uid = None;
+        self
+    }
+
     pub fn clear_headers(mut self) -> Self {
         self.args.headers = None;
         self
@@ -172,3 +206,47 @@ impl<'a, 'b, 'c> ContinueBuilder<'a, 'b, 'c> {
         self
     }
 }
+
+pub struct FetchBuilder<'a, 'b, 'c> {
+    inner: Weak<Impl>,
+    args: FetchArgs<'a, 'b, 'c>,
+}
+
+impl<'a, 'b, 'c> FetchBuilder<'a, 'b, 'c> {
+    pub(crate) fn new(inner: Weak<Impl>) -> Self {
+        let args = FetchArgs::default();
+        Self { inner, args }
+    }
+
+    pub async fn fetch(self) -> ArcResult<RouteResponse> {
+        let Self { inner, args } = self;
+        upgrade(&inner)?.fetch(args).await
+    }
+
+    /// If set changes the request HTTP headers. Header values will be converted to a string.
+    pub fn headers<T>(mut self, x: T) -> Self
+    where
+        T: IntoIterator<Item = (String, String)>,
+    {
+        self.args.headers = Some(x.into_iter().map(Header::from).collect());
+        self
+    }
+
+    setter! {
+        /// Maximum number of request redirects that will be followed automatically. Defaults to `20`.
+        max_redirects: Option<i32>,
+        /// If set changes the request method (e.g. GET or POST)
+        method: Option<&'b str>,
+        /// If set changes the post data of request
+        post_data: Option<&'c str>,
+        /// Request timeout in milliseconds. Defaults to `30000` (30 seconds). Pass `0` to disable timeout.
+        timeout: Option<f64>,
+        /// If set changes the request URL. New URL must have same protocol as original one.
+        url: Option<&'a str>
+    }
+
+    pub fn clear_headers(mut self) -> Self {
+        self.args.headers = None;
+        self
+    }
+}
@@ -0,0 +1,60 @@
+use crate::imp::{
+    core::*,
+    prelude::*,
+    worker::{Evt, Worker as Impl},
+};
+
+/// A [Service Worker](https://developer.mozilla.org/en-US/docs/Web/API/Service_Worker_API) registered in a
+/// [`BrowserContext`](crate::api::BrowserContext). Unlike a dedicated [`Worker`](crate::api::Worker) it is not owned by
+/// a page and outlives the pages that registered it. Only Chromium reports Service Workers.
+#[derive(Debug, Clone)]
+pub struct ServiceWorker {
+    inner: Weak<Impl>,
+}
+
+impl PartialEq for ServiceWorker {
+    fn eq(&self, other: &Self) -> bool {
+        let a = self.inner.upgrade();
+        let b = other.inner.upgrade();
+        a.and_then(|a| b.map(|b| (a, b)))
+            .map(|(a, b)| a.guid() == b.guid())
+            .unwrap_or_default()
+    }
+}
+
+impl ServiceWorker {
+    pub(crate) fn new(inner: Weak<Impl>) -> Self {
+        Self { inner }
+    }
+
+    /// URL of the worker script.
+    pub fn url(&self) -> Result<String, Error> {
+        Ok(upgrade(&self.inner)?.url().to_owned())
+    }
+
+    /// Evaluates `expression` in the worker with `arg` and returns its result.
+    pub async fn evaluate<A, R>(&self, expression: &str, arg: A) -> Result<R, Error>
+    where
+        A: Serialize,
+        R: DeserializeOwned,
+    {
+        upgrade(&self.inner)?
+            .evaluate(expression, Some(arg))
+            .await
+            .map_err(Error::unwrap_arc)
+    }
+
+    /// Calls `handler` once the worker is gone.
+    pub async fn on_close<F>(&self, handler: F) -> Result<(), Error>
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        let rx = upgrade(&self.inner)?.subscribe_event();
+        on_event(rx, move |e| {
+            if let Evt::Close = e {
+                handler();
+            }
+        });
+        Ok(())
+    }
+}
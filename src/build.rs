@@ -14,6 +14,7 @@ fn main() {
     download(&url(platform), &dest);
     println!("cargo:rerun-if-changed=src/build.rs");
     println!("cargo:rustc-env=SEP={}", MAIN_SEPARATOR);
+    println!("cargo:rustc-env=PLAYWRIGHT_VERSION={}", DRIVER_VERSION);
 }
 
 #[cfg(all(not(feature = "only-for-docs-rs"), not(unix)))]
@@ -20,11 +20,11 @@ pub(crate) mod prelude {
     #[derive(Debug, thiserror::Error)]
     pub enum JoinError {}
     #[cfg(feature = "rt-async-std")]
-    pub use async_std::{task::sleep, task::spawn};
+    pub use async_std::{fs, task::sleep, task::spawn};
     #[cfg(feature = "rt-tokio")]
-    pub use tokio::{task::spawn, task::JoinError, time::sleep};
+    pub use tokio::{fs, task::spawn, task::JoinError, time::sleep};
     #[cfg(feature = "rt-actix")]
-    pub use tokio::{task::spawn, task::JoinError, time::sleep};
+    pub use tokio::{fs, task::spawn, task::JoinError, time::sleep};
 
     pub(crate) trait RemoveOne<T> {
         fn remove_one<F>(&mut self, f: F)
@@ -67,7 +67,7 @@ mod macros {
             let r = $r.channel().create_request(m).set_args($args)?;
             let fut = $r.channel().send_message(r).await?;
             let res = fut.await?;
-            let res = res.map_err(Error::ErrorResponded)?;
+            let res = res.map_err(|e| Error::responded($method, e))?;
             res
         }};
     }
@@ -93,19 +93,25 @@ pub(crate) mod playwright;
 pub(crate) mod selectors;
 pub(crate) mod utils;
 
+pub(crate) mod api_request_context;
 pub(crate) mod artifact;
 pub(crate) mod binding_call;
 pub(crate) mod browser;
 pub(crate) mod browser_context;
+pub(crate) mod cdp_session;
 pub(crate) mod console_message;
 pub(crate) mod dialog;
 pub(crate) mod download;
 pub(crate) mod element_handle;
-pub(crate) mod file_hooser;
+pub(crate) mod file_chooser;
 pub(crate) mod frame;
+pub(crate) mod har_router;
 pub(crate) mod js_handle;
+pub(crate) mod local_utils;
 pub(crate) mod locator;
 pub(crate) mod page;
+pub(crate) mod pdf_a;
+pub(crate) mod png;
 pub(crate) mod request;
 pub(crate) mod response;
 pub(crate) mod route;
@@ -0,0 +1,66 @@
+use crate::imp::{core::*, prelude::*, utils::Header};
+use base64::Engine;
+
+#[derive(Debug)]
+pub(crate) struct ApiRequestContext {
+    channel: ChannelOwner,
+}
+
+impl ApiRequestContext {
+    pub(crate) fn new(channel: ChannelOwner) -> Self {
+        Self { channel }
+    }
+
+    pub(crate) async fn fetch(&self, args: FetchArgs<'_>) -> ArcResult<FetchResponse> {
+        let v = send_message!(self, "fetch", args);
+        let first = first(&v).ok_or(Error::InvalidParams)?;
+        let res: FetchResponse = serde_json::from_value((*first).clone()).map_err(Error::Serde)?;
+        Ok(res)
+    }
+
+    pub(crate) async fn fetch_response_body(&self, fetch_uid: &str) -> ArcResult<Vec<u8>> {
+        let mut args = HashMap::new();
+        args.insert("fetchUid", fetch_uid);
+        let v = send_message!(self, "fetchResponseBody", args);
+        let s = match maybe_only_str(&v)? {
+            Some(s) => s,
+            None => return Ok(Vec::new()),
+        };
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(s)
+            .map_err(Error::InvalidBase64)?;
+        Ok(bytes)
+    }
+}
+
+impl RemoteObject for ApiRequestContext {
+    fn channel(&self) -> &ChannelOwner {
+        &self.channel
+    }
+    fn channel_mut(&mut self) -> &mut ChannelOwner {
+        &mut self.channel
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct FetchArgs<'a> {
+    pub(crate) url: &'a str,
+    pub(crate) method: Option<&'a str>,
+    pub(crate) headers: Option<Vec<Header>>,
+    /// base64
+    pub(crate) post_data: Option<String>,
+    pub(crate) timeout: Option<f64>,
+    pub(crate) max_redirects: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct FetchResponse {
+    pub(crate) fetch_uid: String,
+    pub(crate) url: String,
+    pub(crate) status: i32,
+    pub(crate) status_text: String,
+    pub(crate) headers: Vec<Header>,
+}
@@ -2,47 +2,60 @@ use crate::imp::{
     browser_context::BrowserContext,
     browser_type::{RecordHar, RecordVideo},
     core::*,
+    page::Page,
     prelude::*,
-    utils::{ColorScheme, Geolocation, HttpCredentials, ProxySettings, StorageState, Viewport},
+    utils::{
+        serialize_accept_downloads, ColorScheme, Geolocation, HttpCredentials, ProxySettings,
+        ServiceWorkerPolicy, StorageState, Viewport,
+    },
 };
+use std::borrow::Cow;
 
 #[derive(Debug)]
 pub(crate) struct Browser {
     channel: ChannelOwner,
+    name: String,
     version: String,
     var: Mutex<Variable>,
+    tx: Mutex<Option<broadcast::Sender<Evt>>>,
 }
 
 #[derive(Debug, Default)]
 pub(crate) struct Variable {
     contexts: Vec<Weak<BrowserContext>>,
     is_remote: bool,
+    is_connected: bool,
 }
 
 impl Browser {
     pub(crate) fn try_new(channel: ChannelOwner) -> Result<Self, Error> {
-        let Initializer { version } = serde_json::from_value(channel.initializer.clone())?;
+        let Initializer { name, version } = serde_json::from_value(channel.initializer.clone())?;
         Ok(Self {
             channel,
+            name,
             version,
             var: Mutex::new(Variable {
                 contexts: Vec::new(),
                 is_remote: false,
+                is_connected: true,
             }),
+            tx: Mutex::default(),
         })
     }
     pub(crate) fn version(&self) -> &str {
         &self.version
     }
 
+    /// `chromium`, `firefox` or `webkit`
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
     pub(crate) async fn close(&self) -> Result<(), Arc<Error>> {
         let _ = send_message!(self, "close", Map::new());
+        self.on_close();
         Ok(())
     }
-
-    // Responds newtype `OwnerPage` of `SinglePageBrowserContext`.
-    // There are different behavior in BrowserContext::new_page
-    // async fn new_page(
 }
 
 // mutable
@@ -68,17 +81,40 @@ impl Browser {
         self.var.lock().unwrap().is_remote = true;
     }
 
+    pub(crate) fn is_connected(&self) -> bool {
+        self.var.lock().unwrap().is_connected
+    }
+
+    pub(crate) fn on_close(&self) {
+        let was_connected = std::mem::replace(&mut self.var.lock().unwrap().is_connected, false);
+        if was_connected {
+            self.emit_event(Evt::Disconnected);
+        }
+    }
+
     pub(crate) async fn new_context(
         &self,
         args: NewContextArgs<'_, '_, '_, '_, '_, '_, '_>,
     ) -> Result<Weak<BrowserContext>, Arc<Error>> {
+        let har_path = args.record_har.as_ref().map(|h| h.path.to_owned());
         let res = send_message!(self, "newContext", args);
         let guid = only_guid(&res)?;
         let c = get_object!(self.context()?.lock().unwrap(), guid, BrowserContext)?;
+        if let Some(path) = har_path {
+            upgrade(&c)?.record_har(path);
+        }
         self.register_new_context(c.clone())?;
         Ok(c)
     }
 
+    /// Opens a page in a new context owned by the page; closing the page closes the context too.
+    pub(crate) async fn new_page(&self) -> Result<Weak<Page>, Arc<Error>> {
+        let c = self.new_context(NewContextArgs::default()).await?;
+        let p = upgrade(&c)?.new_page().await?;
+        upgrade(&p)?.set_owned_context(c);
+        Ok(p)
+    }
+
     fn register_new_context(&self, c: Weak<BrowserContext>) -> Result<(), Arc<Error>> {
         self.push_context(c);
         // TODO: options
@@ -96,11 +132,56 @@ impl RemoteObject for Browser {
     fn channel_mut(&mut self) -> &mut ChannelOwner {
         &mut self.channel
     }
+
+    fn handle_event(
+        &self,
+        _ctx: &Context,
+        method: Str<Method>,
+        _params: Map<String, Value>,
+    ) -> Result<(), Error> {
+        if method.as_str() == "close" {
+            self.on_close();
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Evt {
+    Disconnected,
+}
+
+impl EventEmitter for Browser {
+    type Event = Evt;
+
+    fn tx(&self) -> Option<broadcast::Sender<Self::Event>> {
+        self.tx.lock().unwrap().clone()
+    }
+
+    fn set_tx(&self, tx: broadcast::Sender<Self::Event>) {
+        *self.tx.lock().unwrap() = Some(tx);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EventType {
+    Disconnected,
+}
+
+impl IsEvent for Evt {
+    type EventType = EventType;
+
+    fn event_type(&self) -> Self::EventType {
+        match self {
+            Self::Disconnected => EventType::Disconnected,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct Initializer {
+    name: String,
     version: String,
 }
 
@@ -129,15 +210,17 @@ pub(crate) struct NewContextArgs<'e, 'f, 'g, 'h, 'i, 'j, 'k> {
     #[serde(rename = "extraHTTPHeaders")]
     pub(crate) extra_http_headers: Option<HashMap<String, String>>,
     pub(crate) offline: Option<bool>,
-    pub(crate) http_credentials: Option<&'i HttpCredentials>,
+    pub(crate) http_credentials: Option<Cow<'i, HttpCredentials>>,
     pub(crate) device_scale_factor: Option<f64>,
     pub(crate) is_mobile: Option<bool>,
     pub(crate) has_touch: Option<bool>,
     pub(crate) color_scheme: Option<ColorScheme>,
+    #[serde(serialize_with = "serialize_accept_downloads")]
     pub(crate) accept_downloads: Option<bool>,
     pub(crate) chromium_sandbox: Option<bool>,
     pub(crate) record_video: Option<RecordVideo<'j>>,
     pub(crate) record_har: Option<RecordHar<'k>>,
+    pub(crate) service_workers: Option<ServiceWorkerPolicy>,
 
     pub(crate) storage_state: Option<StorageState>,
 }
@@ -1,14 +1,27 @@
 use crate::imp::{
+    api_request_context::ApiRequestContext,
+    artifact::Artifact,
     browser::Browser,
+    browser_type::RecordHarOptions,
+    cdp_session::CdpSession,
+    console_message::ConsoleMessage,
     core::*,
-    page::Page,
+    dialog::Dialog,
+    har_router::HarRouter,
+    page::{Evt as PageEvt, Page, PageError},
     prelude::*,
-    utils::{Cookie, Geolocation, Header, StorageState},
+    request::Request,
+    response::Response,
+    route::{InterceptionPatterns, Route, RouteHandler, RouteHandlerFn},
+    utils::{Cookie, CookieFilter, Geolocation, Header, StorageState, UpdateSubscriptionArgs},
+    worker::Worker,
 };
+use std::collections::HashSet;
 
 #[derive(Debug)]
 pub(crate) struct BrowserContext {
     channel: ChannelOwner,
+    request_context: Option<Str<Guid>>,
     var: Mutex<Variable>,
     tx: Mutex<Option<broadcast::Sender<Evt>>>,
 }
@@ -17,15 +30,28 @@ pub(crate) struct BrowserContext {
 pub(crate) struct Variable {
     browser: Option<Weak<Browser>>,
     pages: Vec<Weak<Page>>,
-    timeout: Option<u32>,
-    navigation_timeout: Option<u32>,
+    service_workers: Vec<Weak<Worker>>,
+    timeout: Option<f64>,
+    navigation_timeout: Option<f64>,
+    routes: Vec<RouteHandler>,
+    subscriptions: HashSet<&'static str>,
+    har_recorders: Vec<HarRecorder>,
+    har_routers: Vec<HarRouter>,
+    is_closed: bool,
+}
+
+/// A HAR being recorded by the driver, saved to `path` when the context closes.
+#[derive(Debug, Clone)]
+struct HarRecorder {
+    id: String,
+    path: PathBuf,
 }
 
 impl BrowserContext {
-    const DEFAULT_TIMEOUT: u32 = 30000;
+    const DEFAULT_TIMEOUT: f64 = 30000.;
 
     pub(crate) fn try_new(channel: ChannelOwner) -> Result<Self, Error> {
-        let Initializer {} = serde_json::from_value(channel.initializer.clone())?;
+        let Initializer { request_context } = serde_json::from_value(channel.initializer.clone())?;
         let browser = match &channel.parent {
             Some(RemoteWeak::Browser(b)) => Some(b.clone()),
             _ => None,
@@ -36,6 +62,7 @@ impl BrowserContext {
         });
         Ok(Self {
             channel,
+            request_context: request_context.map(|OnlyGuid { guid }| guid),
             var,
             tx: Mutex::default(),
         })
@@ -48,19 +75,150 @@ impl BrowserContext {
         Ok(p)
     }
 
-    pub(crate) async fn close(&self) -> Result<(), Arc<Error>> {
-        let _ = send_message!(self, "close", Map::new());
+    pub(crate) async fn new_cdp_session(&self, page: &Page) -> ArcResult<Weak<CdpSession>> {
+        let is_chromium = self
+            .browser()
+            .and_then(|b| b.upgrade())
+            .map(|b| b.name() == "chromium")
+            .unwrap_or(true);
+        if !is_chromium {
+            return Err(Arc::new(Error::NotSupported {
+                feature: "CDP sessions".into(),
+            }));
+        }
+        #[derive(Serialize)]
+        struct Args {
+            page: OnlyGuid,
+        }
+        let args = Args {
+            page: OnlyGuid {
+                guid: page.guid().to_owned(),
+            },
+        };
+        let res = send_message!(self, "newCDPSession", args);
+        let guid = only_guid(&res)?;
+        let s = get_object!(self.context()?.lock().unwrap(), guid, CdpSession)?;
+        Ok(s)
+    }
+
+    pub(crate) async fn close(&self, options: ContextCloseOptions) -> Result<(), Arc<Error>> {
+        let (recorders, routers) = {
+            let var = &mut self.var.lock().unwrap();
+            (
+                std::mem::take(&mut var.har_recorders),
+                std::mem::take(&mut var.har_routers),
+            )
+        };
+        for HarRecorder { id, path } in recorders {
+            self.export_har(&id, &path).await?;
+        }
+        for router in routers {
+            router.dispose().await?;
+        }
+        #[skip_serializing_none]
+        #[derive(Serialize)]
+        struct Args {
+            reason: Option<String>,
+        }
+        let args = Args {
+            reason: options.reason,
+        };
+        // the driver closes the pages and emits their close events before responding
+        let _ = send_message!(self, "close", args);
+        self.var.lock().unwrap().is_closed = true;
         Ok(())
     }
 
+    pub(crate) fn is_closed(&self) -> bool {
+        self.var.lock().unwrap().is_closed
+    }
+
+    async fn export_har(&self, id: &str, path: &Path) -> ArcResult<()> {
+        let mut args = HashMap::new();
+        args.insert("harId", id);
+        let res = send_message!(self, "harExport", args);
+        let guid = only_guid(&res)?;
+        let artifact = get_object!(self.context()?.lock().unwrap(), guid, Artifact)?;
+        let artifact = upgrade(&artifact)?;
+        artifact.save_as(path).await?;
+        artifact.delete().await
+    }
+
+    /// Saves the HAR the driver records for `newContext`'s `recordHar` to `path` on close.
+    pub(crate) fn record_har(&self, path: PathBuf) {
+        let id = String::new();
+        self.var
+            .lock()
+            .unwrap()
+            .har_recorders
+            .push(HarRecorder { id, path });
+    }
+
+    /// Starts recording the requests of `page`, or of the whole context, into the HAR at `path`.
+    pub(crate) async fn record_into_har(
+        &self,
+        path: &Path,
+        page: Option<&Page>,
+        url: Option<&str>,
+    ) -> ArcResult<()> {
+        #[skip_serializing_none]
+        #[derive(Serialize)]
+        struct Args<'a> {
+            page: Option<OnlyGuid>,
+            options: RecordHarOptions<'a>,
+        }
+        let args = Args {
+            page: page.map(|p| OnlyGuid {
+                guid: p.guid().to_owned(),
+            }),
+            options: RecordHarOptions::new(path, Some("embed"), Some("minimal"), url),
+        };
+        let res = send_message!(self, "harStart", args);
+        let id = only_str(&res)?.to_owned();
+        let path = path.to_owned();
+        self.var
+            .lock()
+            .unwrap()
+            .har_recorders
+            .push(HarRecorder { id, path });
+        Ok(())
+    }
+
+    /// Opens the HAR at `path` for answering requests. The router is released when the context closes.
+    pub(crate) async fn open_har(&self, path: &Path) -> ArcResult<HarRouter> {
+        let local_utils = self
+            .context()?
+            .lock()
+            .unwrap()
+            .local_utils()
+            .ok_or(Error::ObjectNotFound)?;
+        let router = HarRouter::new(local_utils, path).await?;
+        self.var.lock().unwrap().har_routers.push(router.clone());
+        Ok(router)
+    }
+
+    pub(crate) async fn route_from_har(
+        &self,
+        path: &Path,
+        options: RouteFromHarOptions,
+    ) -> ArcResult<()> {
+        let RouteFromHarOptions { update, url } = options;
+        if update == Some(true) {
+            return self.record_into_har(path, None, url.as_deref()).await;
+        }
+        let router = self.open_har(path).await?;
+        let url = url.as_deref().unwrap_or("**/*");
+        self.route(url, router.handler()).await
+    }
+
     pub(crate) async fn storage_state(&self) -> ArcResult<StorageState> {
         let v = send_message!(self, "storageState", Map::new());
         let s = serde_json::from_value((*v).clone()).map_err(Error::Serde)?;
         Ok(s)
     }
 
-    pub(crate) async fn clear_cookies(&self) -> ArcResult<()> {
-        let _ = send_message!(self, "clearCookies", Map::new());
+    pub(crate) async fn clear_cookies(&self, filter: &CookieFilter) -> ArcResult<()> {
+        let _ = send_message!(self, "clearCookies", filter);
         Ok(())
     }
 
@@ -155,10 +313,39 @@ impl BrowserContext {
         Ok(())
     }
 
+    pub(crate) async fn route(&self, url: &str, handler: RouteHandlerFn) -> ArcResult<()> {
+        self.var
+            .lock()
+            .unwrap()
+            .routes
+            .insert(0, RouteHandler::new(url, handler));
+        self.update_interception_patterns().await
+    }
+
+    pub(crate) async fn unroute(&self, url: &str) -> ArcResult<()> {
+        self.var.lock().unwrap().routes.retain(|r| r.url != url);
+        self.update_interception_patterns().await
+    }
+
+    pub(crate) async fn unroute_all(&self) -> ArcResult<()> {
+        self.var.lock().unwrap().routes.clear();
+        self.update_interception_patterns().await
+    }
+
+    async fn update_interception_patterns(&self) -> ArcResult<()> {
+        let routes = self.var.lock().unwrap().routes.clone();
+        let args = InterceptionPatterns::new(&routes);
+        let _ = send_message!(self, "setNetworkInterceptionPatterns", args);
+        Ok(())
+    }
+
+    pub(crate) fn request_context(&self) -> Result<Weak<ApiRequestContext>, Error> {
+        let guid = self.request_context.as_ref().ok_or(Error::ObjectNotFound)?;
+        get_object!(self.context()?.lock().unwrap(), guid, ApiRequestContext)
+    }
+
     // async def expose_binding(
     // async def expose_function(self, name: str, callback: Callable) -> None:
-    // async def route(self, url: URLMatch, handler: RouteHandler) -> None:
-    // async def unroute(
 
     // async fn pause(&self) -> ArcResult<()> {
     //    let _ = send_message!(self, "pause", Map::new());
@@ -189,7 +376,30 @@ impl BrowserContext {
         pages.remove_one(|p| p.ptr_eq(page));
     }
 
-    pub(crate) fn default_timeout(&self) -> u32 {
+    pub(crate) fn service_workers(&self) -> Vec<Weak<Worker>> {
+        self.var.lock().unwrap().service_workers.clone()
+    }
+
+    pub(crate) fn remove_service_worker(&self, worker: &Weak<Worker>) {
+        let workers = &mut self.var.lock().unwrap().service_workers;
+        workers.remove_one(|w| w.ptr_eq(worker));
+    }
+
+    /// The driver dispatches some events like `"request"` only to subscribed clients.
+    pub(crate) async fn subscribe(&self, event: &'static str) -> ArcResult<()> {
+        if self.var.lock().unwrap().subscriptions.contains(event) {
+            return Ok(());
+        }
+        let args = UpdateSubscriptionArgs {
+            event,
+            enabled: true,
+        };
+        let _ = send_message!(self, "updateSubscription", args);
+        self.var.lock().unwrap().subscriptions.insert(event);
+        Ok(())
+    }
+
+    pub(crate) fn default_timeout(&self) -> f64 {
         self.var
             .lock()
             .unwrap()
@@ -197,7 +407,7 @@ impl BrowserContext {
             .unwrap_or(Self::DEFAULT_TIMEOUT)
     }
 
-    pub(crate) fn default_navigation_timeout(&self) -> u32 {
+    pub(crate) fn default_navigation_timeout(&self) -> f64 {
         self.var
             .lock()
             .unwrap()
@@ -205,7 +415,7 @@ impl BrowserContext {
             .unwrap_or(Self::DEFAULT_TIMEOUT)
     }
 
-    pub(crate) async fn set_default_timeout(&self, timeout: u32) -> ArcResult<()> {
+    pub(crate) async fn set_default_timeout(&self, timeout: f64) -> ArcResult<()> {
         let mut args = Map::new();
         args.insert("timeout".into(), timeout.into());
         let _ = send_message!(self, "setDefaultTimeoutNoReply", args);
@@ -213,7 +423,7 @@ impl BrowserContext {
         Ok(())
     }
 
-    pub(crate) async fn set_default_navigation_timeout(&self, timeout: u32) -> ArcResult<()> {
+    pub(crate) async fn set_default_navigation_timeout(&self, timeout: f64) -> ArcResult<()> {
         let mut args = Map::new();
         args.insert("timeout".into(), timeout.into());
         let _ = send_message!(self, "setDefaultNavigationTimeoutNoReply", args);
@@ -222,6 +432,7 @@ impl BrowserContext {
     }
 
     fn on_close(&self, ctx: &Context) -> Result<(), Error> {
+        self.var.lock().unwrap().is_closed = true;
         let browser = match self.browser().and_then(|b| b.upgrade()) {
             None => return Ok(()),
             Some(b) => b,
@@ -232,8 +443,162 @@ impl BrowserContext {
         Ok(())
     }
 
-    fn on_route(&self, _ctx: &Context, _parmas: Map<String, Value>) -> Result<(), Error> {
-        // TODO: noimplemented
+    fn on_service_worker(&self, ctx: &Context, params: Map<String, Value>) -> Result<(), Error> {
+        let first = first_object(&params).ok_or(Error::InvalidParams)?;
+        let OnlyGuid { guid } = serde_json::from_value((*first).clone())?;
+        let worker = get_object!(ctx, &guid, Worker)?;
+        let this = get_object!(ctx, self.guid(), BrowserContext)?;
+        upgrade(&worker)?.set_browser_context(this);
+        self.var
+            .lock()
+            .unwrap()
+            .service_workers
+            .push(worker.clone());
+        self.emit_event(Evt::ServiceWorker(worker));
+        Ok(())
+    }
+
+    fn on_route(&self, ctx: &Context, params: Map<String, Value>) -> Result<(), Error> {
+        let first = first_object(&params).ok_or(Error::InvalidParams)?;
+        let OnlyGuid { guid } = serde_json::from_value((*first).clone())?;
+        let route = get_object!(ctx, &guid, Route)?;
+        self.handle_route(route)
+    }
+
+    fn on_request(&self, ctx: &Context, params: Map<String, Value>) -> Result<(), Error> {
+        #[derive(Debug, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct De {
+            request: OnlyGuid,
+            page: Option<OnlyGuid>,
+        }
+        let De { request, page } = serde_json::from_value(params.into())?;
+        let request = get_object!(ctx, &request.guid, Request)?;
+        self.emit_event(Evt::Request(request.clone()));
+        emit_page_event(ctx, page, PageEvt::Request(request))
+    }
+
+    fn on_response(&self, ctx: &Context, params: Map<String, Value>) -> Result<(), Error> {
+        #[derive(Debug, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct De {
+            response: OnlyGuid,
+            page: Option<OnlyGuid>,
+        }
+        let De { response, page } = serde_json::from_value(params.into())?;
+        let response = get_object!(ctx, &response.guid, Response)?;
+        self.emit_event(Evt::Response(response.clone()));
+        emit_page_event(ctx, page, PageEvt::Response(response))
+    }
+
+    fn on_request_failed(&self, ctx: &Context, params: Map<String, Value>) -> Result<(), Error> {
+        #[derive(Debug, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct De {
+            request: OnlyGuid,
+            response_end_timing: f64,
+            failure_text: Option<String>,
+            page: Option<OnlyGuid>,
+        }
+        let De {
+            request,
+            response_end_timing,
+            failure_text,
+            page,
+        } = serde_json::from_value(params.into())?;
+        let request = get_object!(ctx, &request.guid, Request)?;
+        let req = upgrade(&request)?;
+        req.set_failure(failure_text);
+        req.set_response_end(response_end_timing);
+        self.emit_event(Evt::RequestFailed(request.clone()));
+        emit_page_event(ctx, page, PageEvt::RequestFailed(request))
+    }
+
+    fn on_request_finished(&self, ctx: &Context, params: Map<String, Value>) -> Result<(), Error> {
+        #[derive(Debug, Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct De {
+            request: OnlyGuid,
+            response_end_timing: f64,
+            page: Option<OnlyGuid>,
+        }
+        let De {
+            request,
+            response_end_timing,
+            page,
+        } = serde_json::from_value(params.into())?;
+        let request = get_object!(ctx, &request.guid, Request)?;
+        let req = upgrade(&request)?;
+        req.set_response_end(response_end_timing);
+        self.emit_event(Evt::RequestFinished(request.clone()));
+        emit_page_event(ctx, page, PageEvt::RequestFinished(request))
+    }
+
+    fn on_console(&self, ctx: &Context, params: Map<String, Value>) -> Result<(), Error> {
+        #[derive(Debug, Deserialize)]
+        struct De {
+            page: Option<OnlyGuid>,
+        }
+        let params = Value::from(params);
+        let De { page } = serde_json::from_value(params.clone())?;
+        let console = Arc::new(ConsoleMessage::try_new(ctx, params)?);
+        self.emit_event(Evt::Console(console.clone()));
+        emit_page_event(ctx, page, PageEvt::Console(console))
+    }
+
+    fn on_dialog(&self, ctx: &Context, params: Map<String, Value>) -> Result<(), Error> {
+        #[derive(Debug, Deserialize)]
+        struct De {
+            dialog: OnlyGuid,
+        }
+        let De { dialog } = serde_json::from_value(params.into())?;
+        let dialog = get_object!(ctx, &dialog.guid, Dialog)?;
+        self.emit_event(Evt::Dialog(dialog.clone()));
+        if let Some(page) = upgrade(&dialog)?.page().and_then(|p| p.upgrade()) {
+            page.emit_event(PageEvt::Dialog(dialog));
+        }
+        Ok(())
+    }
+
+    fn on_page_error(&self, ctx: &Context, params: Map<String, Value>) -> Result<(), Error> {
+        #[derive(Debug, Deserialize)]
+        struct De {
+            error: SerializedError,
+            page: OnlyGuid,
+        }
+        #[derive(Debug, Deserialize)]
+        struct SerializedError {
+            error: Option<PageError>,
+            value: Option<Value>,
+        }
+        let De { error, page } = serde_json::from_value(params.into())?;
+        let error = match error {
+            SerializedError { error: Some(e), .. } => e,
+            // a thrown value that is not an `Error`
+            SerializedError { value, .. } => PageError {
+                name: String::new(),
+                message: match value.as_ref().map(de::from_value::<Value>) {
+                    Some(Ok(Value::String(s))) => s,
+                    Some(Ok(v)) => v.to_string(),
+                    _ => String::new(),
+                },
+                stack: None,
+            },
+        };
+        emit_page_event(ctx, Some(page), PageEvt::PageError(error))
+    }
+
+    /// Called for routes not handled by the page
+    pub(crate) fn handle_route(&self, route: Weak<Route>) -> Result<(), Error> {
+        let url = upgrade(&upgrade(&route)?.request())?.url().to_owned();
+        let handler = {
+            let routes = &self.var.lock().unwrap().routes;
+            routes.iter().find(|r| r.matches(&url)).cloned()
+        };
+        match handler {
+            Some(h) => (h.handler)(route),
+            None => Route::fallback(route),
+        }
         Ok(())
     }
 }
@@ -263,16 +628,60 @@ impl RemoteObject for BrowserContext {
             "close" => self.on_close(ctx)?,
             "bindingCall" => {}
             "route" => self.on_route(ctx, params)?,
+            "request" => self.on_request(ctx, params)?,
+            "response" => self.on_response(ctx, params)?,
+            "requestFailed" => self.on_request_failed(ctx, params)?,
+            "requestFinished" => self.on_request_finished(ctx, params)?,
+            "console" => self.on_console(ctx, params)?,
+            "dialog" => self.on_dialog(ctx, params)?,
+            "pageError" => self.on_page_error(ctx, params)?,
+            "serviceWorker" => self.on_service_worker(ctx, params)?,
             _ => {}
         }
         Ok(())
     }
 }
 
+/// Network events are dispatched on the context and forwarded to the page that issued them.
+fn emit_page_event(ctx: &Context, page: Option<OnlyGuid>, evt: PageEvt) -> Result<(), Error> {
+    let guid = match page {
+        Some(OnlyGuid { guid }) => guid,
+        None => return Ok(()),
+    };
+    if let Some(page) = get_object!(ctx, &guid, Page)?.upgrade() {
+        page.emit_event(evt);
+    }
+    Ok(())
+}
+
+/// Options of [`BrowserContext::close_with_options`](crate::api::BrowserContext::close_with_options)
+#[derive(Debug, Default, Clone)]
+pub struct ContextCloseOptions {
+    /// The reason reported to operations interrupted by the context closure.
+    pub reason: Option<String>,
+}
+
+/// Options of [`BrowserContext::route_from_har`](crate::api::BrowserContext::route_from_har)
+#[derive(Debug, Default, Clone)]
+pub struct RouteFromHarOptions {
+    /// When set to `true`, requests are performed over the network and recorded into the HAR file instead of being served
+    /// from it. The file is written when the context closes.
+    pub update: Option<bool>,
+    /// A glob pattern; only requests with a matching url are served from the HAR. Defaults to all requests.
+    pub url: Option<String>,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) enum Evt {
     Close,
+    Console(Arc<ConsoleMessage>),
+    Dialog(Weak<Dialog>),
     Page(Weak<Page>),
+    Request(Weak<Request>),
+    Response(Weak<Response>),
+    RequestFailed(Weak<Request>),
+    RequestFinished(Weak<Request>),
+    ServiceWorker(Weak<Worker>),
 }
 
 impl EventEmitter for BrowserContext {
@@ -290,7 +699,29 @@ impl EventEmitter for BrowserContext {
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum EventType {
     Close,
+    Console,
+    Dialog,
     Page,
+    Request,
+    Response,
+    RequestFailed,
+    RequestFinished,
+    ServiceWorker,
+}
+
+impl EventType {
+    /// Name for `updateSubscription` of the events the driver dispatches only on demand.
+    pub(crate) fn subscription(&self) -> Option<&'static str> {
+        match self {
+            Self::Console => Some("console"),
+            Self::Dialog => Some("dialog"),
+            Self::Request => Some("request"),
+            Self::Response => Some("response"),
+            Self::RequestFailed => Some("requestFailed"),
+            Self::RequestFinished => Some("requestFinished"),
+            _ => None,
+        }
+    }
 }
 
 impl IsEvent for Evt {
@@ -299,14 +730,23 @@ impl IsEvent for Evt {
     fn event_type(&self) -> Self::EventType {
         match self {
             Self::Close => EventType::Close,
+            Self::Console(_) => EventType::Console,
+            Self::Dialog(_) => EventType::Dialog,
             Self::Page(_) => EventType::Page,
+            Self::Request(_) => EventType::Request,
+            Self::Response(_) => EventType::Response,
+            Self::RequestFailed(_) => EventType::RequestFailed,
+            Self::RequestFinished(_) => EventType::RequestFinished,
+            Self::ServiceWorker(_) => EventType::ServiceWorker,
         }
     }
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
-struct Initializer {}
+struct Initializer {
+    request_context: Option<OnlyGuid>,
+}
 
 #[cfg(test)]
 mod tests {
@@ -325,6 +765,6 @@ mod tests {
         let c = c.upgrade().unwrap();
         c.storage_state().await.unwrap();
         c.cookies(&[]).await.unwrap();
-        c.set_default_timeout(30000).await.unwrap();
+        c.set_default_timeout(30000.).await.unwrap();
     });
 }
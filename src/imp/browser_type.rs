@@ -3,8 +3,12 @@ use crate::imp::{
     browser_context::BrowserContext,
     core::*,
     prelude::*,
-    utils::{BrowserChannel, ColorScheme, Geolocation, HttpCredentials, ProxySettings, Viewport},
+    utils::{
+        serialize_accept_downloads, BrowserChannel, ColorScheme, Geolocation, HttpCredentials,
+        ProxySettings, ServiceWorkerPolicy, Viewport,
+    },
 };
+use std::borrow::Cow;
 
 #[derive(Debug)]
 pub(crate) struct BrowserType {
@@ -45,9 +49,13 @@ impl BrowserType {
         &self,
         args: LaunchPersistentContextArgs<'_, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>,
     ) -> Result<Weak<BrowserContext>, Arc<Error>> {
+        let har_path = args.record_har.as_ref().map(|h| h.path.to_owned());
         let res = send_message!(self, "launchPersistentContext", args);
         let guid = only_guid(&res)?;
         let b = get_object!(self.context()?.lock().unwrap(), guid, BrowserContext)?;
+        if let Some(path) = har_path {
+            upgrade(&b)?.record_har(path);
+        }
         Ok(b)
     }
 
@@ -90,7 +98,7 @@ impl BrowserType {
 pub(crate) struct LaunchArgs<'a, 'b, 'c> {
     #[serde(rename = "executablePath")]
     pub(crate) executable: Option<&'a Path>,
-    pub(crate) args: Option<&'b [String]>,
+    pub(crate) args: Option<Cow<'b, [String]>>,
     pub(crate) ignore_all_default_args: Option<bool>,
     #[serde(rename = "handleSIGINT")]
     pub(crate) handle_sigint: Option<bool>,
@@ -102,7 +110,7 @@ pub(crate) struct LaunchArgs<'a, 'b, 'c> {
     pub(crate) devtools: Option<bool>,
     pub(crate) proxy: Option<ProxySettings>,
     #[serde(rename = "downloadsPath")]
-    pub(crate) downloads: Option<&'c Path>,
+    pub(crate) downloads: Option<Cow<'c, Path>>,
     #[serde(rename = "slowMo")]
     pub(crate) slowmo: Option<f64>,
     pub(crate) env: Option<Map<String, Value>>,
@@ -153,7 +161,7 @@ pub(crate) struct LaunchPersistentContextArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i
     pub(crate) devtools: Option<bool>,
     pub(crate) proxy: Option<ProxySettings>,
     #[serde(rename = "downloadsPath")]
-    pub(crate) downloads: Option<&'d Path>,
+    pub(crate) downloads: Option<Cow<'d, Path>>,
     #[serde(rename = "slowMo")]
     pub(crate) slowmo: Option<f64>,
 
@@ -179,10 +187,12 @@ pub(crate) struct LaunchPersistentContextArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i
     pub(crate) is_mobile: Option<bool>,
     pub(crate) has_touch: Option<bool>,
     pub(crate) color_scheme: Option<ColorScheme>,
+    #[serde(serialize_with = "serialize_accept_downloads")]
     pub(crate) accept_downloads: Option<bool>,
     pub(crate) chromium_sandbox: Option<bool>,
     pub(crate) record_video: Option<RecordVideo<'j>>,
     pub(crate) record_har: Option<RecordHar<'k>>,
+    pub(crate) service_workers: Option<ServiceWorkerPolicy>,
 
     pub(crate) channel: Option<BrowserChannel>,
 }
@@ -195,12 +205,63 @@ pub struct RecordVideo<'a> {
     pub size: Option<Viewport>,
 }
 
-#[skip_serializing_none]
-#[derive(Debug, Serialize, Clone)]
-#[serde(rename_all = "camelCase")]
+#[derive(Debug, Clone)]
 pub struct RecordHar<'a> {
     pub path: &'a Path,
     pub omit_content: Option<bool>,
+    /// Glob pattern of the request urls to record. All requests are recorded if not set.
+    pub url_filter: Option<&'a str>,
+}
+
+impl<'a> RecordHar<'a> {
+    pub fn new(path: &'a Path) -> Self {
+        Self {
+            path,
+            omit_content: None,
+            url_filter: None,
+        }
+    }
+}
+
+/// The driver records into an artifact; the client saves it to `path` when the context closes.
+impl Serialize for RecordHar<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let content = match self.omit_content {
+            Some(true) => Some("omit"),
+            _ => None,
+        };
+        RecordHarOptions::new(self.path, content, None, self.url_filter).serialize(serializer)
+    }
+}
+
+/// `RecordHarOptions` of the protocol
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RecordHarOptions<'a> {
+    zip: bool,
+    content: Option<&'static str>,
+    mode: Option<&'static str>,
+    url_glob: Option<&'a str>,
+}
+
+impl<'a> RecordHarOptions<'a> {
+    pub(crate) fn new(
+        path: &Path,
+        content: Option<&'static str>,
+        mode: Option<&'static str>,
+        url_glob: Option<&'a str>,
+    ) -> Self {
+        Self {
+            zip: path.extension().map_or(false, |e| e == "zip"),
+            content,
+            mode,
+            url_glob,
+        }
+    }
 }
 
 impl<'a> LaunchPersistentContextArgs<'a, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_> {
@@ -244,6 +305,7 @@ impl<'a> LaunchPersistentContextArgs<'a, '_, '_, '_, '_, '_, '_, '_, '_, '_, '_>
             chromium_sandbox: None,
             record_video: None,
             record_har: None,
+            service_workers: None,
             channel: None,
         }
     }
@@ -320,7 +382,10 @@ mod tests {
             Ok(send_message!(c, "nonExistentMethod", Map::default()))
         }
         match send(&chromium).await {
-            Err(Error::ErrorResponded(e)) => dbg!(e),
+            Err(Error::ProtocolError { method, message }) => {
+                assert_eq!(method, "nonExistentMethod");
+                dbg!(message);
+            }
             x => {
                 dbg!(&x);
                 unreachable!()
@@ -0,0 +1,38 @@
+use crate::imp::{core::*, prelude::*};
+
+/// A raw Chrome DevTools Protocol session of a page, opened by the browser context.
+#[derive(Debug)]
+pub(crate) struct CdpSession {
+    channel: ChannelOwner,
+}
+
+impl CdpSession {
+    pub(crate) fn new(channel: ChannelOwner) -> Self {
+        Self { channel }
+    }
+
+    pub(crate) async fn send(&self, method: &str, params: Option<Value>) -> ArcResult<Value> {
+        #[skip_serializing_none]
+        #[derive(Serialize)]
+        struct Args<'a> {
+            method: &'a str,
+            params: Option<Value>,
+        }
+        let v = send_message!(self, "send", Args { method, params });
+        Ok(first(&v).cloned().unwrap_or_default())
+    }
+
+    pub(crate) async fn detach(&self) -> ArcResult<()> {
+        let _ = send_message!(self, "detach", Map::new());
+        Ok(())
+    }
+}
+
+impl RemoteObject for CdpSession {
+    fn channel(&self) -> &ChannelOwner {
+        &self.channel
+    }
+    fn channel_mut(&mut self) -> &mut ChannelOwner {
+        &mut self.channel
+    }
+}
@@ -1,45 +1,48 @@
 use crate::imp::{core::*, js_handle::JsHandle, prelude::*, utils::SourceLocation};
 
+/// A console message. The driver sends its fields inline with the `console` event of the context; older drivers sent
+/// them as the initializer of a `ConsoleMessage` object.
 #[derive(Debug)]
 pub(crate) struct ConsoleMessage {
-    channel: ChannelOwner,
+    r#type: String,
+    text: String,
     location: SourceLocation,
     args: Vec<Weak<JsHandle>>,
 }
 
 impl ConsoleMessage {
-    pub(crate) fn try_new(ctx: &Context, channel: ChannelOwner) -> Result<Self, Error> {
+    pub(crate) fn try_new(ctx: &Context, fields: Value) -> Result<Self, Error> {
         #[derive(Deserialize)]
         struct De {
+            r#type: String,
+            text: String,
             location: SourceLocation,
             args: Vec<OnlyGuid>,
         }
-        let De { location, args } = serde_json::from_value(channel.initializer.clone())?;
+        let De {
+            r#type,
+            text,
+            location,
+            args,
+        } = serde_json::from_value(fields)?;
         let args = args
             .iter()
             .map(|OnlyGuid { guid }| get_object!(ctx, guid, JsHandle))
             .collect::<Result<Vec<_>, _>>()?;
         Ok(Self {
-            channel,
+            r#type,
+            text,
             location,
             args,
         })
     }
 
     pub(crate) fn r#type(&self) -> &str {
-        self.channel()
-            .initializer
-            .get("type")
-            .and_then(|v| v.as_str())
-            .unwrap_or_default()
+        &self.r#type
     }
 
     pub(crate) fn text(&self) -> &str {
-        self.channel()
-            .initializer
-            .get("text")
-            .and_then(|v| v.as_str())
-            .unwrap_or_default()
+        &self.text
     }
 
     pub(crate) fn location(&self) -> &SourceLocation {
@@ -50,12 +53,3 @@ impl ConsoleMessage {
         &self.args
     }
 }
-
-impl RemoteObject for ConsoleMessage {
-    fn channel(&self) -> &ChannelOwner {
-        &self.channel
-    }
-    fn channel_mut(&mut self) -> &mut ChannelOwner {
-        &mut self.channel
-    }
-}
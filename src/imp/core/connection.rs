@@ -1,4 +1,4 @@
-use crate::imp::{core::*, prelude::*};
+use crate::imp::{core::*, local_utils::LocalUtils, prelude::*};
 use std::{
     io,
     process::{Child, Command, Stdio},
@@ -45,8 +45,6 @@ pub enum Error {
     Transport(#[from] TransportError),
     #[error("Callback not found")]
     CallbackNotFound,
-    #[error(transparent)]
-    ErrorResponded(#[from] Arc<ErrorMessage>),
     #[error("Value is not Object")]
     NotObject,
     #[error("guid not found in {0:?}")]
@@ -67,14 +65,147 @@ pub enum Error {
     RemoteArtifact,
     #[error("Failed to resolve path {0:?}")]
     ResolvePath(PathBuf),
-    #[error("Timed out")]
-    Timeout,
+    #[error("Timed out: {message}")]
+    Timeout { message: String },
     #[error(transparent)]
     Join(#[from] JoinError),
+    #[error("strict mode violation: {selector:?} resolved to {count} elements")]
+    AmbiguousMatch { selector: String, count: usize },
+    #[error("Page crashed")]
+    PageCrashed,
+    #[error("Page closed")]
+    PageClosed,
+    #[error("protocol error in '{method}': {message}")]
+    ProtocolError { method: String, message: String },
+    #[error("Navigation failed: {message}")]
+    NavigationFailed { message: String },
+    #[error("No element matches {selector:?}")]
+    ElementNotFound { selector: String },
+    #[error("Element is not attached to the DOM")]
+    ElementDetached,
+    #[error("Element {selector:?} is not a checkbox")]
+    ElementNotCheckable { selector: String },
+    #[error("{feature} is not supported by this browser")]
+    NotSupported { feature: String },
+    #[error("Failed to parse the generated PDF")]
+    InvalidPdf,
+    #[error("Failed to parse a screenshot")]
+    InvalidPng,
+    #[error("Invalid argument: {message}")]
+    InvalidArgument { message: String },
+    #[error("XPath {selector:?} would be evaluated by JavaScript, which XPathFallbackMode::Error forbids")]
+    XPathFallback { selector: String },
+    #[error("Playwright driver error ({kind:?}): {message}")]
+    DriverError {
+        kind: DriverErrorKind,
+        message: String,
+    },
+}
+
+/// What went wrong with the driver in [`Error::DriverError`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriverErrorKind {
+    /// The driver is not installed at the expected path
+    NotFound,
+    /// The installed driver is not the version this crate was built for
+    VersionMismatch,
+    /// The driver could not be started
+    ExecFailed,
 }
 
 pub(crate) type ArcResult<T> = Result<T, Arc<Error>>;
 
+impl Error {
+    /// Wraps an error returned by the driver for `method`, recognizing the ones caused by a crashed page.
+    pub(crate) fn responded(method: &str, m: Arc<ErrorMessage>) -> Error {
+        if m.message.contains("Target crashed") || m.message.contains("Page crashed") {
+            Error::PageCrashed
+        } else if m.message.contains("Element is not attached to the DOM") {
+            Error::ElementDetached
+        } else {
+            Error::ProtocolError {
+                method: method.to_owned(),
+                message: m.message.clone(),
+            }
+        }
+    }
+
+    /// Maps a failed action on the locator `selector` to [`Error::Timeout`], [`Error::ElementNotFound`] or
+    /// [`Error::AmbiguousMatch`] where the driver's message allows it.
+    pub(crate) fn locator(selector: &str, e: Arc<Error>) -> Error {
+        match Error::unwrap_arc(Error::strict(selector, e)) {
+            Error::ProtocolError { message, .. } if is_timeout(&message) => {
+                // the call log mentions the element once the locator resolved
+                if message.contains("resolved to") {
+                    Error::Timeout { message }
+                } else {
+                    Error::ElementNotFound {
+                        selector: selector.to_owned(),
+                    }
+                }
+            }
+            e => e,
+        }
+    }
+
+    /// Maps a failed navigation to [`Error::Timeout`] or [`Error::NavigationFailed`].
+    pub(crate) fn navigation(e: Arc<Error>) -> Arc<Error> {
+        match &*e {
+            Error::ProtocolError { message, .. } if is_timeout(message) => {
+                Arc::new(Error::Timeout {
+                    message: message.clone(),
+                })
+            }
+            Error::ProtocolError { message, .. } => Arc::new(Error::NavigationFailed {
+                message: message.clone(),
+            }),
+            _ => e,
+        }
+    }
+
+    /// Whether the driver rejected the call because it does not know the method, as older drivers do for newer
+    /// protocol messages.
+    pub(crate) fn is_unknown_method(&self) -> bool {
+        match self {
+            Error::ProtocolError { message, .. } => {
+                message.contains("Unknown scheme for") || message.contains("does not implement")
+            }
+            Error::Arc(e) => e.is_unknown_method(),
+            _ => false,
+        }
+    }
+
+    /// Takes the error out of the `Arc` when it is not shared, so callers can match on its variant.
+    pub(crate) fn unwrap_arc(e: Arc<Error>) -> Error {
+        Arc::try_unwrap(e).unwrap_or_else(Error::Arc)
+    }
+
+    /// Turns the driver's strict mode violation for `selector` into [`Error::AmbiguousMatch`].
+    pub(crate) fn strict(selector: &str, e: Arc<Error>) -> Arc<Error> {
+        let message = match &*e {
+            Error::ProtocolError { message, .. } => message,
+            _ => return e,
+        };
+        let count = match message.find("strict mode violation") {
+            Some(_) => message
+                .split("resolved to ")
+                .nth(1)
+                .and_then(|s| s.split_whitespace().next())
+                .and_then(|n| n.parse().ok())
+                .unwrap_or_default(),
+            None => return e,
+        };
+        Arc::new(Error::AmbiguousMatch {
+            selector: selector.to_owned(),
+            count,
+        })
+    }
+}
+
+fn is_timeout(message: &str) -> bool {
+    message.starts_with("Timeout ") && message.contains("exceeded")
+}
+
 impl Drop for Connection {
     fn drop(&mut self) {
         self.notify_closed(Error::ReceiverClosed);
@@ -206,6 +337,12 @@ impl Context {
         for p in self.callbacks.iter().map(|(_, v)| v) {
             Context::respond_wait(p, Err(err.clone()));
         }
+        // The driver is gone, so it will never send "close" for the browsers it owned
+        for o in self.objects.values() {
+            if let RemoteArc::Browser(b) = o {
+                b.on_close();
+            }
+        }
         self.objects = HashMap::new();
     }
 
@@ -308,6 +445,13 @@ impl Context {
         self.objects.get(k).map(|r| r.downgrade())
     }
 
+    pub(in crate::imp) fn local_utils(&self) -> Option<Weak<LocalUtils>> {
+        self.objects.values().find_map(|r| match r {
+            RemoteArc::LocalUtils(x) => Some(Arc::downgrade(x)),
+            _ => None,
+        })
+    }
+
     pub(in crate::imp) fn remove_object(&mut self, k: &S<Guid>) {
         self.objects.remove(k);
     }
@@ -1,4 +1,7 @@
-use crate::imp::prelude::*;
+use crate::imp::{
+    core::{DriverErrorKind, Error},
+    prelude::*,
+};
 use std::{env, fs, io};
 use zip::{result::ZipError, ZipArchive};
 
@@ -10,10 +13,13 @@ pub struct Driver {
 impl Driver {
     const ZIP: &'static [u8] = include_bytes!(concat!(env!("OUT_DIR"), env!("SEP"), "driver.zip"));
     const PLATFORM: &'static str = include_str!(concat!(env!("OUT_DIR"), env!("SEP"), "platform"));
+    /// Version of the driver bundled with this crate
+    pub const PLAYWRIGHT_VERSION: &'static str = env!("PLAYWRIGHT_VERSION");
 
     pub fn install() -> io::Result<Self> {
         let this = Self::new(Self::default_dest());
-        if !this.path.is_dir() {
+        let outdated = matches!(this.version(), Some(v) if v != Self::PLAYWRIGHT_VERSION);
+        if !this.path.is_dir() || outdated {
             this.prepare()?;
         }
         Ok(this)
@@ -53,6 +59,42 @@ impl Driver {
         }
     }
 
+    /// Version reported by the `package.json` of the installed driver
+    pub fn version(&self) -> Option<String> {
+        #[derive(Deserialize)]
+        struct Package {
+            version: String,
+        }
+        let bytes = fs::read(self.path.join("package").join("package.json")).ok()?;
+        let Package { version } = serde_json::from_slice(&bytes).ok()?;
+        Some(version)
+    }
+
+    /// Checks that the driver is installed and matches [`Driver::PLAYWRIGHT_VERSION`].
+    pub(crate) fn check(&self) -> Result<(), Error> {
+        if !self.executable().is_file() {
+            return Err(Error::DriverError {
+                kind: DriverErrorKind::NotFound,
+                message: format!(
+                    "no driver at {}; call Driver::prepare() or use Playwright::initialize() to install it",
+                    self.path.display()
+                ),
+            });
+        }
+        match self.version() {
+            Some(v) if v != Self::PLAYWRIGHT_VERSION => Err(Error::DriverError {
+                kind: DriverErrorKind::VersionMismatch,
+                message: format!(
+                    "driver at {} is version {} but {} is required; call Driver::prepare() to reinstall it",
+                    self.path.display(),
+                    v,
+                    Self::PLAYWRIGHT_VERSION
+                ),
+            }),
+            _ => Ok(()),
+        }
+    }
+
     pub fn executable(&self) -> PathBuf {
         match self.platform() {
             Platform::Linux => self.path.join("playwright.sh"),
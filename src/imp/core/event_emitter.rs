@@ -60,7 +60,9 @@ where
         }
     });
     tokio::select! {
-        _ = sleep => Err(Error::Timeout),
+        _ = sleep => Err(Error::Timeout {
+            message: format!("{}ms exceeded while waiting for event", timeout)
+        }),
         x = event => x?.map_err(Error::Event)
     }
 }
@@ -87,11 +89,30 @@ where
         }
     });
     tokio::select! {
-        _ = sleep => Err(Error::Timeout),
+        _ = sleep => Err(Error::Timeout {
+            message: format!("{}ms exceeded while waiting for event", timeout)
+        }),
         x = event => x.map_err(Error::Event)
     }
 }
 
+/// Calls `f` with every event received until the emitter is dropped.
+pub(crate) fn on_event<E, F>(mut rx: broadcast::Receiver<E>, f: F)
+where
+    E: Clone + Send + 'static,
+    F: Fn(E) + Send + 'static,
+{
+    spawn(async move {
+        loop {
+            match rx.recv().await {
+                Ok(x) => f(x),
+                Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    });
+}
+
 async fn consume<E>(rx: &mut broadcast::Receiver<E>) -> Result<(), Error>
 where
     E: IsEvent,
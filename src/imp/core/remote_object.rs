@@ -153,12 +153,12 @@ pub(crate) trait RemoteObject: Debug {
 mod remote_enum {
     use super::{DummyObject as Dummy, RootObject as Root, *};
     use crate::imp::{
-        artifact::Artifact, binding_call::BindingCall, browser::Browser,
-        browser_context::BrowserContext, browser_type::BrowserType,
-        console_message::ConsoleMessage, dialog::Dialog, element_handle::ElementHandle,
-        frame::Frame, js_handle::JsHandle, locator::Locator, page::Page, playwright::Playwright,
-        request::Request, response::Response, route::Route, selectors::Selectors, stream::Stream,
-        websocket::WebSocket, worker::Worker,
+        api_request_context::ApiRequestContext, artifact::Artifact, binding_call::BindingCall,
+        browser::Browser, browser_context::BrowserContext, browser_type::BrowserType,
+        cdp_session::CdpSession, dialog::Dialog, element_handle::ElementHandle, frame::Frame,
+        js_handle::JsHandle, local_utils::LocalUtils, locator::Locator, page::Page,
+        playwright::Playwright, request::Request, response::Response, route::Route,
+        selectors::Selectors, stream::Stream, websocket::WebSocket, worker::Worker,
     };
 
     macro_rules! upgrade {
@@ -241,19 +241,20 @@ mod remote_enum {
         // Android
         // AndroidSocket
         // AndroidDevice
+        ApiRequestContext,
         Artifact,
         BindingCall,
         Browser,
         BrowserContext,
         BrowserType,
-        // CdpSession
-        ConsoleMessage,
+        CdpSession,
         Dialog,
         // Electron
         // ElectronApplication
         ElementHandle,
         Frame,
         JsHandle,
+        LocalUtils,
         Locator,
         Page,
         Playwright,
@@ -273,6 +274,9 @@ mod remote_enum {
             c: ChannelOwner,
         ) -> Result<RemoteArc, Error> {
             let r = match typ.as_str() {
+                "APIRequestContext" => {
+                    RemoteArc::ApiRequestContext(Arc::new(ApiRequestContext::new(c)))
+                }
                 "Artifact" => RemoteArc::Artifact(Arc::new(Artifact::try_new(c)?)),
                 "BindingCall" => RemoteArc::BindingCall(Arc::new(BindingCall::new(c))),
                 "Browser" => RemoteArc::Browser(Arc::new(Browser::try_new(c)?)),
@@ -280,13 +284,12 @@ mod remote_enum {
                     RemoteArc::BrowserContext(Arc::new(BrowserContext::try_new(c)?))
                 }
                 "BrowserType" => RemoteArc::BrowserType(Arc::new(BrowserType::try_new(c)?)),
-                "ConsoleMessage" => {
-                    RemoteArc::ConsoleMessage(Arc::new(ConsoleMessage::try_new(ctx, c)?))
-                }
-                "Dialog" => RemoteArc::Dialog(Arc::new(Dialog::new(c))),
+                "CDPSession" => RemoteArc::CdpSession(Arc::new(CdpSession::new(c))),
+                "Dialog" => RemoteArc::Dialog(Arc::new(Dialog::try_new(ctx, c)?)),
                 "ElementHandle" => RemoteArc::ElementHandle(Arc::new(ElementHandle::new(c))),
                 "Frame" => RemoteArc::Frame(Arc::new(Frame::try_new(ctx, c)?)),
                 "JSHandle" => RemoteArc::JsHandle(Arc::new(JsHandle::try_new(c)?)),
+                "LocalUtils" => RemoteArc::LocalUtils(Arc::new(LocalUtils::new(c))),
                 "Locator" => RemoteArc::Locator(Arc::new(Locator::try_new(ctx, c)?)),
                 "Page" => RemoteArc::Page(Arc::new(Page::try_new(ctx, c)?)),
                 "Playwright" => RemoteArc::Playwright(Arc::new(Playwright::try_new(ctx, c)?)),
@@ -1,13 +1,63 @@
-use crate::imp::core::*;
+use crate::imp::{core::*, page::Page, prelude::*};
 
 #[derive(Debug)]
 pub(crate) struct Dialog {
     channel: ChannelOwner,
+    r#type: String,
+    message: String,
+    default_value: String,
+    page: Option<Weak<Page>>,
 }
 
 impl Dialog {
-    pub(crate) fn new(channel: ChannelOwner) -> Self {
-        Self { channel }
+    pub(crate) fn try_new(ctx: &Context, channel: ChannelOwner) -> Result<Self, Error> {
+        let Initializer {
+            r#type,
+            message,
+            default_value,
+            page,
+        } = serde_json::from_value(channel.initializer.clone())?;
+        let page = match page {
+            Some(OnlyGuid { guid }) => Some(get_object!(ctx, &guid, Page)?),
+            None => None,
+        };
+        Ok(Self {
+            channel,
+            r#type,
+            message,
+            default_value,
+            page,
+        })
+    }
+
+    pub(crate) fn r#type(&self) -> &str {
+        &self.r#type
+    }
+
+    pub(crate) fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub(crate) fn default_value(&self) -> &str {
+        &self.default_value
+    }
+
+    pub(crate) fn page(&self) -> Option<Weak<Page>> {
+        self.page.clone()
+    }
+
+    pub(crate) async fn accept(&self, prompt_text: Option<&str>) -> ArcResult<()> {
+        let mut args = HashMap::new();
+        if let Some(x) = prompt_text {
+            args.insert("promptText", x);
+        }
+        let _ = send_message!(self, "accept", args);
+        Ok(())
+    }
+
+    pub(crate) async fn dismiss(&self) -> ArcResult<()> {
+        let _ = send_message!(self, "dismiss", Map::new());
+        Ok(())
     }
 }
 
@@ -19,3 +69,13 @@ impl RemoteObject for Dialog {
         &mut self.channel
     }
 }
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Initializer {
+    r#type: String,
+    message: String,
+    #[serde(default)]
+    default_value: String,
+    page: Option<OnlyGuid>,
+}
@@ -1,6 +1,7 @@
 use crate::imp::{
     core::*,
     frame::Frame,
+    js_handle::JsHandle,
     prelude::*,
     utils::{
         ElementState, File, FloatRect, KeyboardModifier, MouseButton, Position, ScreenshotType,
@@ -32,6 +33,58 @@ impl ElementHandle {
         Self { channel }
     }
 
+    pub(crate) async fn eval<U>(&self, expression: &str) -> ArcResult<U>
+    where
+        U: DeserializeOwned,
+    {
+        self.evaluate::<(), U>(expression, None).await
+    }
+
+    /// The element is passed to `expression` as its first argument and `arg` as the second.
+    pub(crate) async fn evaluate<T, U>(&self, expression: &str, arg: Option<T>) -> ArcResult<U>
+    where
+        T: Serialize,
+        U: DeserializeOwned,
+    {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Args<'a> {
+            expression: &'a str,
+            arg: Value,
+        }
+        let arg = ser::to_value(&arg).map_err(Error::SerializationPwJson)?;
+        let args = Args { expression, arg };
+        let v = send_message!(self, "evaluateExpression", args);
+        let first = first(&v).ok_or(Error::ObjectNotFound)?;
+        Ok(de::from_value(first).map_err(Error::DeserializationPwJson)?)
+    }
+
+    pub(crate) async fn eval_handle(&self, expression: &str) -> ArcResult<Weak<JsHandle>> {
+        self.evaluate_handle::<()>(expression, None).await
+    }
+
+    pub(crate) async fn evaluate_handle<T>(
+        &self,
+        expression: &str,
+        arg: Option<T>,
+    ) -> ArcResult<Weak<JsHandle>>
+    where
+        T: Serialize,
+    {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Args<'a> {
+            expression: &'a str,
+            arg: Value,
+        }
+        let arg = ser::to_value(&arg).map_err(Error::SerializationPwJson)?;
+        let args = Args { expression, arg };
+        let v = send_message!(self, "evaluateExpressionHandle", args);
+        let guid = only_guid(&v)?;
+        let h = get_object!(self.context()?.lock().unwrap(), guid, JsHandle)?;
+        Ok(h)
+    }
+
     pub(crate) async fn query_selector(
         &self,
         selector: &str,
@@ -72,6 +125,12 @@ impl ElementHandle {
         Ok(s.to_owned())
     }
 
+    pub(crate) async fn input_value(&self) -> ArcResult<String> {
+        let v = send_message!(self, "inputValue", Map::new());
+        let s = only_str(&v)?;
+        Ok(s.to_owned())
+    }
+
     pub(crate) async fn inner_html(&self) -> ArcResult<String> {
         let v = send_message!(self, "innerHTML", Map::new());
         let s = only_str(&v)?;
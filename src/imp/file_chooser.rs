@@ -31,3 +31,14 @@ impl FileChooser {
         }
     }
 }
+
+/// Options for [`FileChooser::set_files`](crate::api::FileChooser::set_files).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SetFilesOptions {
+    /// Maximum time in milliseconds, defaults to 30 seconds, pass `0` to disable timeout.
+    pub timeout: Option<f64>,
+    /// Actions that initiate navigations are waiting for these navigations to happen and for pages to start loading. You can
+    /// opt out of waiting via setting this flag. You would only need this option in the exceptional cases such as navigating
+    /// to inaccessible pages. Defaults to `false`.
+    pub no_wait_after: Option<bool>,
+}
@@ -12,19 +12,51 @@ use crate::imp::{
 use std::{collections::HashSet, iter::FromIterator};
 
 // Locator options structs
-#[derive(Debug, Default)]
-pub(crate) struct GetByRoleOptions {
-    pub name: Option<String>,
+/// Options of [`Locator::get_by_role`](crate::api::Locator::get_by_role).
+#[derive(Debug, Default, Clone)]
+pub struct GetByRoleOptions {
+    /// Option to match the [accessible name](https://w3c.github.io/accname/#dfn-accessible-name). By default, matching is
+    /// case-insensitive and searches for a substring, use `exact` to control this behavior.
+    pub name: Option<RoleName>,
+    /// Whether `name` is matched exactly: case-sensitive and whole-string.
     pub exact: Option<bool>,
+    /// An attribute that is usually set by `aria-checked` or native `<input type=checkbox>` controls.
     pub checked: Option<bool>,
+    /// An attribute that is usually set by `aria-disabled` or `disabled`.
     pub disabled: Option<bool>,
+    /// An attribute that is usually set by `aria-expanded`.
     pub expanded: Option<bool>,
+    /// Whether hidden elements are matched. By default, only non-hidden elements are matched.
     pub include_hidden: Option<bool>,
+    /// A number attribute that is usually present for roles `heading`, `listitem`, `row`, `treeitem`.
     pub level: Option<i32>,
+    /// An attribute that is usually set by `aria-pressed`.
     pub pressed: Option<bool>,
+    /// An attribute that is usually set by `aria-selected`.
     pub selected: Option<bool>,
 }
 
+/// Accessible name matcher of [`GetByRoleOptions::name`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoleName {
+    /// Matches the name as a string. Case-insensitive substring unless [`GetByRoleOptions::exact`] is set.
+    Exact(String),
+    /// Matches the name against a JavaScript regular expression source, such as `submit|send`.
+    Regex(String),
+}
+
+impl From<&str> for RoleName {
+    fn from(name: &str) -> Self {
+        Self::Exact(name.to_owned())
+    }
+}
+
+impl From<String> for RoleName {
+    fn from(name: String) -> Self {
+        Self::Exact(name)
+    }
+}
+
 #[derive(Debug, Default)]
 pub(crate) struct GetByTextOptions {
     pub exact: Option<bool>,
@@ -41,80 +73,114 @@ pub(crate) struct GetByPlaceholderOptions {
 }
 
 // Selector generation functions (client-side implementation)
-fn build_role_selector(role: &str, options: Option<&GetByRoleOptions>) -> String {
-    let mut selector = format!("internal:role={}", role);
+/// Quotes `text` for an attribute selector. `i` suffix matches case-insensitively, `s` exactly.
+fn escape_for_attribute_selector(text: &str, exact: bool) -> String {
+    let quoted = text.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("\"{}\"{}", quoted, if exact { "s" } else { "i" })
+}
 
-    if let Some(opts) = options {
-        if let Some(name) = &opts.name {
-            selector.push_str(&format!("[name=\"{}\"]", name));
-        }
-        if let Some(exact) = opts.exact {
-            selector.push_str(&format!("[exact={}]", exact));
-        }
-        if let Some(checked) = opts.checked {
-            selector.push_str(&format!("[checked={}]", checked));
-        }
-        if let Some(disabled) = opts.disabled {
-            selector.push_str(&format!("[disabled={}]", disabled));
-        }
-        if let Some(expanded) = opts.expanded {
-            selector.push_str(&format!("[expanded={}]", expanded));
-        }
-        if let Some(include_hidden) = opts.include_hidden {
-            selector.push_str(&format!("[include-hidden={}]", include_hidden));
-        }
-        if let Some(level) = opts.level {
-            selector.push_str(&format!("[level={}]", level));
-        }
-        if let Some(pressed) = opts.pressed {
-            selector.push_str(&format!("[pressed={}]", pressed));
-        }
-        if let Some(selected) = opts.selected {
-            selector.push_str(&format!("[selected={}]", selected));
+/// Writes `source` as a regex literal, escaping quotes and `>>` that would end the selector.
+fn escape_regex_for_selector(source: &str) -> String {
+    let mut escaped = String::with_capacity(source.len());
+    let mut backslashes = 0;
+    for c in source.chars() {
+        if matches!(c, '"' | '\'' | '`' | '/') && backslashes % 2 == 0 {
+            escaped.push('\\');
         }
+        backslashes = if c == '\\' { backslashes + 1 } else { 0 };
+        escaped.push(c);
     }
+    format!("/{}/", escaped.replace(">>", "\\>\\>"))
+}
 
+pub(crate) fn build_role_selector(role: &str, options: &GetByRoleOptions) -> String {
+    let mut selector = format!("internal:role={}", role);
+    let GetByRoleOptions {
+        name,
+        exact,
+        checked,
+        disabled,
+        expanded,
+        include_hidden,
+        level,
+        pressed,
+        selected,
+    } = options;
+    if let Some(checked) = checked {
+        selector.push_str(&format!("[checked={}]", checked));
+    }
+    if let Some(disabled) = disabled {
+        selector.push_str(&format!("[disabled={}]", disabled));
+    }
+    if let Some(selected) = selected {
+        selector.push_str(&format!("[selected={}]", selected));
+    }
+    if let Some(expanded) = expanded {
+        selector.push_str(&format!("[expanded={}]", expanded));
+    }
+    if let Some(include_hidden) = include_hidden {
+        selector.push_str(&format!("[include-hidden={}]", include_hidden));
+    }
+    if let Some(level) = level {
+        selector.push_str(&format!("[level={}]", level));
+    }
+    if let Some(name) = name {
+        let name = match name {
+            RoleName::Exact(name) => escape_for_attribute_selector(name, exact.unwrap_or_default()),
+            RoleName::Regex(source) => escape_regex_for_selector(source),
+        };
+        selector.push_str(&format!("[name={}]", name));
+    }
+    if let Some(pressed) = pressed {
+        selector.push_str(&format!("[pressed={}]", pressed));
+    }
     selector
 }
 
-fn build_text_selector(text: &str, options: Option<&GetByTextOptions>) -> String {
-    let mut selector = format!("internal:text=\"{}\"", text);
-
-    if let Some(opts) = options {
-        if let Some(exact) = opts.exact {
-            selector.push_str(&format!("[exact={}]", exact));
-        }
-    }
+pub(crate) fn build_text_selector(text: &str, exact: Option<bool>) -> String {
+    let text = escape_for_attribute_selector(text, exact.unwrap_or_default());
+    format!("internal:text={}", text)
+}
 
-    selector
+pub(crate) fn build_label_selector(text: &str, exact: Option<bool>) -> String {
+    let text = escape_for_attribute_selector(text, exact.unwrap_or_default());
+    format!("internal:label={}", text)
 }
 
-fn build_label_selector(text: &str, options: Option<&GetByLabelOptions>) -> String {
-    let mut selector = format!("internal:label=\"{}\"", text);
+fn build_attr_selector(name: &str, value: &str, exact: Option<bool>) -> String {
+    let value = escape_for_attribute_selector(value, exact.unwrap_or_default());
+    format!("internal:attr=[{}={}]", name, value)
+}
 
-    if let Some(opts) = options {
-        if let Some(exact) = opts.exact {
-            selector.push_str(&format!("[exact={}]", exact));
-        }
-    }
+pub(crate) fn build_placeholder_selector(text: &str, exact: Option<bool>) -> String {
+    build_attr_selector("placeholder", text, exact)
+}
 
-    selector
+pub(crate) fn build_alt_text_selector(text: &str, exact: Option<bool>) -> String {
+    build_attr_selector("alt", text, exact)
 }
 
-fn build_placeholder_selector(text: &str, options: Option<&GetByPlaceholderOptions>) -> String {
-    let mut selector = format!("internal:attr=[placeholder=\"{}\"]", text);
+pub(crate) fn build_title_selector(text: &str, exact: Option<bool>) -> String {
+    build_attr_selector("title", text, exact)
+}
 
-    if let Some(opts) = options {
-        if let Some(exact) = opts.exact {
-            selector.push_str(&format!("[exact={}]", exact));
-        }
-    }
+pub(crate) fn build_has_text_selector(text: &str, not: bool) -> String {
+    let engine = if not { "has-not-text" } else { "has-text" };
+    format!(
+        "internal:{}={}",
+        engine,
+        escape_for_attribute_selector(text, false)
+    )
+}
 
-    selector
+pub(crate) fn build_has_selector(selector: &str, not: bool) -> String {
+    let engine = if not { "has-not" } else { "has" };
+    format!("internal:{}={}", engine, Value::from(selector))
 }
 
-fn build_test_id_selector(test_id: &str) -> String {
-    format!("internal:testid=\"{}\"", test_id)
+pub(crate) fn build_test_id_selector(test_id: &str) -> String {
+    let test_id = escape_for_attribute_selector(test_id, true);
+    format!("internal:testid=[data-testid={}]", test_id)
 }
 
 #[derive(Debug)]
@@ -242,6 +308,12 @@ impl Frame {
         Ok(())
     }
 
+    pub(crate) async fn blur(&self, selector: &str, timeout: Option<f64>) -> ArcResult<()> {
+        let args = SelectorTimeout { selector, timeout };
+        let _ = send_message!(self, "blur", args);
+        Ok(())
+    }
+
     pub(crate) async fn text_content(
         &self,
         selector: &str,
@@ -275,6 +347,17 @@ impl Frame {
         Ok(s.into())
     }
 
+    pub(crate) async fn input_value(
+        &self,
+        selector: &str,
+        timeout: Option<f64>,
+    ) -> ArcResult<String> {
+        let args = SelectorTimeout { selector, timeout };
+        let v = send_message!(self, "inputValue", args);
+        let s = only_str(&v)?;
+        Ok(s.into())
+    }
+
     pub(crate) async fn get_attribute(
         &self,
         selector: &str,
@@ -303,8 +386,31 @@ impl Frame {
         &self,
         selector: &str,
     ) -> ArcResult<Option<Weak<ElementHandle>>> {
-        let mut args = HashMap::new();
-        args.insert("selector", selector);
+        self.send_query_selector(selector, None).await
+    }
+
+    /// Fails with [`Error::AmbiguousMatch`] if more than one element matches `selector`.
+    pub(crate) async fn query_selector_strict(
+        &self,
+        selector: &str,
+    ) -> ArcResult<Option<Weak<ElementHandle>>> {
+        self.send_query_selector(selector, Some(true))
+            .await
+            .map_err(|e| Error::strict(selector, e))
+    }
+
+    async fn send_query_selector(
+        &self,
+        selector: &str,
+        strict: Option<bool>,
+    ) -> ArcResult<Option<Weak<ElementHandle>>> {
+        #[skip_serializing_none]
+        #[derive(Serialize)]
+        struct Args<'a> {
+            selector: &'a str,
+            strict: Option<bool>,
+        }
+        let args = Args { selector, strict };
         let v = send_message!(self, "querySelector", args);
         let guid = match as_only_guid(&v) {
             Some(g) => g,
@@ -314,6 +420,13 @@ impl Frame {
         Ok(Some(e))
     }
 
+    pub(crate) async fn query_count(&self, selector: &str) -> ArcResult<usize> {
+        let mut args = HashMap::new();
+        args.insert("selector", selector);
+        let v = send_message!(self, "queryCount", args);
+        Ok(only_u64(&v)? as usize)
+    }
+
     pub(crate) async fn query_selector_all(
         &self,
         selector: &str,
@@ -344,7 +457,11 @@ impl Frame {
         &self,
         args: WaitForSelectorArgs<'_>,
     ) -> ArcResult<Option<Weak<ElementHandle>>> {
-        let v = send_message!(self, "waitForSelector", args);
+        let selector = args.selector;
+        let v = match self.send_wait_for_selector(args).await {
+            Ok(v) => v,
+            Err(e) => return Err(Error::strict(selector, e)),
+        };
         let guid = match as_only_guid(&v) {
             Some(g) => g,
             None => return Ok(None),
@@ -353,6 +470,10 @@ impl Frame {
         Ok(Some(e))
     }
 
+    async fn send_wait_for_selector(&self, args: WaitForSelectorArgs<'_>) -> ArcResult<Arc<Value>> {
+        Ok(send_message!(self, "waitForSelector", args))
+    }
+
     pub(crate) async fn title(&self) -> ArcResult<String> {
         let v = send_message!(self, "title", Map::new());
         let s = only_str(&v)?;
@@ -453,6 +574,26 @@ impl Frame {
         Ok(de::from_value(first).map_err(Error::DeserializationPwJson)?)
     }
 
+    /// Fails with [`Error::Timeout`] when `expression` does not settle within `timeout` milliseconds. The page keeps
+    /// running the expression.
+    pub(crate) async fn evaluate_with_timeout<T, U>(
+        &self,
+        expression: &str,
+        arg: Option<T>,
+        timeout: f64,
+    ) -> ArcResult<U>
+    where
+        T: Serialize,
+        U: DeserializeOwned,
+    {
+        tokio::select! {
+            _ = sleep(Duration::from_millis(timeout as u64)) => Err(Arc::new(Error::Timeout {
+                message: format!("{}ms exceeded while evaluating", timeout)
+            })),
+            r = self.evaluate(expression, arg) => r
+        }
+    }
+
     async fn evaluate_handle<T>(&self, expression: &str, arg: Option<T>) -> ArcResult<Handle>
     where
         T: Serialize,
@@ -591,7 +732,7 @@ impl Frame {
     }
 
     // Client-side Locator creation (following TypeScript/Go pattern)
-    pub(crate) async fn locator(&self, selector: &str) -> ArcResult<Weak<Locator>> {
+    pub(crate) fn locator(&self, selector: &str) -> ArcResult<Arc<Locator>> {
         // Create client-side Locator object (no server communication needed)
         // Get weak reference to this frame from the context registry
         let context = self.context()?;
@@ -601,71 +742,63 @@ impl Frame {
             get_object!(ctx, &frame_guid, Frame)?
         };
 
-        let locator = Locator::new_client_side(frame_weak, selector.to_string());
-        let locator_arc = Arc::new(locator);
-        let locator_weak = Arc::downgrade(&locator_arc);
-
-        // Store the Arc so it doesn't get dropped immediately
-        // This ensures our client-side locators persist like server-side objects
-        std::mem::forget(locator_arc.clone());
-
-        Ok(locator_weak)
+        Ok(Locator::client_side(frame_weak, selector.to_string()))
     }
 
-    pub(crate) async fn get_by_role(
+    pub(crate) fn get_by_role(
         &self,
         role: &str,
-        options: Option<GetByRoleOptions>,
-    ) -> ArcResult<Weak<Locator>> {
+        options: &GetByRoleOptions,
+    ) -> ArcResult<Arc<Locator>> {
         // Generate selector client-side (like official Playwright clients)
-        let selector = build_role_selector(role, options.as_ref());
+        let selector = build_role_selector(role, options);
 
         // Use existing working locator method instead of direct protocol call
-        self.locator(&selector).await
+        self.locator(&selector)
     }
 
-    pub(crate) async fn get_by_text(
+    pub(crate) fn get_by_text(
         &self,
         text: &str,
         options: Option<GetByTextOptions>,
-    ) -> ArcResult<Weak<Locator>> {
+    ) -> ArcResult<Arc<Locator>> {
         // Generate selector client-side (like official Playwright clients)
-        let selector = build_text_selector(text, options.as_ref());
+        let selector = build_text_selector(text, options.and_then(|o| o.exact));
 
         // Use existing working locator method instead of direct protocol call
-        self.locator(&selector).await
+        self.locator(&selector)
     }
 
-    pub(crate) async fn get_by_label(
+    pub(crate) fn get_by_label(
         &self,
         text: &str,
         options: Option<GetByLabelOptions>,
-    ) -> ArcResult<Weak<Locator>> {
+    ) -> ArcResult<Arc<Locator>> {
         // Generate selector client-side (like official Playwright clients)
-        let selector = build_label_selector(text, options.as_ref());
+        let selector = build_label_selector(text, options.and_then(|o| o.exact));
 
         // Use existing working locator method instead of direct protocol call
-        self.locator(&selector).await
+        self.locator(&selector)
     }
 
-    pub(crate) async fn get_by_placeholder(
+    pub(crate) fn get_by_placeholder(
         &self,
         text: &str,
         options: Option<GetByPlaceholderOptions>,
-    ) -> ArcResult<Weak<Locator>> {
+    ) -> ArcResult<Arc<Locator>> {
         // Generate selector client-side (like official Playwright clients)
-        let selector = build_placeholder_selector(text, options.as_ref());
+        let selector = build_placeholder_selector(text, options.and_then(|o| o.exact));
 
         // Use existing working locator method instead of direct protocol call
-        self.locator(&selector).await
+        self.locator(&selector)
     }
 
-    pub(crate) async fn get_by_test_id(&self, test_id: &str) -> ArcResult<Weak<Locator>> {
+    pub(crate) fn get_by_test_id(&self, test_id: &str) -> ArcResult<Arc<Locator>> {
         // Generate selector client-side (like official Playwright clients)
         let selector = build_test_id_selector(test_id);
 
         // Use existing working locator method instead of direct protocol call
-        self.locator(&selector).await
+        self.locator(&selector)
     }
 }
 
@@ -835,6 +968,7 @@ pub(crate) struct ClickArgs<'a> {
     pub(crate) force: Option<bool>,
     pub(crate) no_wait_after: Option<bool>,
     pub(crate) trial: Option<bool>,
+    pub(crate) strict: Option<bool>,
 }
 
 impl<'a> ClickArgs<'a> {
@@ -851,6 +985,7 @@ impl<'a> ClickArgs<'a> {
             force: None,
             no_wait_after: None,
             trial: None,
+            strict: None,
         }
     }
 }
@@ -862,6 +997,7 @@ pub(crate) struct WaitForSelectorArgs<'a> {
     selector: &'a str,
     pub(crate) timeout: Option<f64>,
     pub(crate) state: Option<FrameState>,
+    pub(crate) strict: Option<bool>,
 }
 
 impl<'a> WaitForSelectorArgs<'a> {
@@ -870,11 +1006,25 @@ impl<'a> WaitForSelectorArgs<'a> {
             selector,
             timeout: None,
             state: None,
+            strict: None,
         }
     }
 }
 
-#[derive(Serialize)]
+/// Options for [`Frame::wait_for_selector`](crate::api::Frame::wait_for_selector).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WaitForSelectorOptions {
+    /// The state to wait for. Defaults to `'visible'`. Waiting for `Detached` or `Hidden` resolves to `None` since
+    /// no visible element is left to return.
+    pub state: Option<FrameState>,
+    /// Maximum time in milliseconds. Defaults to the page's default timeout.
+    pub timeout: Option<f64>,
+    /// When true, the call requires selector to resolve to a single element and fails with
+    /// [`Error::AmbiguousMatch`](crate::Error::AmbiguousMatch) otherwise.
+    pub strict: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum FrameState {
     Attached,
@@ -885,6 +1035,7 @@ pub enum FrameState {
 
 macro_rules! type_args {
     ($t:ident, $f:ident) => {
+        #[skip_serializing_none]
         #[derive(Serialize)]
         #[serde(rename_all = "camelCase")]
         pub(crate) struct $t<'a, 'b> {
@@ -893,6 +1044,7 @@ macro_rules! type_args {
             pub(crate) delay: Option<f64>,
             pub(crate) timeout: Option<f64>,
             pub(crate) no_wait_after: Option<bool>,
+            pub(crate) strict: Option<bool>,
         }
 
         impl<'a, 'b> $t<'a, 'b> {
@@ -903,6 +1055,7 @@ macro_rules! type_args {
                     delay: None,
                     timeout: None,
                     no_wait_after: None,
+                    strict: None,
                 }
             }
         }
@@ -921,7 +1074,9 @@ pub(crate) struct HoverArgs<'a> {
     pub(crate) position: Option<Position>,
     pub(crate) timeout: Option<f64>,
     pub(crate) force: Option<bool>,
+    pub(crate) no_wait_after: Option<bool>,
     pub(crate) trial: Option<bool>,
+    pub(crate) strict: Option<bool>,
 }
 
 impl<'a> HoverArgs<'a> {
@@ -932,7 +1087,9 @@ impl<'a> HoverArgs<'a> {
             position: None,
             timeout: None,
             force: None,
+            no_wait_after: None,
             trial: None,
+            strict: None,
         }
     }
 }
@@ -956,6 +1113,15 @@ impl<'a> SetContentArgs<'a> {
     }
 }
 
+/// Options for [`Frame::set_content`](crate::api::Frame::set_content).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SetContentOptions {
+    /// Maximum operation time in milliseconds. Defaults to the page's default navigation timeout.
+    pub timeout: Option<f64>,
+    /// When to consider the operation succeeded. Defaults to `load`.
+    pub wait_until: Option<DocumentLoadState>,
+}
+
 #[skip_serializing_none]
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -991,6 +1157,7 @@ pub(crate) struct FillArgs<'a, 'b> {
     value: &'b str,
     pub(crate) timeout: Option<f64>,
     pub(crate) no_wait_after: Option<bool>,
+    pub(crate) strict: Option<bool>,
 }
 
 impl<'a, 'b> FillArgs<'a, 'b> {
@@ -1000,6 +1167,7 @@ impl<'a, 'b> FillArgs<'a, 'b> {
             value,
             timeout: None,
             no_wait_after: None,
+            strict: None,
         }
     }
 }
@@ -1022,6 +1190,7 @@ pub(crate) struct CheckArgs<'a> {
     pub(crate) force: Option<bool>,
     pub(crate) no_wait_after: Option<bool>,
     pub(crate) trial: Option<bool>,
+    pub(crate) strict: Option<bool>,
 }
 
 impl<'a> CheckArgs<'a> {
@@ -1033,6 +1202,7 @@ impl<'a> CheckArgs<'a> {
             force: None,
             no_wait_after: None,
             trial: None,
+            strict: None,
         }
     }
 }
@@ -1067,6 +1237,8 @@ pub(crate) struct SelectOptionArgs<'a> {
 
     pub(crate) timeout: Option<f64>,
     pub(crate) no_wait_after: Option<bool>,
+    pub(crate) force: Option<bool>,
+    pub(crate) strict: Option<bool>,
 }
 
 impl<'a> SelectOptionArgs<'a> {
@@ -1077,6 +1249,8 @@ impl<'a> SelectOptionArgs<'a> {
             elements: None,
             timeout: None,
             no_wait_after: None,
+            force: None,
+            strict: None,
         }
     }
 }
@@ -1119,6 +1293,16 @@ pub enum Polling {
     Millis(u32),
 }
 
+/// Options for [`Frame::wait_for_function`](crate::api::Frame::wait_for_function).
+#[derive(Default)]
+pub struct WaitForFunctionOptions {
+    /// If `polling` is `'raf'`, then `expression` is constantly executed in `requestAnimationFrame` callback. If `polling` is a
+    /// number, then it is treated as an interval in milliseconds at which the function would be executed. Defaults to `raf`.
+    pub polling: Option<Polling>,
+    /// Maximum time to wait for in milliseconds. Defaults to the page's default timeout.
+    pub timeout: Option<f64>,
+}
+
 impl Serialize for Polling {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1212,4 +1396,35 @@ mod tests {
         let s = serde_json::to_string(&Polling::RequestAnimationFrame).unwrap();
         assert_eq!(s, r#""raf""#);
     }
+
+    #[test]
+    fn role_selector_name() {
+        let options = |name: RoleName, exact: Option<bool>| GetByRoleOptions {
+            name: Some(name),
+            exact,
+            ..Default::default()
+        };
+        assert_eq!(
+            build_role_selector("button", &options("Submit".into(), Some(true))),
+            r#"internal:role=button[name="Submit"s]"#
+        );
+        assert_eq!(
+            build_role_selector("button", &options("Submit".into(), None)),
+            r#"internal:role=button[name="Submit"i]"#
+        );
+        assert_eq!(
+            build_role_selector(
+                "button",
+                &options(RoleName::Regex("submit|send".into()), None)
+            ),
+            "internal:role=button[name=/submit|send/]"
+        );
+        assert_eq!(
+            build_role_selector(
+                "link",
+                &options(RoleName::Regex(r#"a"b>>c\/d"#.into()), None)
+            ),
+            r#"internal:role=link[name=/a\"b\>\>c\/d/]"#
+        );
+    }
 }
@@ -0,0 +1,78 @@
+use crate::imp::{
+    core::*,
+    local_utils::{HarAction, HarLookupArgs, LocalUtils},
+    prelude::*,
+    route::{FulfillArgs, Route, RouteHandlerFn},
+    utils::Header,
+};
+
+/// Answers routed requests with the entries of a HAR file. Requests without an entry are aborted.
+#[derive(Debug, Clone)]
+pub(crate) struct HarRouter {
+    local_utils: Weak<LocalUtils>,
+    har_id: String,
+}
+
+impl HarRouter {
+    pub(crate) async fn new(local_utils: Weak<LocalUtils>, file: &Path) -> ArcResult<Self> {
+        let har_id = upgrade(&local_utils)?.har_open(file).await?;
+        Ok(Self {
+            local_utils,
+            har_id,
+        })
+    }
+
+    pub(crate) fn handler(&self) -> RouteHandlerFn {
+        let this = self.clone();
+        Arc::new(move |route| {
+            let this = this.clone();
+            spawn(async move {
+                if let Err(e) = this.handle(route).await {
+                    log::debug!("failed to route from HAR: {:?}", e);
+                }
+            });
+        })
+    }
+
+    async fn handle(&self, route: Weak<Route>) -> ArcResult<()> {
+        let route = upgrade(&route)?;
+        let request = upgrade(&route.request())?;
+        let headers = request
+            .headers()
+            .iter()
+            .map(|(k, v)| Header::from((k.clone(), v.clone())))
+            .collect();
+        let args = HarLookupArgs {
+            har_id: &self.har_id,
+            url: request.url(),
+            method: request.method(),
+            headers,
+            post_data: request.post_data_base64(),
+            is_navigation_request: request.is_navigation_request(),
+        };
+        let res = upgrade(&self.local_utils)?.har_lookup(args).await?;
+        let action = res.action;
+        match action {
+            HarAction::Redirect => {
+                let url = res.redirect_url.unwrap_or_default();
+                route.redirect_navigation_request(&url).await
+            }
+            HarAction::Fulfill if res.status != Some(-1) => {
+                let body = res.body.unwrap_or_default();
+                let mut args = FulfillArgs::new(&body, true);
+                args.status = res.status;
+                args.headers = res.headers;
+                route.fulfill(args).await
+            }
+            HarAction::Error => {
+                log::debug!("HAR lookup failed: {:?}", res.message);
+                route.abort(None).await
+            }
+            _ => route.abort(None).await,
+        }
+    }
+
+    pub(crate) async fn dispose(&self) -> ArcResult<()> {
+        upgrade(&self.local_utils)?.har_close(&self.har_id).await
+    }
+}
@@ -0,0 +1,90 @@
+use crate::imp::{core::*, prelude::*, utils::Header};
+
+/// Client side helpers of the driver, such as reading HAR files.
+#[derive(Debug)]
+pub(crate) struct LocalUtils {
+    channel: ChannelOwner,
+}
+
+impl LocalUtils {
+    pub(crate) fn new(channel: ChannelOwner) -> Self {
+        Self { channel }
+    }
+
+    pub(crate) async fn har_open(&self, file: &Path) -> ArcResult<String> {
+        #[derive(Serialize)]
+        struct Args<'a> {
+            file: &'a Path,
+        }
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Res {
+            har_id: Option<String>,
+            error: Option<String>,
+        }
+        let v = send_message!(self, "harOpen", Args { file });
+        let Res { har_id, error } = serde_json::from_value((*v).clone()).map_err(Error::Serde)?;
+        har_id.ok_or_else(|| {
+            Arc::new(Error::ProtocolError {
+                method: "harOpen".into(),
+                message: error.unwrap_or_default(),
+            })
+        })
+    }
+
+    pub(crate) async fn har_lookup(&self, args: HarLookupArgs<'_>) -> ArcResult<HarLookup> {
+        let v = send_message!(self, "harLookup", args);
+        let res = serde_json::from_value((*v).clone()).map_err(Error::Serde)?;
+        Ok(res)
+    }
+
+    pub(crate) async fn har_close(&self, har_id: &str) -> ArcResult<()> {
+        let mut args = HashMap::new();
+        args.insert("harId", har_id);
+        let _ = send_message!(self, "harClose", args);
+        Ok(())
+    }
+}
+
+impl RemoteObject for LocalUtils {
+    fn channel(&self) -> &ChannelOwner {
+        &self.channel
+    }
+    fn channel_mut(&mut self) -> &mut ChannelOwner {
+        &mut self.channel
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct HarLookupArgs<'a> {
+    pub(crate) har_id: &'a str,
+    pub(crate) url: &'a str,
+    pub(crate) method: &'a str,
+    pub(crate) headers: Vec<Header>,
+    pub(crate) post_data: Option<&'a str>,
+    pub(crate) is_navigation_request: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct HarLookup {
+    pub(crate) action: HarAction,
+    pub(crate) message: Option<String>,
+    #[serde(rename = "redirectURL")]
+    pub(crate) redirect_url: Option<String>,
+    pub(crate) status: Option<i32>,
+    pub(crate) headers: Option<Vec<Header>>,
+    /// base64
+    pub(crate) body: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum HarAction {
+    Error,
+    Redirect,
+    Fulfill,
+    NoEntry,
+}
@@ -1,11 +1,13 @@
 use crate::imp::{
     core::*,
     element_handle::SetInputFilesArgs,
-    frame::Frame,
+    frame::{build_has_selector, build_has_text_selector, Frame, FrameState, WaitForSelectorArgs},
+    page::ScreenshotMask,
     prelude::*,
-    utils::{KeyboardModifier, MouseButton, Position},
+    utils::{FloatRect, KeyboardModifier, MouseButton, Position},
 };
 use serde_json::map::Map;
+use std::{str::FromStr, sync::OnceLock};
 
 #[derive(Debug)]
 pub(crate) struct Locator {
@@ -42,6 +44,11 @@ impl Locator {
         }
     }
 
+    /// Maps an error of an action on this locator to the variant describing what went wrong.
+    pub(crate) fn error(&self, e: Arc<Error>) -> Error {
+        Error::locator(&self.selector, e)
+    }
+
     pub(crate) fn selector(&self) -> &str {
         &self.selector
     }
@@ -50,6 +57,75 @@ impl Locator {
         self.frame.clone()
     }
 
+    pub(crate) fn mask(&self) -> Result<ScreenshotMask, Error> {
+        let frame = upgrade(&self.frame)?;
+        Ok(ScreenshotMask {
+            frame: OnlyGuid {
+                guid: frame.guid().to_owned(),
+            },
+            selector: self.selector.clone(),
+        })
+    }
+
+    /// Creates a client-side locator. It is owned by the [`api::Locator`](crate::api::Locator) wrapping it and freed
+    /// with the last of its clones.
+    pub(crate) fn client_side(frame: Weak<Frame>, selector: String) -> Arc<Locator> {
+        Arc::new(Locator::new_client_side(frame, selector))
+    }
+
+    /// Creates a client-side locator for `selector` scoped to the elements of this locator.
+    pub(crate) fn child(&self, selector: &str) -> Arc<Locator> {
+        let selector = format!("{} >> {}", self.selector, selector);
+        Self::client_side(self.frame.clone(), selector)
+    }
+
+    /// Matches the elements of either locator, each element once. Both must belong to the same frame.
+    pub(crate) fn or(&self, other: &Locator) -> Result<Arc<Locator>, Error> {
+        if !self.frame.ptr_eq(&other.frame) {
+            return Err(Error::InvalidArgument {
+                message: "Locators must belong to the same frame.".into(),
+            });
+        }
+        Ok(self.child(&format!(
+            "internal:or={}",
+            Value::from(other.selector.as_str())
+        )))
+    }
+
+    /// Bounding box of the first matching element. Fails with [`Error::ElementNotFound`] when nothing matches or the
+    /// element is not visible.
+    pub(crate) async fn bounding_box(&self) -> ArcResult<FloatRect> {
+        let not_found = || {
+            Arc::new(Error::ElementNotFound {
+                selector: self.selector.clone(),
+            })
+        };
+        let frame = upgrade(&self.frame)?;
+        let element = frame
+            .query_selector(&self.selector)
+            .await?
+            .ok_or_else(not_found)?;
+        upgrade(&element)?
+            .bounding_box()
+            .await?
+            .ok_or_else(not_found)
+    }
+
+    /// Selector of this locator used as the inner locator of a `has` or `has_not` filter on a locator of `frame`
+    pub(crate) fn inner_selector(&self, frame: &Weak<Frame>) -> Result<String, Error> {
+        if !self.frame.ptr_eq(frame) {
+            return Err(Error::InvalidArgument {
+                message: "Inner \"has\" locator must belong to the same frame.".into(),
+            });
+        }
+        Ok(self.selector.clone())
+    }
+
+    /// Whether both locators match the same elements
+    pub(crate) fn same_target(&self, other: &Locator) -> bool {
+        self.selector == other.selector && self.frame.ptr_eq(&other.frame)
+    }
+
     // Action methods - Delegate to Frame methods (following TypeScript/Go pattern)
     pub(crate) async fn click(&self, args: ClickArgs) -> Result<(), Arc<Error>> {
         if let Some(frame) = self.frame.upgrade() {
@@ -63,7 +139,8 @@ impl Locator {
             frame_args.timeout = args.timeout;
             frame_args.force = args.force;
             frame_args.no_wait_after = args.no_wait_after;
-            // trial defaults to None in constructor
+            frame_args.trial = args.trial;
+            frame_args.strict = args.strict;
             frame.click(frame_args).await
         } else {
             Err(Arc::new(crate::Error::ObjectNotFound))
@@ -81,6 +158,8 @@ impl Locator {
             frame_args.timeout = args.timeout;
             frame_args.force = args.force;
             frame_args.no_wait_after = args.no_wait_after;
+            frame_args.trial = args.trial;
+            frame_args.strict = args.strict;
             frame.dblclick(frame_args).await
         } else {
             Err(Arc::new(crate::Error::ObjectNotFound))
@@ -89,41 +168,25 @@ impl Locator {
 
     pub(crate) async fn fill(&self, value: &str, args: FillArgs) -> Result<(), Arc<Error>> {
         if let Some(frame) = self.frame.upgrade() {
-            // SPECIAL HANDLING: Check if this is a complex selector nth-index marker
-            if self.selector.starts_with("(") && self.selector.contains(")>>>nth-index-") {
-                // Parse the complex selector: "(input, select, textarea)>>>nth-index-7"
-                if let Some(close_paren) = self.selector.find(")>>>nth-index-") {
-                    let base_selector = &self.selector[1..close_paren]; // Remove outer parentheses
-                    let index_part = &self.selector[close_paren + 14..]; // After ")>>>nth-index-"
-                    if let Ok(index) = index_part.parse::<usize>() {
-                        // Use query_selector_all approach to get the specific element via frame
-                        let elements = frame
-                            .query_selector_all(base_selector)
-                            .await
-                            .map_err(Arc::from)?;
-
-                        if let Some(element_weak) = elements.get(index) {
-                            // Fill the specific element
-                            if let Some(element) = element_weak.upgrade() {
-                                let mut element_fill_args =
-                                    crate::imp::element_handle::FillArgs::new(value);
-                                element_fill_args.timeout = args.timeout;
-                                element_fill_args.no_wait_after = args.no_wait_after;
-                                return element.fill(element_fill_args).await.map_err(Arc::from);
-                            }
-                        } else {
-                            // Index out of bounds
-                            return Err(Arc::new(crate::Error::ObjectNotFound));
-                        }
-                    }
-                }
-            }
-
-            // Regular selector handling
             let mut frame_args = crate::imp::frame::FillArgs::new(&self.selector, value);
             frame_args.timeout = args.timeout;
             frame_args.no_wait_after = args.no_wait_after;
-            frame.fill(frame_args).await
+            frame_args.strict = args.strict;
+            match frame.fill(frame_args).await {
+                // the driver waits for the element before refusing anything but a text control
+                Err(e) if is_not_fillable(&e) && self.is_select(&frame).await => {
+                    let select_args = SelectOptionArgs {
+                        values: Some(vec![value.to_owned()]),
+                        force: args.force,
+                        no_wait_after: args.no_wait_after,
+                        timeout: args.timeout,
+                        strict: args.strict,
+                        ..SelectOptionArgs::default()
+                    };
+                    self.select_option(select_args).await.map(|_| ())
+                }
+                r => r,
+            }
         } else {
             Err(Arc::new(crate::Error::ObjectNotFound))
         }
@@ -135,7 +198,10 @@ impl Locator {
             frame_args.position = args.position;
             frame_args.modifiers = args.modifiers;
             frame_args.force = args.force;
+            frame_args.no_wait_after = args.no_wait_after;
             frame_args.timeout = args.timeout;
+            frame_args.trial = args.trial;
+            frame_args.strict = args.strict;
             frame.hover(frame_args).await
         } else {
             Err(Arc::new(crate::Error::ObjectNotFound))
@@ -144,11 +210,16 @@ impl Locator {
 
     pub(crate) async fn check(&self, args: CheckArgs) -> Result<(), Arc<Error>> {
         if let Some(frame) = self.frame.upgrade() {
+            if args.strict == Some(true) {
+                self.ensure_checkbox(&frame).await?;
+            }
             let mut frame_args = crate::imp::frame::CheckArgs::new(&self.selector);
             frame_args.position = args.position;
             frame_args.force = args.force;
             frame_args.no_wait_after = args.no_wait_after;
             frame_args.timeout = args.timeout;
+            frame_args.trial = args.trial;
+            frame_args.strict = args.strict;
             frame.check(frame_args).await
         } else {
             Err(Arc::new(crate::Error::ObjectNotFound))
@@ -157,37 +228,53 @@ impl Locator {
 
     pub(crate) async fn uncheck(&self, args: CheckArgs) -> Result<(), Arc<Error>> {
         if let Some(frame) = self.frame.upgrade() {
+            if args.strict == Some(true) {
+                self.ensure_checkbox(&frame).await?;
+            }
             let mut frame_args = crate::imp::frame::CheckArgs::new(&self.selector);
             frame_args.position = args.position;
             frame_args.force = args.force;
             frame_args.no_wait_after = args.no_wait_after;
             frame_args.timeout = args.timeout;
+            frame_args.trial = args.trial;
+            frame_args.strict = args.strict;
             frame.uncheck(frame_args).await
         } else {
             Err(Arc::new(crate::Error::ObjectNotFound))
         }
     }
 
+    /// Whether the element, which the failed action already waited for, is a `<select>`.
+    async fn is_select(&self, frame: &Frame) -> bool {
+        frame
+            .evaluate_on_selector(&self.selector, "e => e.tagName === 'SELECT'", None::<()>)
+            .await
+            .unwrap_or(false)
+    }
+
+    async fn ensure_checkbox(&self, frame: &Frame) -> ArcResult<()> {
+        const IS_CHECKBOX: &str = "e => (e.tagName === 'INPUT' && e.type === 'checkbox') \
+            || e.getAttribute('role') === 'checkbox'";
+        let is_checkbox: bool = frame
+            .evaluate_on_selector(&self.selector, IS_CHECKBOX, None::<()>)
+            .await?;
+        if is_checkbox {
+            Ok(())
+        } else {
+            Err(Arc::new(Error::ElementNotCheckable {
+                selector: self.selector.clone(),
+            }))
+        }
+    }
+
     pub(crate) async fn press(&self, key: &str, args: PressArgs) -> Result<(), Arc<Error>> {
         if let Some(frame) = self.frame.upgrade() {
-            // Use ElementHandle-based approach via querySelector since Frame's press method signature is unclear
-            let element = frame
-                .query_selector(&self.selector)
-                .await
-                .map_err(Arc::from)?;
-            if let Some(element) = element {
-                if let Some(element) = element.upgrade() {
-                    let mut press_args = crate::imp::element_handle::PressArgs::new(key);
-                    press_args.delay = args.delay;
-                    press_args.timeout = args.timeout;
-                    press_args.no_wait_after = args.no_wait_after;
-                    element.press(press_args).await.map_err(Arc::from)
-                } else {
-                    Err(Arc::new(crate::Error::ObjectNotFound))
-                }
-            } else {
-                Err(Arc::new(crate::Error::ObjectNotFound))
-            }
+            let mut frame_args = crate::imp::frame::PressArgs::new(&self.selector, key);
+            frame_args.delay = args.delay;
+            frame_args.timeout = args.timeout;
+            frame_args.no_wait_after = args.no_wait_after;
+            frame_args.strict = args.strict;
+            frame.press(frame_args).await
         } else {
             Err(Arc::new(crate::Error::ObjectNotFound))
         }
@@ -195,32 +282,6 @@ impl Locator {
 
     pub(crate) async fn set_input_files(&self, args: SetInputFilesArgs) -> Result<(), Arc<Error>> {
         if let Some(frame) = self.frame.upgrade() {
-            // SPECIAL HANDLING: Check if this is a complex selector nth-index marker
-            if self.selector.starts_with("(") && self.selector.contains(")>>>nth-index-") {
-                // Parse the complex selector: "(input, select, textarea)>>>nth-index-4"
-                if let Some(close_paren) = self.selector.find(")>>>nth-index-") {
-                    let base_selector = &self.selector[1..close_paren]; // Remove outer parentheses
-                    let index_part = &self.selector[close_paren + 14..]; // After ")>>>nth-index-"
-                    if let Ok(index) = index_part.parse::<usize>() {
-                        // Use query_selector_all approach to get the specific element via frame
-                        let elements = frame
-                            .query_selector_all(base_selector)
-                            .await
-                            .map_err(Arc::from)?;
-
-                        if let Some(element_weak) = elements.get(index) {
-                            // Set input files on the specific element
-                            if let Some(element) = element_weak.upgrade() {
-                                return element.set_input_files(args).await.map_err(Arc::from);
-                            }
-                        }
-                        // If element not found or index out of bounds, fall through to ObjectNotFound
-                        return Err(Arc::new(crate::Error::ObjectNotFound));
-                    }
-                }
-            }
-
-            // Regular selector handling
             let mut frame_args = crate::imp::frame::SetInputFilesArgs::new(&self.selector);
             frame_args.files = args.files;
             frame_args.timeout = args.timeout;
@@ -240,15 +301,21 @@ impl Locator {
     }
 
     pub(crate) async fn blur(&self, timeout: Option<f64>) -> Result<(), Arc<Error>> {
-        #[skip_serializing_none]
-        #[derive(Serialize)]
-        #[serde(rename_all = "camelCase")]
-        struct Args {
-            timeout: Option<f64>,
+        if self.channel.is_some() {
+            #[skip_serializing_none]
+            #[derive(Serialize)]
+            #[serde(rename_all = "camelCase")]
+            struct Args {
+                timeout: Option<f64>,
+            }
+            let args = Args { timeout };
+            let _ = send_message!(self, "blur", args);
+            Ok(())
+        } else if let Some(frame) = self.frame.upgrade() {
+            frame.blur(&self.selector, timeout).await
+        } else {
+            Err(Arc::new(crate::Error::ObjectNotFound))
         }
-        let args = Args { timeout };
-        let _ = send_message!(self, "blur", args);
-        Ok(())
     }
 
     pub(crate) async fn clear(&self, args: ClearArgs) -> Result<(), Arc<Error>> {
@@ -266,6 +333,7 @@ impl Locator {
                 force: args.force,
                 no_wait_after: args.no_wait_after,
                 timeout: args.timeout,
+                strict: None,
             };
             self.fill("", fill_args).await
         }
@@ -273,24 +341,12 @@ impl Locator {
 
     pub(crate) async fn r#type(&self, text: &str, args: TypeArgs) -> Result<(), Arc<Error>> {
         if let Some(frame) = self.frame.upgrade() {
-            // Use ElementHandle-based approach via querySelector
-            let element = frame
-                .query_selector(&self.selector)
-                .await
-                .map_err(Arc::from)?;
-            if let Some(element) = element {
-                if let Some(element) = element.upgrade() {
-                    let mut type_args = crate::imp::element_handle::TypeArgs::new(text);
-                    type_args.delay = args.delay;
-                    type_args.timeout = args.timeout;
-                    type_args.no_wait_after = args.no_wait_after;
-                    element.r#type(type_args).await.map_err(Arc::from)
-                } else {
-                    Err(Arc::new(crate::Error::ObjectNotFound))
-                }
-            } else {
-                Err(Arc::new(crate::Error::ObjectNotFound))
-            }
+            let mut frame_args = crate::imp::frame::TypeArgs::new(&self.selector, text);
+            frame_args.delay = args.delay;
+            frame_args.timeout = args.timeout;
+            frame_args.no_wait_after = args.no_wait_after;
+            frame_args.strict = args.strict;
+            frame.r#type(frame_args).await
         } else {
             Err(Arc::new(crate::Error::ObjectNotFound))
         }
@@ -326,13 +382,30 @@ impl Locator {
                         .map(|i| crate::imp::element_handle::Opt::Index(i as usize)),
                 );
             }
+            if args.all {
+                let values: Vec<String> = frame
+                    .evaluate_on_selector(
+                        &self.selector,
+                        "e => [...e.options].map(o => o.value)",
+                        None::<()>,
+                    )
+                    .await?;
+                options.extend(
+                    values
+                        .into_iter()
+                        .map(crate::imp::element_handle::Opt::Value),
+                );
+            }
 
             if !options.is_empty() {
                 frame_args.options = Some(options);
             }
+            frame_args.elements = args.element_handles;
 
             frame_args.timeout = args.timeout;
             frame_args.no_wait_after = args.no_wait_after;
+            frame_args.force = args.force;
+            frame_args.strict = args.strict;
             frame.select_option(frame_args).await.map_err(Arc::from)
         } else {
             Err(Arc::new(crate::Error::ObjectNotFound))
@@ -343,73 +416,27 @@ impl Locator {
     pub(crate) async fn text_content(
         &self,
         timeout: Option<f64>,
+        xpath_fallback: XPathFallbackMode,
     ) -> Result<Option<String>, Arc<Error>> {
         if let Some(frame) = self.frame.upgrade() {
             // SPECIAL HANDLING: Check for problematic XPath patterns that cause hanging
             if self.selector.starts_with("xpath=") && self.is_complex_xpath() {
-                // Convert complex XPath to JavaScript evaluation to avoid driver hanging
-                return self
-                    .handle_complex_xpath_text_content(&frame, timeout)
-                    .await;
-            }
-
-            // SPECIAL HANDLING: Check if this is a complex selector nth-index marker
-            if self.selector.starts_with("(") && self.selector.contains(")>>>nth-index-") {
-                // Parse the complex selector: "(input, select, textarea)>>>nth-index-4"
-                if let Some(close_paren) = self.selector.find(")>>>nth-index-") {
-                    let base_selector = &self.selector[1..close_paren]; // Remove outer parentheses
-                    let index_part = &self.selector[close_paren + 14..]; // After ")>>>nth-index-"
-                    if let Ok(index) = index_part.parse::<usize>() {
-                        // Use query_selector_all approach to get the specific element via frame
-                        let elements = frame
-                            .query_selector_all(base_selector)
-                            .await
-                            .map_err(Arc::from)?;
-
-                        if let Some(element_weak) = elements.get(index) {
-                            // Get text content from the specific element
-                            if let Some(element) = element_weak.upgrade() {
-                                return element.text_content().await.map_err(Arc::from);
-                            }
-                        }
-                        // If element not found or index out of bounds, return None
-                        return Ok(None);
+                match xpath_fallback {
+                    XPathFallbackMode::Native => {}
+                    // Convert complex XPath to JavaScript evaluation to avoid driver hanging
+                    XPathFallbackMode::JavaScriptFallback => {
+                        return self
+                            .handle_complex_xpath_text_content(&frame, timeout)
+                            .await;
                     }
-                }
-            }
-
-            // SPECIAL HANDLING: Check if this is a simple nth-of-type selector created by nth() method
-            if self.selector.contains(":nth-of-type(") {
-                // Parse selectors like "label:nth-of-type(2)" created by nth() method
-                if let Some(nth_pos) = self.selector.find(":nth-of-type(") {
-                    let base_selector = &self.selector[..nth_pos]; // e.g., "label"
-                    let nth_part = &self.selector[nth_pos + 13..]; // After ":nth-of-type("
-                    if let Some(close_paren) = nth_part.find(')') {
-                        let index_str = &nth_part[..close_paren];
-                        if let Ok(css_index) = index_str.parse::<usize>() {
-                            // CSS nth-of-type is 1-based, convert to 0-based for array indexing
-                            let array_index = css_index.saturating_sub(1);
-
-                            // Use query_selector_all to get all elements, then select by index
-                            let elements = frame
-                                .query_selector_all(base_selector)
-                                .await
-                                .map_err(Arc::from)?;
-
-                            if let Some(element_weak) = elements.get(array_index) {
-                                // Get text content from the specific element
-                                if let Some(element) = element_weak.upgrade() {
-                                    return element.text_content().await.map_err(Arc::from);
-                                }
-                            }
-                            // If element not found or index out of bounds, return None
-                            return Ok(None);
-                        }
+                    XPathFallbackMode::Error => {
+                        return Err(Arc::new(Error::XPathFallback {
+                            selector: self.selector.clone(),
+                        }));
                     }
                 }
             }
 
-            // Regular selector handling
             frame
                 .text_content(&self.selector, timeout)
                 .await
@@ -420,12 +447,7 @@ impl Locator {
     }
 
     fn is_complex_xpath(&self) -> bool {
-        // Detect XPath patterns that are known to cause hanging in the driver
-        self.selector.contains("|") || // Union operators like "input | select | textarea"
-        self.selector.contains("ancestor::") || // Ancestor traversal
-        self.selector.contains("descendant::") || // Descendant traversal  
-        self.selector.contains("following::") || // Following sibling traversal
-        self.selector.contains("preceding::") // Preceding sibling traversal
+        is_complex_xpath(&self.selector)
     }
 
     async fn handle_complex_xpath_text_content(
@@ -514,33 +536,6 @@ impl Locator {
         timeout: Option<f64>,
     ) -> Result<Option<String>, Arc<Error>> {
         if let Some(frame) = self.frame.upgrade() {
-            // SPECIAL HANDLING: Check if this is a complex selector nth-index marker
-            if self.selector.starts_with("(") && self.selector.contains(")>>>nth-index-") {
-                // Parse the complex selector: "(input, select, textarea)>>>nth-index-7"
-                if let Some(close_paren) = self.selector.find(")>>>nth-index-") {
-                    let base_selector = &self.selector[1..close_paren]; // Remove outer parentheses
-                    let index_part = &self.selector[close_paren + 14..]; // After ")>>>nth-index-"
-                    if let Ok(index) = index_part.parse::<usize>() {
-                        // Use query_selector_all approach to get the specific element via frame
-                        let elements = frame
-                            .query_selector_all(base_selector)
-                            .await
-                            .map_err(Arc::from)?;
-
-                        if let Some(element_weak) = elements.get(index) {
-                            // Get attribute from the specific element
-                            if let Some(element) = element_weak.upgrade() {
-                                return element.get_attribute(name).await.map_err(Arc::from);
-                            }
-                        } else {
-                            // Index out of bounds
-                            return Ok(None);
-                        }
-                    }
-                }
-            }
-
-            // Regular selector handling
             frame
                 .get_attribute(&self.selector, name, timeout)
                 .await
@@ -551,47 +546,8 @@ impl Locator {
     }
 
     pub(crate) async fn input_value(&self, timeout: Option<f64>) -> Result<String, Arc<Error>> {
-        // Check if this is a client-side locator (like get_attribute does)
-        if let Some(frame) = self.frame.upgrade() {
-            // SPECIAL HANDLING: Check if this is a complex selector nth-index marker
-            if self.selector.starts_with("(") && self.selector.contains(")>>>nth-index-") {
-                // Parse the complex selector: "(input, select, textarea)>>>nth-index-7"
-                if let Some(close_paren) = self.selector.find(")>>>nth-index-") {
-                    let base_selector = &self.selector[1..close_paren]; // Remove outer parentheses
-                    let index_part = &self.selector[close_paren + 14..]; // After ")>>>nth-index-"
-                    if let Ok(index) = index_part.parse::<usize>() {
-                        // Get input value from the specific element using JavaScript evaluation
-                        let js_code = format!(
-                            "(() => {{
-                                const elements = document.querySelectorAll('{}');
-                                const element = elements[{}];
-                                return element ? (element.value || '') : '';
-                            }})()",
-                            base_selector.replace("'", "\\'"),
-                            index
-                        );
-                        return frame
-                            .evaluate::<(), String>(&js_code, None)
-                            .await
-                            .map_err(Arc::from);
-                    }
-                }
-            }
-
-            // Use frame.evaluate with JavaScript to get input value
-            let js_code = format!(
-                "(() => {{
-                    const element = document.querySelector('{}');
-                    return element ? (element.value || '') : '';
-                }})()",
-                self.selector.replace("'", "\\'")
-            );
-            frame
-                .evaluate::<(), String>(&js_code, None)
-                .await
-                .map_err(Arc::from)
-        } else {
-            // Fallback for server-side locators (existing implementation)
+        if self.channel.is_some() {
+            // Server-side locator: use protocol message
             #[skip_serializing_none]
             #[derive(Serialize)]
             #[serde(rename_all = "camelCase")]
@@ -601,10 +557,32 @@ impl Locator {
             let args = Args { timeout };
             let v = send_message!(self, "inputValue", args);
             let value = only_str(&v)?;
-            Ok(value.to_owned())
+            return Ok(value.to_owned());
+        }
+        if let Some(frame) = self.frame.upgrade() {
+            frame.input_value(&self.selector, timeout).await
+        } else {
+            Err(Arc::new(crate::Error::ObjectNotFound))
         }
     }
 
+    /// Values of all selected options of a `<select>`, or the single value of an `<input>` or `<textarea>`
+    pub(crate) async fn input_values(&self, timeout: Option<f64>) -> ArcResult<Vec<String>> {
+        const INPUT_VALUES: &str = "e => { \
+            if (e.tagName === 'SELECT') return [...e.selectedOptions].map(o => o.value); \
+            if (e.tagName === 'INPUT' || e.tagName === 'TEXTAREA') return [e.value]; \
+            throw new Error('Not an <input>, <textarea> or <select> element'); }";
+        let frame = upgrade(&self.frame)?;
+        let mut args = WaitForSelectorArgs::new(&self.selector);
+        args.timeout = timeout;
+        args.state = Some(FrameState::Attached);
+        args.strict = Some(true);
+        frame.wait_for_selector(args).await?;
+        frame
+            .evaluate_on_selector(&self.selector, INPUT_VALUES, None::<()>)
+            .await
+    }
+
     pub(crate) async fn count(&self) -> Result<usize, Arc<Error>> {
         // Handle both server-side and client-side locators
         if self.channel.is_some() {
@@ -615,6 +593,12 @@ impl Locator {
         } else {
             // Client-side locator: delegate to frame
             if let Some(frame) = self.frame.upgrade() {
+                // queryCount avoids creating a handle per element; older drivers lack it
+                match frame.query_count(&self.selector).await {
+                    Ok(count) => return Ok(count),
+                    Err(e) if e.is_unknown_method() => {}
+                    Err(e) => return Err(e),
+                }
                 let elements = frame
                     .query_selector_all(&self.selector)
                     .await
@@ -694,21 +678,25 @@ impl Locator {
     }
 
     // Chaining methods
-    pub(crate) async fn first(&self) -> Result<Weak<Locator>, Arc<Error>> {
-        let v = send_message!(self, "first", Map::new());
-        let guid = only_guid(&v)?;
-        let locator = get_object!(self.context()?.lock().unwrap(), guid, Locator)?;
-        Ok(locator)
+    pub(crate) async fn first(&self) -> Result<Arc<Locator>, Arc<Error>> {
+        if self.channel.is_some() {
+            let v = send_message!(self, "first", Map::new());
+            self.server_side_locator(&v)
+        } else {
+            Ok(self.child("nth=0"))
+        }
     }
 
-    pub(crate) async fn last(&self) -> Result<Weak<Locator>, Arc<Error>> {
-        let v = send_message!(self, "last", Map::new());
-        let guid = only_guid(&v)?;
-        let locator = get_object!(self.context()?.lock().unwrap(), guid, Locator)?;
-        Ok(locator)
+    pub(crate) async fn last(&self) -> Result<Arc<Locator>, Arc<Error>> {
+        if self.channel.is_some() {
+            let v = send_message!(self, "last", Map::new());
+            self.server_side_locator(&v)
+        } else {
+            Ok(self.child("nth=-1"))
+        }
     }
 
-    pub(crate) async fn nth(&self, index: i32) -> Result<Weak<Locator>, Arc<Error>> {
+    pub(crate) async fn nth(&self, index: i32) -> Result<Arc<Locator>, Arc<Error>> {
         // Handle both server-side and client-side locators
         if self.channel.is_some() {
             // Server-side locator: use protocol message
@@ -719,53 +707,30 @@ impl Locator {
             }
             let args = Args { index };
             let v = send_message!(self, "nth", args);
-            let guid = only_guid(&v)?;
-            let locator = get_object!(self.context()?.lock().unwrap(), guid, Locator)?;
-            Ok(locator)
+            self.server_side_locator(&v)
         } else {
-            // Client-side locator: create new locator with nth selector
-            // CRITICAL: Use self.frame directly instead of self.frame.upgrade()
-            // This preserves the same frame reference as the parent locator
-
-            // SPECIAL HANDLING FOR COMPLEX SELECTORS (contains comma)
-            if self.selector.contains(',') {
-                // Complex selectors like "input, select, textarea" cannot use CSS :nth-of-type()
-                // because it creates invalid selectors like "input, select, textarea:nth-of-type(8)"
-                // which selects ALL inputs + ALL selects + 8th textarea (causing homogenized results)
-                //
-                // SOLUTION: Create a unique selector for the specific element at this index
-                // We'll delegate to get_attribute() to use querySelector approach
-                let unique_selector = format!("({})>>>nth-index-{}", self.selector, index);
-                let locator = Locator::new_client_side(self.frame.clone(), unique_selector);
-                let locator_arc = Arc::new(locator);
-                let locator_weak = Arc::downgrade(&locator_arc);
-
-                // Keep the locator alive (same pattern as frame.locator())
-                std::mem::forget(locator_arc.clone());
-
-                Ok(locator_weak)
-            } else {
-                // Simple selectors can use CSS nth-of-type safely
-                // Use CSS nth-of-type instead of nth engine for compatibility with older drivers
-                // CSS nth is 1-based, so add 1 to the 0-based index
-                let nth_selector = format!("{}:nth-of-type({})", self.selector, index + 1);
-                let locator = Locator::new_client_side(self.frame.clone(), nth_selector);
-                let locator_arc = Arc::new(locator);
-                let locator_weak = Arc::downgrade(&locator_arc);
-
-                // Keep the locator alive (same pattern as frame.locator())
-                std::mem::forget(locator_arc.clone());
-
-                Ok(locator_weak)
-            }
+            // Client-side locator: the nth engine indexes across all matches, whatever their tag
+            Ok(self.child(&format!("nth={}", index)))
+        }
+    }
+
+    pub(crate) async fn filter(&self, options: FilterOptions) -> Result<Arc<Locator>, Arc<Error>> {
+        let options = options.resolve(&self.frame).map_err(Arc::new)?;
+        if self.channel.is_some() {
+            let v = send_message!(self, "filter", options);
+            self.server_side_locator(&v)
+        } else {
+            Ok(Self::client_side(
+                self.frame.clone(),
+                options.selector(&self.selector),
+            ))
         }
     }
 
-    pub(crate) async fn filter(&self, options: FilterOptions) -> Result<Weak<Locator>, Arc<Error>> {
-        let v = send_message!(self, "filter", options);
-        let guid = only_guid(&v)?;
+    fn server_side_locator(&self, v: &Value) -> Result<Arc<Locator>, Arc<Error>> {
+        let guid = only_guid(v)?;
         let locator = get_object!(self.context()?.lock().unwrap(), guid, Locator)?;
-        Ok(locator)
+        Ok(upgrade(&locator)?)
     }
 }
 
@@ -784,6 +749,92 @@ impl RemoteObject for Locator {
     }
 }
 
+/// How [`Locator::text_content`](crate::api::Locator::text_content) evaluates XPath selectors that are known to hang
+/// the driver, see `is_complex_xpath`. Set per locator with
+/// [`Locator::with_xpath_fallback`](crate::api::Locator::with_xpath_fallback), otherwise the [`Default`] applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XPathFallbackMode {
+    /// Let the driver evaluate the selector anyway.
+    Native,
+    /// Evaluate the XPath with `document.evaluate` in the main frame document. Unlike the driver it neither waits for
+    /// the element nor pierces shadow roots.
+    JavaScriptFallback,
+    /// Fail with [`Error::XPathFallback`].
+    Error,
+}
+
+impl XPathFallbackMode {
+    pub const ENV: &'static str = "PLAYWRIGHT_XPATH_FALLBACK";
+
+    /// Reads [`XPathFallbackMode::ENV`], one of `native`, `javascript` or `error`, defaulting to
+    /// [`XPathFallbackMode::JavaScriptFallback`] when it is unset or unknown.
+    pub fn from_env() -> Self {
+        Self::from_var(std::env::var(Self::ENV).ok().as_deref())
+    }
+
+    fn from_var(var: Option<&str>) -> Self {
+        var.and_then(|s| s.parse().ok())
+            .unwrap_or(Self::JavaScriptFallback)
+    }
+}
+
+impl Default for XPathFallbackMode {
+    /// [`XPathFallbackMode::from_env`], read on first use
+    fn default() -> Self {
+        static DEFAULT: OnceLock<XPathFallbackMode> = OnceLock::new();
+        *DEFAULT.get_or_init(Self::from_env)
+    }
+}
+
+impl FromStr for XPathFallbackMode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "native" => Ok(Self::Native),
+            "javascript" | "javascriptfallback" => Ok(Self::JavaScriptFallback),
+            "error" => Ok(Self::Error),
+            _ => Err(Error::InvalidArgument {
+                message: format!("Unknown XPath fallback mode: {:?}", s),
+            }),
+        }
+    }
+}
+
+/// The driver's refusal to fill an element that is not an `<input>`, a `<textarea>` or editable content
+fn is_not_fillable(e: &Error) -> bool {
+    match e {
+        Error::ProtocolError { message, .. } => message.contains("is not an <input>"),
+        Error::Arc(e) => is_not_fillable(e),
+        _ => false,
+    }
+}
+
+/// Detects XPath patterns that are known to cause hanging in the driver: unions inside a predicate, unions of more
+/// than two clauses, and traversals along the ancestor, descendant, following or preceding axes.
+fn is_complex_xpath(xpath: &str) -> bool {
+    const AXES: [&str; 4] = ["ancestor::", "descendant::", "following::", "preceding::"];
+    if AXES.iter().any(|a| xpath.contains(a)) {
+        return true;
+    }
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut top_level_unions = 0;
+    for c in xpath.chars() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '[') => depth += 1,
+            (None, ']') => depth = depth.saturating_sub(1),
+            (None, '|') if depth > 0 => return true,
+            (None, '|') => top_level_unions += 1,
+            _ => {}
+        }
+    }
+    top_level_unions > 1
+}
+
 // Helper types for locator operations
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -804,6 +855,8 @@ pub(crate) struct ClickArgs {
     pub(crate) force: Option<bool>,
     pub(crate) no_wait_after: Option<bool>,
     pub(crate) timeout: Option<f64>,
+    pub(crate) trial: Option<bool>,
+    pub(crate) strict: Option<bool>,
 }
 
 #[skip_serializing_none]
@@ -813,6 +866,7 @@ pub(crate) struct FillArgs {
     pub(crate) force: Option<bool>,
     pub(crate) no_wait_after: Option<bool>,
     pub(crate) timeout: Option<f64>,
+    pub(crate) strict: Option<bool>,
 }
 
 #[skip_serializing_none]
@@ -822,7 +876,10 @@ pub(crate) struct HoverArgs {
     pub(crate) position: Option<Position>,
     pub(crate) modifiers: Option<Vec<KeyboardModifier>>,
     pub(crate) force: Option<bool>,
+    pub(crate) no_wait_after: Option<bool>,
     pub(crate) timeout: Option<f64>,
+    pub(crate) trial: Option<bool>,
+    pub(crate) strict: Option<bool>,
 }
 
 #[skip_serializing_none]
@@ -833,6 +890,9 @@ pub(crate) struct CheckArgs {
     pub(crate) force: Option<bool>,
     pub(crate) no_wait_after: Option<bool>,
     pub(crate) timeout: Option<f64>,
+    pub(crate) trial: Option<bool>,
+    /// Also requires the element to be a checkbox
+    pub(crate) strict: Option<bool>,
 }
 
 #[skip_serializing_none]
@@ -842,6 +902,7 @@ pub(crate) struct PressArgs {
     pub(crate) delay: Option<f64>,
     pub(crate) no_wait_after: Option<bool>,
     pub(crate) timeout: Option<f64>,
+    pub(crate) strict: Option<bool>,
 }
 
 #[skip_serializing_none]
@@ -852,6 +913,74 @@ pub(crate) struct FilterOptions {
     pub(crate) has_not_text: Option<String>,
     pub(crate) has: Option<String>,
     pub(crate) has_not: Option<String>,
+    /// Folded into `has` by [`FilterOptions::resolve`]
+    #[serde(skip)]
+    pub(crate) has_locator: Option<Arc<Locator>>,
+    /// Folded into `has_not` by [`FilterOptions::resolve`]
+    #[serde(skip)]
+    pub(crate) has_not_locator: Option<Arc<Locator>>,
+}
+
+impl FilterOptions {
+    /// Replaces the locators by their selectors. They must belong to the `frame` of the filtered locator and can't be
+    /// combined with a `has` or `has_not` selector.
+    fn resolve(mut self, frame: &Weak<Frame>) -> Result<Self, Error> {
+        fn exclusive() -> Error {
+            Error::InvalidArgument {
+                message: "has and has_locator (or has_not and has_not_locator) can't be combined"
+                    .into(),
+            }
+        }
+        if let Some(l) = self.has_locator.take() {
+            if self.has.is_some() {
+                return Err(exclusive());
+            }
+            self.has = Some(l.inner_selector(frame)?);
+        }
+        if let Some(l) = self.has_not_locator.take() {
+            if self.has_not.is_some() {
+                return Err(exclusive());
+            }
+            self.has_not = Some(l.inner_selector(frame)?);
+        }
+        Ok(self)
+    }
+
+    /// Appends the filters to `selector` the way the driver does for server-side locators.
+    fn selector(&self, selector: &str) -> String {
+        append_filters(
+            selector,
+            self.has_text.as_deref(),
+            self.has_not_text.as_deref(),
+            self.has.as_deref(),
+            self.has_not.as_deref(),
+        )
+    }
+}
+
+/// Appends the `has_text`, `has_not_text`, `has` and `has_not` filters to `selector`, in the order the driver applies
+/// them. `has` and `has_not` are selectors of inner locators.
+pub(crate) fn append_filters(
+    selector: &str,
+    has_text: Option<&str>,
+    has_not_text: Option<&str>,
+    has: Option<&str>,
+    has_not: Option<&str>,
+) -> String {
+    let mut selector = selector.to_owned();
+    if let Some(text) = has_text {
+        selector = format!("{} >> {}", selector, build_has_text_selector(text, false));
+    }
+    if let Some(text) = has_not_text {
+        selector = format!("{} >> {}", selector, build_has_text_selector(text, true));
+    }
+    if let Some(inner) = has {
+        selector = format!("{} >> {}", selector, build_has_selector(inner, false));
+    }
+    if let Some(inner) = has_not {
+        selector = format!("{} >> {}", selector, build_has_selector(inner, true));
+    }
+    selector
 }
 
 #[skip_serializing_none]
@@ -870,6 +999,7 @@ pub(crate) struct TypeArgs {
     pub(crate) delay: Option<f64>,
     pub(crate) no_wait_after: Option<bool>,
     pub(crate) timeout: Option<f64>,
+    pub(crate) strict: Option<bool>,
 }
 
 #[skip_serializing_none]
@@ -879,7 +1009,86 @@ pub(crate) struct SelectOptionArgs {
     pub(crate) values: Option<Vec<String>>,
     pub(crate) labels: Option<Vec<String>>,
     pub(crate) indices: Option<Vec<i32>>,
+    pub(crate) element_handles: Option<Vec<OnlyGuid>>,
     pub(crate) force: Option<bool>,
     pub(crate) no_wait_after: Option<bool>,
     pub(crate) timeout: Option<f64>,
+    pub(crate) strict: Option<bool>,
+    /// Adds the values of every `<option>` at the time of the call
+    #[serde(skip)]
+    pub(crate) all: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn client_side_locators_are_freed() {
+        let root = Locator::client_side(Weak::new(), "li".into());
+        let children = (0..1000)
+            .map(|i| Arc::downgrade(&root.child(&format!("nth={}", i))))
+            .collect::<Vec<_>>();
+        assert!(children.iter().all(|c| c.upgrade().is_none()));
+        let root_weak = Arc::downgrade(&root);
+        drop(root);
+        assert!(root_weak.upgrade().is_none());
+    }
+
+    #[test]
+    fn simple_unions_are_not_complex() {
+        assert!(!is_complex_xpath("xpath=//input"));
+        assert!(!is_complex_xpath("xpath=//input | //select"));
+        assert!(!is_complex_xpath("xpath=//a[@title='a | b | c']"));
+        assert!(!is_complex_xpath(r#"xpath=//a[text()="x|y"] | //b"#));
+    }
+
+    #[test]
+    fn predicate_unions_are_complex() {
+        assert!(is_complex_xpath("xpath=//form[.//input | .//select]"));
+        assert!(is_complex_xpath("xpath=//div[@id='a']/p[b | i]"));
+    }
+
+    #[test]
+    fn long_unions_are_complex() {
+        assert!(is_complex_xpath("xpath=//input | //select | //textarea"));
+    }
+
+    #[test]
+    fn xpath_fallback_modes_are_parsed() {
+        assert_eq!("Error".parse().ok(), Some(XPathFallbackMode::Error));
+        assert_eq!("native".parse().ok(), Some(XPathFallbackMode::Native));
+        assert_eq!(
+            "JavaScriptFallback".parse().ok(),
+            Some(XPathFallbackMode::JavaScriptFallback)
+        );
+        assert!(matches!(
+            "js".parse::<XPathFallbackMode>(),
+            Err(Error::InvalidArgument { .. })
+        ));
+    }
+
+    #[test]
+    fn xpath_fallback_mode_is_read_from_the_variable() {
+        assert_eq!(
+            XPathFallbackMode::from_var(Some("error")),
+            XPathFallbackMode::Error
+        );
+        assert_eq!(
+            XPathFallbackMode::from_var(Some("js")),
+            XPathFallbackMode::JavaScriptFallback
+        );
+        assert_eq!(
+            XPathFallbackMode::from_var(None),
+            XPathFallbackMode::JavaScriptFallback
+        );
+    }
+
+    #[test]
+    fn axes_are_complex() {
+        assert!(is_complex_xpath("xpath=//td/ancestor::tr"));
+        assert!(is_complex_xpath("xpath=//table/descendant::td"));
+        assert!(is_complex_xpath("xpath=//h1/following::p"));
+        assert!(is_complex_xpath("xpath=//p/preceding::h1"));
+    }
 }
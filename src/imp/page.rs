@@ -1,23 +1,30 @@
 use crate::imp::{
-    browser_context::BrowserContext,
+    browser_context::{BrowserContext, RouteFromHarOptions},
     console_message::ConsoleMessage,
     core::*,
+    dialog::Dialog,
     download::Download,
     element_handle::may_save,
-    file_hooser::FileChooser,
+    file_chooser::FileChooser,
     frame::Frame,
+    png,
     prelude::*,
     request::Request,
     response::Response,
+    route::{InterceptionPatterns, Route, RouteHandler, RouteHandlerFn},
     utils::{
         ColorScheme, DocumentLoadState, FloatRect, Header, Length, MouseButton, PdfMargins,
-        ScreenshotType, Viewport,
+        ScreenshotAnimations, ScreenshotType, UpdateSubscriptionArgs, Viewport,
     },
     video::Video,
     websocket::WebSocket,
     worker::Worker,
 };
 use base64::Engine;
+use std::{
+    collections::HashSet,
+    sync::atomic::{AtomicBool, Ordering},
+};
 
 #[derive(Debug)]
 pub(crate) struct Page {
@@ -26,16 +33,21 @@ pub(crate) struct Page {
     browser_context: Weak<BrowserContext>,
     var: Mutex<Variable>,
     tx: Mutex<Option<broadcast::Sender<Evt>>>,
+    is_closed: AtomicBool,
+    is_crashed: AtomicBool,
 }
 
 #[derive(Debug, Default)]
 pub(crate) struct Variable {
     viewport: Option<Viewport>,
     frames: Vec<Weak<Frame>>,
-    timeout: Option<u32>,
-    navigation_timeout: Option<u32>,
+    timeout: Option<f64>,
+    navigation_timeout: Option<f64>,
     workers: Vec<Weak<Worker>>,
     video: Option<Video>,
+    routes: Vec<RouteHandler>,
+    subscriptions: HashSet<&'static str>,
+    owned_context: Option<Weak<BrowserContext>>,
 }
 
 macro_rules! navigation {
@@ -80,7 +92,7 @@ macro_rules! mouse_down {
 }
 
 impl Page {
-    const DEFAULT_TIMEOUT: u32 = 30000;
+    const DEFAULT_TIMEOUT: f64 = 30000.;
 
     pub(crate) fn try_new(ctx: &Context, channel: ChannelOwner) -> Result<Self, Error> {
         let Initializer {
@@ -103,6 +115,8 @@ impl Page {
             browser_context,
             var,
             tx: Mutex::default(),
+            is_closed: AtomicBool::new(false),
+            is_crashed: AtomicBool::new(false),
         })
     }
 
@@ -111,10 +125,28 @@ impl Page {
         Ok(())
     }
 
+    pub(crate) fn is_closed(&self) -> bool {
+        self.is_closed.load(Ordering::SeqCst)
+    }
+
+    pub(crate) fn is_crashed(&self) -> bool {
+        self.is_crashed.load(Ordering::SeqCst)
+    }
+
     pub(crate) fn browser_context(&self) -> Weak<BrowserContext> {
         self.browser_context.clone()
     }
 
+    /// Pages whose browser is unknown are assumed to be Chromium and left for the driver to reject.
+    fn is_chromium(&self) -> bool {
+        self.browser_context
+            .upgrade()
+            .and_then(|c| c.browser())
+            .and_then(|b| b.upgrade())
+            .map(|b| b.name() == "chromium")
+            .unwrap_or(true)
+    }
+
     pub(crate) fn main_frame(&self) -> Weak<Frame> {
         self.main_frame.clone()
     }
@@ -220,15 +252,29 @@ impl Page {
     pub(crate) async fn accessibility_snapshot(
         &self,
         args: AccessibilitySnapshotArgs,
-    ) -> ArcResult<Option<AccessibilitySnapshotResponse>> {
+    ) -> ArcResult<Option<AXNode>> {
         let v = send_message!(self, "accessibilitySnapshot", args);
         let first = match first(&v) {
             None => return Ok(None),
             Some(x) => x,
         };
-        let res: AccessibilitySnapshotResponse =
-            serde_json::from_value((*first).clone()).map_err(Error::Serde)?;
-        Ok(Some(res))
+        let res: AXNodeWire = serde_json::from_value((*first).clone()).map_err(Error::Serde)?;
+        Ok(Some(res.into()))
+    }
+
+    /// The driver dispatches some events like `"fileChooser"` only to subscribed clients.
+    pub(crate) async fn subscribe(&self, event: &'static str) -> ArcResult<()> {
+        if self.var.lock().unwrap().subscriptions.contains(event) {
+            return Ok(());
+        }
+        let args = UpdateSubscriptionArgs {
+            event,
+            enabled: true,
+        };
+        let _ = send_message!(self, "updateSubscription", args);
+        // only once the driver has registered it, so a failed attempt is retried by the next call
+        self.var.lock().unwrap().subscriptions.insert(event);
+        Ok(())
     }
 
     pub(crate) async fn bring_to_front(&self) -> ArcResult<()> {
@@ -245,14 +291,32 @@ impl Page {
 
     pub(crate) async fn pdf(
         &self,
-        args: PdfArgs<'_, '_, '_, '_, '_, '_, '_, '_, '_, '_>,
+        args: PdfArgs<'_, '_, '_, '_, '_, '_, '_, '_, '_>,
     ) -> ArcResult<Vec<u8>> {
+        let mut args = args;
+        // explicit width and height override the size of the format, unlike in the driver
+        if args.width.is_some() || args.height.is_some() {
+            if let Some(format) = args.format.take() {
+                let (width, height) = format.size();
+                args.width = args.width.or_else(|| Some(width.into()));
+                args.height = args.height.or_else(|| Some(height.into()));
+            }
+        }
         let path = args.path.clone();
+        let pdf_a = args.pdf_a == Some(true);
+        if pdf_a && !self.is_chromium() {
+            return Err(Arc::new(Error::NotSupported {
+                feature: "PDF/A".into(),
+            }));
+        }
         let v = send_message!(self, "pdf", args);
         let b64 = only_str(&v)?;
-        let bytes = base64::engine::general_purpose::STANDARD
+        let mut bytes = base64::engine::general_purpose::STANDARD
             .decode(b64)
             .map_err(Error::InvalidBase64)?;
+        if pdf_a {
+            bytes = crate::imp::pdf_a::convert(bytes)?;
+        }
         may_save(path.as_deref(), &bytes)?;
         Ok(bytes)
     }
@@ -266,20 +330,70 @@ impl Page {
         }
         let args = Args { run_before_unload };
         let _ = send_message!(self, "close", args);
+        let owned_context = self.var.lock().unwrap().owned_context.take();
+        if let Some(c) = owned_context.and_then(|c| c.upgrade()) {
+            c.close(Default::default()).await?;
+        }
         Ok(())
     }
 
     pub(crate) async fn screenshot(&self, args: ScreenshotArgs) -> ArcResult<Vec<u8>> {
         let path = args.path.clone();
+        let stitch = args.full_page == Some(true)
+            && args.clip.is_none()
+            && args.r#type != Some(ScreenshotType::Jpeg);
+        let bytes = match args.full_page_max_height {
+            Some(max) if stitch => self.stitched_screenshot(args, max).await?,
+            _ => self.capture(&args).await?,
+        };
+        may_save(path.as_deref(), &bytes)?;
+        Ok(bytes)
+    }
+
+    async fn capture(&self, args: &ScreenshotArgs) -> ArcResult<Vec<u8>> {
         let v = send_message!(self, "screenshot", args);
         let b64 = only_str(&v)?;
         let bytes = base64::engine::general_purpose::STANDARD
             .decode(b64)
             .map_err(Error::InvalidBase64)?;
-        may_save(path.as_deref(), &bytes)?;
         Ok(bytes)
     }
 
+    /// Captures a page taller than `max` CSS pixels in slices of one viewport height that span the full scroll width,
+    /// and joins the slices.
+    async fn stitched_screenshot(&self, mut args: ScreenshotArgs, max: u32) -> ArcResult<Vec<u8>> {
+        let frame = upgrade(&self.main_frame)?;
+        let (width, height, viewport): (f64, f64, f64) = frame
+            .eval(
+                "() => [Math.max(document.body.scrollWidth, document.documentElement.scrollWidth), \
+                 Math.max(document.body.scrollHeight, document.documentElement.scrollHeight), \
+                 window.innerHeight]",
+            )
+            .await?;
+        if height <= max as f64 || viewport <= 0. {
+            return self.capture(&args).await;
+        }
+        args.path = None;
+        let mut stitched: Option<png::Image> = None;
+        let mut y = 0.;
+        while y < height {
+            // with full_page the clip is relative to the document, so nothing has to be scrolled
+            args.clip = Some(FloatRect {
+                x: 0.,
+                y,
+                width,
+                height: viewport.min(height - y),
+            });
+            let chunk = png::decode(&self.capture(&args).await?)?;
+            stitched
+                .get_or_insert_with(|| png::Image::new(chunk.width))
+                .append_rows(&chunk, 0, chunk.height)?;
+            y += viewport;
+        }
+        let image = stitched.ok_or(Error::InvalidPng)?;
+        Ok(png::encode(&image)?)
+    }
+
     pub(crate) async fn emulate_media(&self, args: EmulateMediaArgs) -> ArcResult<()> {
         let _ = send_message!(self, "emulateMedia", args);
         Ok(())
@@ -336,7 +450,11 @@ impl Page {
         self.var.lock().unwrap().frames.clone()
     }
 
-    pub(crate) fn default_timeout(&self) -> u32 {
+    pub(crate) fn set_owned_context(&self, c: Weak<BrowserContext>) {
+        self.var.lock().unwrap().owned_context = Some(c);
+    }
+
+    pub(crate) fn default_timeout(&self) -> f64 {
         let this = self.var.lock().unwrap().timeout;
         let parent = || {
             self.browser_context
@@ -347,7 +465,7 @@ impl Page {
         this.unwrap_or_else(parent)
     }
 
-    pub(crate) fn default_navigation_timeout(&self) -> u32 {
+    pub(crate) fn default_navigation_timeout(&self) -> f64 {
         let this = self.var.lock().unwrap().navigation_timeout;
         let parent = || {
             self.browser_context
@@ -358,7 +476,7 @@ impl Page {
         this.unwrap_or_else(parent)
     }
 
-    pub(crate) async fn set_default_timeout(&self, timeout: u32) -> ArcResult<()> {
+    pub(crate) async fn set_default_timeout(&self, timeout: f64) -> ArcResult<()> {
         let mut args = Map::new();
         args.insert("timeout".into(), timeout.into());
         let _ = send_message!(self, "setDefaultTimeoutNoReply", args);
@@ -366,7 +484,7 @@ impl Page {
         Ok(())
     }
 
-    pub(crate) async fn set_default_navigation_timeout(&self, timeout: u32) -> ArcResult<()> {
+    pub(crate) async fn set_default_navigation_timeout(&self, timeout: f64) -> ArcResult<()> {
         let mut args = Map::new();
         args.insert("timeout".into(), timeout.into());
         let _ = send_message!(self, "setDefaultNavigationTimeoutNoReply", args);
@@ -387,13 +505,72 @@ impl Page {
         self.var.lock().unwrap().video.clone()
     }
 
-    fn on_close(&self, ctx: &Context) -> Result<(), Error> {
-        let bc = match self.browser_context().upgrade() {
-            None => return Ok(()),
-            Some(b) => b,
+    pub(crate) async fn route(&self, url: &str, handler: RouteHandlerFn) -> ArcResult<()> {
+        self.var
+            .lock()
+            .unwrap()
+            .routes
+            .insert(0, RouteHandler::new(url, handler));
+        self.update_interception_patterns().await
+    }
+
+    pub(crate) async fn unroute(&self, url: &str) -> ArcResult<()> {
+        self.var.lock().unwrap().routes.retain(|r| r.url != url);
+        self.update_interception_patterns().await
+    }
+
+    pub(crate) async fn unroute_all(&self) -> ArcResult<()> {
+        self.var.lock().unwrap().routes.clear();
+        self.update_interception_patterns().await
+    }
+
+    pub(crate) async fn route_from_har(
+        &self,
+        path: &Path,
+        options: RouteFromHarOptions,
+    ) -> ArcResult<()> {
+        let context = upgrade(&self.browser_context)?;
+        let RouteFromHarOptions { update, url } = options;
+        if update == Some(true) {
+            return context
+                .record_into_har(path, Some(self), url.as_deref())
+                .await;
+        }
+        let router = context.open_har(path).await?;
+        let url = url.as_deref().unwrap_or("**/*");
+        self.route(url, router.handler()).await
+    }
+
+    async fn update_interception_patterns(&self) -> ArcResult<()> {
+        let routes = self.var.lock().unwrap().routes.clone();
+        let args = InterceptionPatterns::new(&routes);
+        let _ = send_message!(self, "setNetworkInterceptionPatterns", args);
+        Ok(())
+    }
+
+    fn on_route(&self, ctx: &Context, params: Map<String, Value>) -> Result<(), Error> {
+        let first = first_object(&params).ok_or(Error::InvalidParams)?;
+        let OnlyGuid { guid } = serde_json::from_value((*first).clone())?;
+        let route = get_object!(ctx, &guid, Route)?;
+        let url = upgrade(&upgrade(&route)?.request())?.url().to_owned();
+        let handler = {
+            let routes = &self.var.lock().unwrap().routes;
+            routes.iter().find(|r| r.matches(&url)).cloned()
         };
-        let this = get_object!(ctx, self.guid(), Page)?;
-        bc.remove_page(&this);
+        match (handler, self.browser_context().upgrade()) {
+            (Some(h), _) => (h.handler)(route),
+            (None, Some(bc)) => bc.handle_route(route)?,
+            (None, None) => Route::fallback(route),
+        }
+        Ok(())
+    }
+
+    fn on_close(&self, ctx: &Context) -> Result<(), Error> {
+        self.is_closed.store(true, Ordering::SeqCst);
+        if let Some(bc) = self.browser_context().upgrade() {
+            let this = get_object!(ctx, self.guid(), Page)?;
+            bc.remove_page(&this);
+        }
         self.emit_event(Evt::Close);
         Ok(())
     }
@@ -495,8 +672,8 @@ impl Page {
         let artifact = get_object!(ctx, &guid, Artifact)?;
         // TODO: set_is_remote
         // artifactObject._isRemote = !!this._browserContext._browser && this._browserContext._browser._isRemote;
-        let download = Download::new(artifact, url, suggested_filename);
-        self.emit_event(Evt::Download(Arc::new(download)));
+        let download = Arc::new(Download::new(artifact, url, suggested_filename));
+        self.emit_event(Evt::Download(download));
         Ok(())
     }
 
@@ -523,8 +700,8 @@ impl Page {
         } = serde_json::from_value(params.into())?;
         let element = get_object!(ctx, &guid, ElementHandle)?;
         let this = get_object!(ctx, self.guid(), Page)?;
-        let _file_chooser = FileChooser::new(this, element, is_multiple);
-        // self.emit_event(Evt::FileChooser(file_chooser));
+        let file_chooser = FileChooser::new(this, element, is_multiple);
+        self.emit_event(Evt::FileChooser(file_chooser));
         Ok(())
     }
 }
@@ -545,23 +722,31 @@ impl RemoteObject for Page {
     ) -> Result<(), Error> {
         match method.as_str() {
             "close" => self.on_close(ctx)?,
-            "frameattached" => {
+            "frameAttached" => {
                 let first = first_object(&params).ok_or(Error::InvalidParams)?;
                 let OnlyGuid { guid } = serde_json::from_value((*first).clone())?;
                 self.on_frame_attached(ctx, guid)?;
             }
-            "framedetached" => {
+            "frameDetached" => {
                 let first = first_object(&params).ok_or(Error::InvalidParams)?;
                 let OnlyGuid { guid } = serde_json::from_value((*first).clone())?;
                 self.on_frame_detached(ctx, guid)?;
             }
             "load" => self.emit_event(Evt::Load),
             "domcontentloaded" => self.emit_event(Evt::DomContentLoaded),
-            "crash" => self.emit_event(Evt::Crash),
+            "crash" => {
+                self.is_crashed.store(true, Ordering::SeqCst);
+                self.emit_event(Evt::Crash);
+            }
             "console" => {
                 let first = first_object(&params).ok_or(Error::InvalidParams)?;
                 let OnlyGuid { guid } = serde_json::from_value((*first).clone())?;
-                let console = get_object!(ctx, &guid, ConsoleMessage)?;
+                let object = ctx
+                    .find_object(&guid)
+                    .and_then(|o| o.upgrade())
+                    .ok_or(Error::ObjectNotFound)?;
+                let fields = object.channel().initializer.clone();
+                let console = Arc::new(ConsoleMessage::try_new(ctx, fields)?);
                 self.emit_event(Evt::Console(console));
             }
             "request" => {
@@ -598,7 +783,8 @@ impl RemoteObject for Page {
             }
             "download" => self.on_download(ctx, params)?,
             "video" => self.on_video(ctx, params)?,
-            "filechooser" => self.on_file_chooser(ctx, params)?,
+            "fileChooser" => self.on_file_chooser(ctx, params)?,
+            "route" => self.on_route(ctx, params)?,
             _ => {}
         }
         Ok(())
@@ -609,15 +795,12 @@ impl RemoteObject for Page {
 pub(crate) enum Evt {
     Close,
     Crash,
-    Console(Weak<ConsoleMessage>),
-    /// Not Implemented Yet
-    Dialog,
+    Console(Arc<ConsoleMessage>),
+    Dialog(Weak<Dialog>),
     Download(Arc<Download>),
-    /// Not Implemented Yet
-    // FileChooser(FileChooser),
+    FileChooser(FileChooser),
     DomContentLoaded,
-    /// Not Implemented Yet
-    PageError,
+    PageError(PageError),
     Request(Weak<Request>),
     Response(Weak<Response>),
     RequestFailed(Weak<Request>),
@@ -649,7 +832,7 @@ pub enum EventType {
     Console,
     Dialog,
     Download,
-    // FileChooser,
+    FileChooser,
     DomContentLoaded,
     PageError,
     Request,
@@ -666,6 +849,21 @@ pub enum EventType {
     Video,
 }
 
+impl EventType {
+    /// Name for `updateSubscription` of the events the driver dispatches only on demand.
+    pub(crate) fn subscription(&self) -> Option<&'static str> {
+        match self {
+            Self::Dialog => Some("dialog"),
+            Self::FileChooser => Some("fileChooser"),
+            Self::Request => Some("request"),
+            Self::Response => Some("response"),
+            Self::RequestFailed => Some("requestFailed"),
+            Self::RequestFinished => Some("requestFinished"),
+            _ => None,
+        }
+    }
+}
+
 impl IsEvent for Evt {
     type EventType = EventType;
 
@@ -674,11 +872,11 @@ impl IsEvent for Evt {
             Self::Close => EventType::Close,
             Self::Crash => EventType::Crash,
             Self::Console(_) => EventType::Console,
-            Self::Dialog => EventType::Dialog,
+            Self::Dialog(_) => EventType::Dialog,
             Self::Download(_) => EventType::Download,
-            // Self::FileChooser(_) => EventType::FileChooser,
+            Self::FileChooser(_) => EventType::FileChooser,
             Self::DomContentLoaded => EventType::DomContentLoaded,
-            Self::PageError => EventType::PageError,
+            Self::PageError(_) => EventType::PageError,
             Self::Request(_) => EventType::Request,
             Self::Response(_) => EventType::Response,
             Self::RequestFailed(_) => EventType::RequestFailed,
@@ -734,15 +932,30 @@ pub(crate) struct AccessibilitySnapshotArgs {
     pub(crate) root: Option<OnlyGuid>,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
-#[serde(rename_all = "camelCase")]
-pub struct AccessibilitySnapshotResponse {
+/// An uncaught exception thrown in the page.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct PageError {
+    pub name: String,
+    pub message: String,
+    pub stack: Option<String>,
+}
+
+/// A node of the accessibility tree returned by [`Accessibility::snapshot`](crate::api::Accessibility::snapshot).
+#[derive(Debug, Clone, PartialEq)]
+pub struct AXNode {
+    /// The [role](https://www.w3.org/TR/wai-aria/#usage_intro).
     pub role: String,
+    /// A human readable name for the node.
     pub name: String,
+    /// The current value of the node, if applicable.
     pub value: Option<Val>,
+    /// An additional human readable description of the node, if applicable.
     pub description: Option<String>,
+    /// Keyboard shortcuts associated with this node, if applicable.
     pub keyshortcuts: Option<String>,
+    /// A human readable alternative to the role, if applicable.
     pub roledescription: Option<String>,
+    /// A description of the current value, if applicable.
     pub valuetext: Option<String>,
     pub disabled: Option<bool>,
     pub expanded: Option<bool>,
@@ -753,30 +966,122 @@ pub struct AccessibilitySnapshotResponse {
     pub readonly: Option<bool>,
     pub required: Option<bool>,
     pub selected: Option<bool>,
+    /// Whether the checkbox is checked, or "mixed", if applicable.
     pub checked: Option<Mixed>,
+    /// Whether the toggle button is checked, or "mixed", if applicable.
     pub pressed: Option<Mixed>,
+    /// The level of a heading, if applicable.
     pub level: Option<i64>,
-    pub valuemin: Option<f64>,
-    pub valuemax: Option<f64>,
+    /// The minimum value in a node, if applicable.
+    pub value_min: Option<f64>,
+    /// The maximum value in a node, if applicable.
+    pub value_max: Option<f64>,
+    /// The current numeric value in a node, if applicable.
+    pub value_now: Option<f64>,
     pub autocomplete: Option<String>,
     pub haspopup: Option<String>,
     pub invalid: Option<String>,
     pub orientation: Option<String>,
-    #[serde(default)]
-    pub children: Vec<AccessibilitySnapshotResponse>,
+    pub children: Vec<AXNode>,
 }
 
-#[derive(Debug, Deserialize, PartialEq)]
+#[deprecated(note = "renamed to `AXNode`")]
+pub type AccessibilitySnapshotResponse = AXNode;
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Val {
     String(String),
     Number(f64),
 }
-#[derive(Debug, Deserialize, PartialEq)]
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Mixed {
     Mixed,
     Bool(bool),
 }
 
+/// `AXNode` as sent by the driver
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AXNodeWire {
+    role: String,
+    name: String,
+    value_string: Option<String>,
+    value_number: Option<f64>,
+    description: Option<String>,
+    keyshortcuts: Option<String>,
+    roledescription: Option<String>,
+    valuetext: Option<String>,
+    disabled: Option<bool>,
+    expanded: Option<bool>,
+    focused: Option<bool>,
+    modal: Option<bool>,
+    multiline: Option<bool>,
+    multiselectable: Option<bool>,
+    readonly: Option<bool>,
+    required: Option<bool>,
+    selected: Option<bool>,
+    checked: Option<String>,
+    pressed: Option<String>,
+    level: Option<i64>,
+    valuemin: Option<f64>,
+    valuemax: Option<f64>,
+    autocomplete: Option<String>,
+    haspopup: Option<String>,
+    invalid: Option<String>,
+    orientation: Option<String>,
+    #[serde(default)]
+    children: Vec<AXNodeWire>,
+}
+
+impl Mixed {
+    fn parse(s: &str, on: &str) -> Self {
+        match s {
+            "mixed" => Self::Mixed,
+            s => Self::Bool(s == on),
+        }
+    }
+}
+
+impl From<AXNodeWire> for AXNode {
+    fn from(x: AXNodeWire) -> Self {
+        let value = match (x.value_string, x.value_number) {
+            (Some(s), _) => Some(Val::String(s)),
+            (None, Some(n)) => Some(Val::Number(n)),
+            (None, None) => None,
+        };
+        Self {
+            role: x.role,
+            name: x.name,
+            value,
+            description: x.description,
+            keyshortcuts: x.keyshortcuts,
+            roledescription: x.roledescription,
+            valuetext: x.valuetext,
+            disabled: x.disabled,
+            expanded: x.expanded,
+            focused: x.focused,
+            modal: x.modal,
+            multiline: x.multiline,
+            multiselectable: x.multiselectable,
+            readonly: x.readonly,
+            required: x.required,
+            selected: x.selected,
+            checked: x.checked.as_deref().map(|s| Mixed::parse(s, "checked")),
+            pressed: x.pressed.as_deref().map(|s| Mixed::parse(s, "pressed")),
+            level: x.level,
+            value_min: x.valuemin,
+            value_max: x.valuemax,
+            value_now: x.value_number,
+            autocomplete: x.autocomplete,
+            haspopup: x.haspopup,
+            invalid: x.invalid,
+            orientation: x.orientation,
+            children: x.children.into_iter().map(AXNode::from).collect(),
+        }
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Serialize, Default)]
 #[serde(rename_all = "camelCase")]
@@ -788,7 +1093,7 @@ pub(crate) struct ReloadArgs {
 #[skip_serializing_none]
 #[derive(Serialize, Default)]
 #[serde(rename_all = "camelCase")]
-pub(crate) struct PdfArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> {
+pub(crate) struct PdfArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i> {
     pub(crate) scale: Option<f64>,
     pub(crate) display_header_footer: Option<bool>,
     pub(crate) header_template: Option<&'a str>,
@@ -796,13 +1101,15 @@ pub(crate) struct PdfArgs<'a, 'b, 'c, 'd, 'e, 'f, 'g, 'h, 'i, 'j> {
     pub(crate) print_background: Option<bool>,
     pub(crate) landscape: Option<bool>,
     pub(crate) page_ranges: Option<&'c str>,
-    pub(crate) format: Option<&'d str>,
-    pub(crate) width: Option<Length<'e>>,
-    pub(crate) height: Option<Length<'f>>,
+    pub(crate) format: Option<PaperFormat>,
+    pub(crate) width: Option<Length<'d>>,
+    pub(crate) height: Option<Length<'e>>,
     #[serde(rename = "preferCSSPageSize")]
     pub(crate) prefer_css_page_size: Option<bool>,
-    pub(crate) margin: Option<PdfMargins<'g, 'h, 'i, 'j>>,
+    pub(crate) margin: Option<PdfMargins<'f, 'g, 'h, 'i>>,
     pub(crate) path: Option<PathBuf>,
+    #[serde(skip)]
+    pub(crate) pdf_a: Option<bool>,
 }
 
 #[skip_serializing_none]
@@ -816,6 +1123,18 @@ pub(crate) struct ScreenshotArgs {
     pub(crate) full_page: Option<bool>,
     pub(crate) clip: Option<FloatRect>,
     pub(crate) path: Option<PathBuf>,
+    pub(crate) mask: Option<Vec<ScreenshotMask>>,
+    pub(crate) mask_color: Option<String>,
+    pub(crate) animations: Option<ScreenshotAnimations>,
+    #[serde(skip)]
+    pub(crate) full_page_max_height: Option<u32>,
+}
+
+/// An element covered with a box of `maskColor` in the screenshot
+#[derive(Debug, Serialize)]
+pub(crate) struct ScreenshotMask {
+    pub(crate) frame: OnlyGuid,
+    pub(crate) selector: String,
 }
 
 #[skip_serializing_none]
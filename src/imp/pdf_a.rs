@@ -0,0 +1,773 @@
+//! Adds the PDF/A-1b identification to the PDF printed by Chromium.
+//!
+//! The document is not rewritten; an incremental update is appended that adds an XMP metadata stream mirroring a new
+//! document information dictionary, an sRGB output intent and a file identifier, drops the transparency group of
+//! every page, and replaces the catalog with one referring to the metadata and the output intent.
+//!
+//! Content streams are kept as printed, so transparency used inside a page or other features that PDF/A-1 forbids
+//! survive. The result is not validated.
+use crate::imp::core::*;
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+const SRGB: &str = "sRGB IEC61966-2.1";
+
+type Ref = (usize, usize);
+type Dict = Vec<(String, String)>;
+
+pub(crate) fn convert(mut pdf: Vec<u8>) -> Result<Vec<u8>, Error> {
+    // PDF/A-1 is based on PDF 1.4
+    if pdf.starts_with(b"%PDF-1.") && pdf.len() > 8 {
+        pdf[5..8].copy_from_slice(b"1.4");
+    }
+    let startxref = rfind(&pdf, b"startxref").ok_or(Error::InvalidPdf)?;
+    let prev: usize = first_token(&pdf[startxref + 9..])
+        .parse()
+        .map_err(|_| Error::InvalidPdf)?;
+    let trailer = rfind(&pdf[..startxref], b"trailer").ok_or(Error::InvalidPdf)?;
+    let trailer = dict_entries(&pdf[trailer + 7..startxref]).ok_or(Error::InvalidPdf)?;
+    let size: usize = find_entry(&trailer, "/Size")
+        .and_then(|v| v.parse().ok())
+        .ok_or(Error::InvalidPdf)?;
+    let root = find_entry(&trailer, "/Root")
+        .and_then(parse_ref)
+        .ok_or(Error::InvalidPdf)?;
+    let catalog = find_object(&pdf, root)
+        .and_then(dict_entries)
+        .ok_or(Error::InvalidPdf)?;
+    let info = find_entry(&trailer, "/Info")
+        .and_then(parse_ref)
+        .and_then(|r| find_object(&pdf, r))
+        .and_then(dict_entries)
+        .map(|entries| DocumentInfo::new(&entries))
+        .unwrap_or_default();
+    let id = match find_entry(&trailer, "/ID") {
+        Some(id) => id.to_owned(),
+        None => file_id(&pdf),
+    };
+    let mut pages = Vec::new();
+    if let Some(node) = find_entry(&catalog, "/Pages").and_then(parse_ref) {
+        collect_pages(&pdf, node, 0, &mut pages);
+    }
+
+    let mut update = Update::new(pdf, size);
+    let metadata = update.add_stream("/Type /Metadata /Subtype /XML", info.xmp().as_bytes());
+    let profile = update.add_stream("/N 3", &srgb_profile());
+    let intent = update.add(&format!(
+        "<</Type /OutputIntent /S /GTS_PDFA1 /OutputConditionIdentifier ({}) /Info ({}) \
+         /DestOutputProfile {} 0 R>>",
+        SRGB, SRGB, profile
+    ));
+    let info = update.add(&info.dict());
+    for (page, entries) in pages {
+        // PDF/A-1 forbids transparency groups
+        if find_entry(&entries, "/Group").is_some() {
+            update.replace(page, &format_dict(&entries, &["/Group"], ""));
+        }
+    }
+    let catalog = format_dict(
+        &catalog,
+        &["/Metadata", "/OutputIntents"],
+        &format!("/Metadata {} 0 R /OutputIntents [{} 0 R]", metadata, intent),
+    );
+    update.replace(root, &catalog);
+    Ok(update.finish(&trailer, info, &id, prev))
+}
+
+/// Objects appended to the document and the cross-reference section pointing at them
+struct Update {
+    out: Vec<u8>,
+    next: usize,
+    offsets: Vec<(Ref, usize)>,
+}
+
+impl Update {
+    fn new(mut pdf: Vec<u8>, size: usize) -> Self {
+        if !pdf.ends_with(b"\n") {
+            pdf.push(b'\n');
+        }
+        Self {
+            out: pdf,
+            next: size,
+            offsets: Vec::new(),
+        }
+    }
+
+    fn add(&mut self, body: &str) -> usize {
+        let num = self.next;
+        self.next += 1;
+        self.replace((num, 0), body);
+        num
+    }
+
+    fn add_stream(&mut self, dict: &str, data: &[u8]) -> usize {
+        let num = self.next;
+        self.next += 1;
+        self.offsets.push(((num, 0), self.out.len()));
+        self.out.extend_from_slice(
+            format!(
+                "{} 0 obj\n<<{} /Length {}>>\nstream\n",
+                num,
+                dict,
+                data.len()
+            )
+            .as_bytes(),
+        );
+        self.out.extend_from_slice(data);
+        self.out.extend_from_slice(b"\nendstream\nendobj\n");
+        num
+    }
+
+    fn replace(&mut self, (num, gen): Ref, body: &str) {
+        self.offsets.push(((num, gen), self.out.len()));
+        self.out
+            .extend_from_slice(format!("{} {} obj\n{}\nendobj\n", num, gen, body).as_bytes());
+    }
+
+    fn finish(
+        mut self,
+        trailer: &[(String, String)],
+        info: usize,
+        id: &str,
+        prev: usize,
+    ) -> Vec<u8> {
+        self.offsets.sort_unstable();
+        let xref = self.out.len();
+        let mut section = String::from("xref\n");
+        for ((num, gen), offset) in &self.offsets {
+            section += &format!("{} 1\n{:010} {:05} n \n", num, offset, gen);
+        }
+        let trailer = format_dict(
+            trailer,
+            &["/Size", "/Prev", "/Info", "/ID"],
+            &format!(
+                "/Size {} /Prev {} /Info {} 0 R /ID {}",
+                self.next, prev, info, id
+            ),
+        );
+        section += &format!("trailer\n{}\nstartxref\n{}\n%%EOF\n", trailer, xref);
+        self.out.extend_from_slice(section.as_bytes());
+        self.out
+    }
+}
+
+/// The entries of the document information dictionary that are kept, written both to the new dictionary and to the
+/// XMP metadata so that the two agree.
+#[derive(Debug, Default, PartialEq)]
+struct DocumentInfo {
+    title: Option<String>,
+    creator: Option<String>,
+    producer: Option<String>,
+    /// `(PDF date, XMP date)`
+    created: Option<(String, String)>,
+    modified: Option<(String, String)>,
+}
+
+impl DocumentInfo {
+    fn new(entries: &[(String, String)]) -> Self {
+        let text = |key| find_entry(entries, key).and_then(decode_string);
+        let date = |key| {
+            let date = text(key)?;
+            let xmp = xmp_date(&date)?;
+            Some((date, xmp))
+        };
+        Self {
+            title: text("/Title"),
+            creator: text("/Creator"),
+            producer: text("/Producer"),
+            created: date("/CreationDate"),
+            modified: date("/ModDate"),
+        }
+    }
+
+    fn dict(&self) -> String {
+        let mut dict = String::from("<<");
+        let texts = [
+            ("/Title", &self.title),
+            ("/Creator", &self.creator),
+            ("/Producer", &self.producer),
+        ];
+        for (key, value) in texts.iter() {
+            if let Some(v) = value {
+                dict += &format!("{} {} ", key, encode_string(v));
+            }
+        }
+        let dates = [
+            ("/CreationDate", &self.created),
+            ("/ModDate", &self.modified),
+        ];
+        for (key, value) in dates.iter() {
+            if let Some((v, _)) = value {
+                dict += &format!("{} {} ", key, encode_string(v));
+            }
+        }
+        dict.truncate(dict.trim_end().len());
+        dict + ">>"
+    }
+
+    fn xmp(&self) -> String {
+        let mut properties = String::new();
+        if let Some(title) = &self.title {
+            properties += &format!(
+                "<dc:title><rdf:Alt><rdf:li xml:lang=\"x-default\">{}</rdf:li></rdf:Alt></dc:title>\n",
+                escape_xml(title)
+            );
+        }
+        if let Some(creator) = &self.creator {
+            properties += &format!(
+                "<xmp:CreatorTool>{}</xmp:CreatorTool>\n",
+                escape_xml(creator)
+            );
+        }
+        if let Some((_, date)) = &self.created {
+            properties += &format!("<xmp:CreateDate>{}</xmp:CreateDate>\n", date);
+        }
+        if let Some((_, date)) = &self.modified {
+            properties += &format!("<xmp:ModifyDate>{}</xmp:ModifyDate>\n", date);
+        }
+        if let Some(producer) = &self.producer {
+            properties += &format!("<pdf:Producer>{}</pdf:Producer>\n", escape_xml(producer));
+        }
+        format!(
+            concat!(
+                "<?xpacket begin=\"\u{feff}\" id=\"W5M0MpCehiHzreSzNTczkc9d\"?>\n",
+                "<x:xmpmeta xmlns:x=\"adobe:ns:meta/\">\n",
+                "<rdf:RDF xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\">\n",
+                "<rdf:Description rdf:about=\"\" xmlns:pdfaid=\"http://www.aiim.org/pdfa/ns/id/\" ",
+                "xmlns:dc=\"http://purl.org/dc/elements/1.1/\" xmlns:xmp=\"http://ns.adobe.com/xap/1.0/\" ",
+                "xmlns:pdf=\"http://ns.adobe.com/pdf/1.3/\">\n",
+                "<pdfaid:part>1</pdfaid:part>\n",
+                "<pdfaid:conformance>B</pdfaid:conformance>\n",
+                "{}",
+                "</rdf:Description>\n",
+                "</rdf:RDF>\n",
+                "</x:xmpmeta>\n",
+                "<?xpacket end=\"w\"?>"
+            ),
+            properties
+        )
+    }
+}
+
+/// `D:YYYYMMDDHHmmSSOHH'mm'` as `YYYY-MM-DDTHH:mm:SS+HH:mm`
+fn xmp_date(date: &str) -> Option<String> {
+    let d = date.strip_prefix("D:").unwrap_or(date);
+    let digits = d.get(..14)?;
+    if !digits.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let offset = match &d[14..] {
+        "" => String::new(),
+        z if z.starts_with('Z') => "Z".into(),
+        tz if tz.starts_with('+') || tz.starts_with('-') => {
+            let hm: String = tz[1..].chars().filter(char::is_ascii_digit).collect();
+            let (h, m) = match hm.len() {
+                2 => (&hm[..], "00"),
+                4 => (&hm[..2], &hm[2..]),
+                _ => return None,
+            };
+            format!("{}{}:{}", &tz[..1], h, m)
+        }
+        _ => return None,
+    };
+    Some(format!(
+        "{}-{}-{}T{}:{}:{}{}",
+        &digits[..4],
+        &digits[4..6],
+        &digits[6..8],
+        &digits[8..10],
+        &digits[10..12],
+        &digits[12..14],
+        offset
+    ))
+}
+
+/// Decodes a literal or hexadecimal (UTF-16BE with a byte order mark) string object
+fn decode_string(v: &str) -> Option<String> {
+    if let Some(hex) = v.strip_prefix('<').and_then(|v| v.strip_suffix('>')) {
+        let hex: String = hex.chars().filter(|c| !c.is_whitespace()).collect();
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(hex.get(i..i + 2).unwrap_or("0"), 16).ok())
+            .collect::<Option<Vec<u8>>>()?;
+        return match bytes.strip_prefix(&[0xfe, 0xff]) {
+            Some(utf16) => {
+                let units: Vec<u16> = utf16
+                    .chunks(2)
+                    .map(|c| u16::from_be_bytes([c[0], *c.get(1).unwrap_or(&0)]))
+                    .collect();
+                String::from_utf16(&units).ok()
+            }
+            None => Some(bytes.iter().map(|&b| b as char).collect()),
+        };
+    }
+    let v = v.strip_prefix('(')?.strip_suffix(')')?;
+    let mut s = String::new();
+    let mut chars = v.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            s.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => s.push('\n'),
+            'r' => s.push('\r'),
+            't' => s.push('\t'),
+            'b' => s.push('\u{8}'),
+            'f' => s.push('\u{c}'),
+            '\n' => {}
+            c @ '0'..='7' => {
+                let mut code = c.to_digit(8)?;
+                for _ in 0..2 {
+                    match chars.clone().next().and_then(|c| c.to_digit(8)) {
+                        Some(d) => {
+                            code = code * 8 + d;
+                            chars.next();
+                        }
+                        None => break,
+                    }
+                }
+                s.push(char::from_u32(code)?);
+            }
+            c => s.push(c),
+        }
+    }
+    Some(s)
+}
+
+/// A literal string for ASCII text, a UTF-16BE hexadecimal string otherwise
+fn encode_string(s: &str) -> String {
+    if s.bytes().all(|b| (0x20..0x7f).contains(&b)) {
+        let escaped = s
+            .replace('\\', "\\\\")
+            .replace('(', "\\(")
+            .replace(')', "\\)");
+        return format!("({})", escaped);
+    }
+    let mut hex = String::from("<FEFF");
+    for unit in s.encode_utf16() {
+        hex += &format!("{:04X}", unit);
+    }
+    hex + ">"
+}
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Both halves of a fresh `/ID`. The content hash only has to be unique, not cryptographic.
+fn file_id(pdf: &[u8]) -> String {
+    let mut hex = String::new();
+    for seed in 0..2u8 {
+        let mut hasher = DefaultHasher::new();
+        seed.hash(&mut hasher);
+        pdf.hash(&mut hasher);
+        hex += &format!("{:016x}", hasher.finish());
+    }
+    format!("[<{0}> <{0}>]", hex)
+}
+
+/// Leaves of the page tree under `node` with their dictionaries
+fn collect_pages(pdf: &[u8], node: Ref, depth: usize, pages: &mut Vec<(Ref, Dict)>) {
+    // a malformed tree may be cyclic
+    if depth > 32 {
+        return;
+    }
+    let entries = match find_object(pdf, node).and_then(dict_entries) {
+        Some(x) => x,
+        None => return,
+    };
+    match find_entry(&entries, "/Kids") {
+        Some(kids) => {
+            for kid in parse_refs(kids) {
+                collect_pages(pdf, kid, depth + 1, pages);
+            }
+        }
+        None => pages.push((node, entries)),
+    }
+}
+
+fn format_dict(entries: &[(String, String)], without: &[&str], extra: &str) -> String {
+    let mut dict = String::from("<<");
+    for (k, v) in entries
+        .iter()
+        .filter(|(k, _)| !without.contains(&k.as_str()))
+    {
+        dict += &format!("{} {} ", k, v);
+    }
+    dict += extra;
+    dict.truncate(dict.trim_end().len());
+    dict + ">>"
+}
+
+fn rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).rposition(|w| w == needle)
+}
+
+fn first_token(b: &[u8]) -> String {
+    String::from_utf8_lossy(b)
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .to_owned()
+}
+
+fn find_entry<'a>(entries: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    entries
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
+}
+
+fn parse_ref(v: &str) -> Option<Ref> {
+    let mut it = v.split_whitespace();
+    let num = it.next()?.parse().ok()?;
+    let gen = it.next()?.parse().ok()?;
+    (it.next()? == "R").then_some((num, gen))
+}
+
+/// `[1 0 R 2 0 R]`
+fn parse_refs(v: &str) -> Vec<Ref> {
+    let v = v.trim_start_matches('[').trim_end_matches(']');
+    let words: Vec<&str> = v.split_whitespace().collect();
+    words
+        .chunks(3)
+        .filter_map(|w| parse_ref(&w.join(" ")))
+        .collect()
+}
+
+/// Returns the bytes between the last `N G obj` and its `endobj`
+fn find_object(pdf: &[u8], (num, gen): Ref) -> Option<&[u8]> {
+    let header = format!("{} {} obj", num, gen);
+    let header = header.as_bytes();
+    let start = pdf
+        .windows(header.len())
+        .enumerate()
+        .rev()
+        .filter(|(_, w)| *w == header)
+        .map(|(i, _)| i)
+        .find(|&i| i == 0 || pdf[i - 1].is_ascii_whitespace())?
+        + header.len();
+    let len = pdf[start..].windows(6).position(|w| w == b"endobj")?;
+    Some(&pdf[start..start + len])
+}
+
+/// Splits the first dictionary in `b` into its keys and the source text of their values.
+fn dict_entries(b: &[u8]) -> Option<Dict> {
+    let s = String::from_utf8_lossy(b);
+    let s = &s[s.find("<<")? + 2..];
+    let mut entries = Vec::new();
+    let mut rest = s.trim_start();
+    while !rest.starts_with(">>") {
+        if rest.is_empty() {
+            return None;
+        }
+        // `get` rather than indexing: a replacement character of a binary byte is not one byte long
+        let key_len = 1 + rest.get(1..)?.find(is_delimiter)?;
+        let (key, tail) = rest.split_at(key_len);
+        let tail = tail.trim_start();
+        let value_len = value_len(tail)?;
+        entries.push((key.to_owned(), tail[..value_len].trim_end().to_owned()));
+        rest = tail[value_len..].trim_start();
+    }
+    Some(entries)
+}
+
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace() || "/<>[]()".contains(c)
+}
+
+/// Length of the literal string at the start of `bytes`, whose parentheses only need balancing among themselves
+fn literal_len(bytes: &[u8]) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut escaped = false;
+    for (i, &c) in bytes.iter().enumerate() {
+        match c {
+            _ if escaped => escaped = false,
+            b'\\' => escaped = true,
+            b'(' => depth += 1,
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn value_len(s: &str) -> Option<usize> {
+    let bytes = s.as_bytes();
+    match bytes.first()? {
+        b'(' => literal_len(bytes),
+        b'<' | b'[' => {
+            let mut depth = 0usize;
+            let mut i = 0;
+            while i < bytes.len() {
+                match bytes[i] {
+                    b'(' => {
+                        i += literal_len(&bytes[i..])?;
+                        continue;
+                    }
+                    b'<' | b'[' => depth += 1,
+                    b'>' | b']' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(i + 1);
+                        }
+                    }
+                    _ => {}
+                }
+                i += 1;
+            }
+            None
+        }
+        b'/' => Some(1 + s.get(1..)?.find(is_delimiter).unwrap_or(s.len() - 1)),
+        _ => {
+            let first = s.find(is_delimiter).unwrap_or(s.len());
+            // `num gen R` is a single value
+            let tail = &s[first..];
+            let mut words = tail.split_whitespace();
+            match (words.next(), words.next()) {
+                (Some(gen), Some(r))
+                    if gen.bytes().all(|c| c.is_ascii_digit()) && r.starts_with('R') =>
+                {
+                    Some(first + tail.find('R')? + 1)
+                }
+                _ => Some(first),
+            }
+        }
+    }
+}
+
+/// An ICC v2 matrix/TRC profile of sRGB: the D50 adapted primaries of IEC 61966-2.1 and its piecewise transfer
+/// function sampled at 1024 points.
+fn srgb_profile() -> Vec<u8> {
+    fn s15(x: f64) -> [u8; 4] {
+        ((x * 65536.).round() as i32).to_be_bytes()
+    }
+    fn xyz(x: f64, y: f64, z: f64) -> Vec<u8> {
+        let mut v = b"XYZ \0\0\0\0".to_vec();
+        for n in [x, y, z].iter() {
+            v.extend_from_slice(&s15(*n));
+        }
+        v
+    }
+    let mut desc = b"desc\0\0\0\0".to_vec();
+    desc.extend_from_slice(&(SRGB.len() as u32 + 1).to_be_bytes());
+    desc.extend_from_slice(SRGB.as_bytes());
+    desc.extend_from_slice(&[0; 1 + 4 + 4 + 2 + 1 + 67]);
+    let mut cprt = b"text\0\0\0\0".to_vec();
+    cprt.extend_from_slice(b"No copyright, use freely\0");
+    let mut trc = b"curv\0\0\0\0".to_vec();
+    trc.extend_from_slice(&1024u32.to_be_bytes());
+    for i in 0..1024 {
+        let v = f64::from(i) / 1023.;
+        let linear = if v <= 0.04045 {
+            v / 12.92
+        } else {
+            ((v + 0.055) / 1.055).powf(2.4)
+        };
+        trc.extend_from_slice(&((linear * 65535.).round() as u16).to_be_bytes());
+    }
+    let tags: Vec<(&[u8; 4], Vec<u8>)> = vec![
+        (b"desc", desc),
+        (b"cprt", cprt),
+        (b"wtpt", xyz(0.9642, 1.0, 0.8249)),
+        (b"rXYZ", xyz(0.4361, 0.2225, 0.0139)),
+        (b"gXYZ", xyz(0.3851, 0.7169, 0.0971)),
+        (b"bXYZ", xyz(0.1431, 0.0606, 0.7141)),
+        (b"rTRC", trc.clone()),
+        (b"gTRC", trc.clone()),
+        (b"bTRC", trc),
+    ];
+    let mut table = (tags.len() as u32).to_be_bytes().to_vec();
+    let mut data = Vec::new();
+    let data_start = 128 + 4 + 12 * tags.len();
+    for (sig, tag) in &tags {
+        while data.len() % 4 != 0 {
+            data.push(0);
+        }
+        table.extend_from_slice(*sig);
+        table.extend_from_slice(&((data_start + data.len()) as u32).to_be_bytes());
+        table.extend_from_slice(&(tag.len() as u32).to_be_bytes());
+        data.extend_from_slice(tag);
+    }
+    let size = data_start + data.len();
+    let mut header = Vec::with_capacity(size);
+    header.extend_from_slice(&(size as u32).to_be_bytes());
+    header.extend_from_slice(&[0; 4]);
+    header.extend_from_slice(&[2, 0x10, 0, 0]);
+    header.extend_from_slice(b"mntrRGB XYZ ");
+    header.extend_from_slice(&[0; 12]);
+    header.extend_from_slice(b"acsp");
+    header.extend_from_slice(&[0; 4 + 4 + 4 + 4 + 8 + 4]);
+    for n in [0.9642, 1.0, 0.8249].iter() {
+        header.extend_from_slice(&s15(*n));
+    }
+    header.resize(128, 0);
+    header.extend(table);
+    header.extend(data);
+    header
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> (Vec<u8>, usize) {
+        let objects = [
+            "1 0 obj\n<</Type /Catalog /Pages 2 0 R /MarkInfo <</Marked true>>>>\nendobj\n",
+            "2 0 obj\n<</Type /Pages /Kids [3 0 R] /Count 1>>\nendobj\n",
+            "3 0 obj\n<</Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] \
+             /Group <</Type /Group /S /Transparency /CS /DeviceRGB>>>>\nendobj\n",
+            "4 0 obj\n<</Producer (Skia/PDF m120) /Creator (Chromium) \
+             /CreationDate (D:20261016112233+00'00') /ModDate (D:20261016112233+00'00')>>\nendobj\n",
+        ];
+        let mut pdf = b"%PDF-1.7\n".to_vec();
+        let mut offsets = Vec::new();
+        for o in objects.iter() {
+            offsets.push(pdf.len());
+            pdf.extend_from_slice(o.as_bytes());
+        }
+        let xref = pdf.len();
+        pdf.extend_from_slice(b"xref\n0 5\n0000000000 65535 f \n");
+        for o in &offsets {
+            pdf.extend_from_slice(format!("{:010} 00000 n \n", o).as_bytes());
+        }
+        pdf.extend_from_slice(
+            format!(
+                "trailer\n<</Size 5 /Root 1 0 R /Info 4 0 R>>\nstartxref\n{}\n%%EOF\n",
+                xref
+            )
+            .as_bytes(),
+        );
+        (pdf, xref)
+    }
+
+    fn resolve<'a>(pdf: &'a [u8], entries: &[(String, String)], key: &str) -> &'a [u8] {
+        let r = find_entry(entries, key).and_then(parse_ref).unwrap();
+        find_object(pdf, r).unwrap()
+    }
+
+    #[test]
+    fn appends_incremental_update() {
+        let (pdf, xref) = sample();
+        let out = convert(pdf.clone()).unwrap();
+        assert!(out.starts_with(b"%PDF-1.4"));
+        assert_eq!(&out[8..pdf.len()], &pdf[8..]);
+
+        // every entry of the new cross-reference section points at its object
+        let startxref = rfind(&out, b"startxref").unwrap();
+        let section: usize = first_token(&out[startxref + 9..]).parse().unwrap();
+        let text = String::from_utf8_lossy(&out[section..]).into_owned();
+        let mut lines = text.lines().skip(1);
+        while let Some(subsection) = lines.next().filter(|l| *l != "trailer") {
+            let num: usize = first_token(subsection.as_bytes()).parse().unwrap();
+            let entry = lines.next().unwrap();
+            let offset: usize = entry[..10].parse().unwrap();
+            assert!(out[offset..].starts_with(format!("{} ", num).as_bytes()));
+        }
+
+        let trailer = rfind(&out, b"trailer").unwrap();
+        let trailer = dict_entries(&out[trailer + 7..startxref]).unwrap();
+        assert_eq!(find_entry(&trailer, "/Size"), Some("9"));
+        assert_eq!(
+            find_entry(&trailer, "/Prev"),
+            Some(xref.to_string().as_str())
+        );
+        let id = find_entry(&trailer, "/ID").unwrap();
+        assert_eq!(id.matches('<').count(), 2);
+
+        let catalog = dict_entries(resolve(&out, &trailer, "/Root")).unwrap();
+        assert_eq!(find_entry(&catalog, "/Pages"), Some("2 0 R"));
+        assert_eq!(find_entry(&catalog, "/MarkInfo"), Some("<</Marked true>>"));
+        let metadata = String::from_utf8_lossy(resolve(&out, &catalog, "/Metadata")).into_owned();
+        assert!(metadata.contains("<pdfaid:part>1</pdfaid:part>"));
+        let intents = parse_refs(find_entry(&catalog, "/OutputIntents").unwrap());
+        let intent = dict_entries(find_object(&out, intents[0]).unwrap()).unwrap();
+        assert_eq!(find_entry(&intent, "/S"), Some("/GTS_PDFA1"));
+        let profile = resolve(&out, &intent, "/DestOutputProfile");
+        assert!(String::from_utf8_lossy(profile).contains("/N 3"));
+
+        // XMP mirrors the information dictionary
+        let info = DocumentInfo::new(&dict_entries(resolve(&out, &trailer, "/Info")).unwrap());
+        assert_eq!(info.producer.as_deref(), Some("Skia/PDF m120"));
+        assert!(metadata.contains(&info.xmp()));
+        assert!(metadata.contains("<xmp:CreateDate>2026-10-16T11:22:33+00:00</xmp:CreateDate>"));
+
+        let page = dict_entries(find_object(&out, (3, 0)).unwrap()).unwrap();
+        assert_eq!(find_entry(&page, "/Group"), None);
+        assert_eq!(find_entry(&page, "/Parent"), Some("2 0 R"));
+    }
+
+    #[test]
+    fn keeps_existing_id() {
+        let (pdf, _) = sample();
+        let pdf = String::from_utf8(pdf)
+            .unwrap()
+            .replace("/Info 4 0 R>>", "/Info 4 0 R /ID [<01> <02>]>>");
+        let out = convert(pdf.into_bytes()).unwrap();
+        let text = String::from_utf8_lossy(&out);
+        assert!(text.contains("/ID [<01> <02>]>>\nstartxref"));
+    }
+
+    #[test]
+    fn malformed_dictionaries_are_rejected() {
+        assert_eq!(
+            dict_entries(b"<</A /B /C [1 2]>>"),
+            Some(vec![
+                ("/A".to_owned(), "/B".to_owned()),
+                ("/C".to_owned(), "[1 2]".to_owned())
+            ])
+        );
+        assert_eq!(dict_entries(b"<<"), None);
+        assert_eq!(dict_entries(b"<< /A 1"), None);
+        // binary bytes become multi-byte replacement characters
+        assert_eq!(dict_entries(b"<< \xff\xfe >>"), None);
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(decode_string("(a\\(b\\) \\101)").as_deref(), Some("a(b) A"));
+        assert_eq!(decode_string("<FEFF00E9>").as_deref(), Some("é"));
+        assert_eq!(encode_string("a(b)"), "(a\\(b\\))");
+        assert_eq!(encode_string("é"), "<FEFF00E9>");
+        assert_eq!(
+            xmp_date("D:20261016112233-05'30'").as_deref(),
+            Some("2026-10-16T11:22:33-05:30")
+        );
+        assert_eq!(
+            xmp_date("D:20261016112233Z").as_deref(),
+            Some("2026-10-16T11:22:33Z")
+        );
+        assert_eq!(xmp_date("D:2026"), None);
+    }
+
+    #[test]
+    fn srgb_profile_is_well_formed() {
+        let icc = srgb_profile();
+        assert_eq!(&icc[..4], &(icc.len() as u32).to_be_bytes());
+        assert_eq!(&icc[36..40], b"acsp");
+        let be32 = |i: usize| u32::from_be_bytes([icc[i], icc[i + 1], icc[i + 2], icc[i + 3]]);
+        let trc = (0..be32(128) as usize)
+            .map(|i| 132 + 12 * i)
+            .find(|&t| &icc[t..t + 4] == b"rTRC")
+            .map(|t| be32(t + 4) as usize)
+            .unwrap();
+        assert_eq!(&icc[trc..trc + 4], b"curv");
+        assert_eq!(&icc[trc + 8..trc + 12], &1024u32.to_be_bytes());
+        let sample = |i: usize| u16::from_be_bytes([icc[trc + 12 + 2 * i], icc[trc + 13 + 2 * i]]);
+        assert_eq!(sample(0), 0);
+        assert_eq!(sample(1023), 65535);
+        // sRGB 0.5 is 21.4% linear, not the 21.8% of a 2.2 gamma
+        let mid = f64::from(sample(512)) / 65535.;
+        assert!((0.213..0.216).contains(&mid));
+    }
+}
@@ -0,0 +1,157 @@
+//! Decodes screenshots to RGBA and encodes them back, to stitch them together.
+use crate::imp::core::*;
+use ::png::{BitDepth, ColorType, Decoder, Encoder, Transformations};
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct Image {
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+    /// RGBA, row by row
+    pub(crate) pixels: Vec<u8>,
+}
+
+impl Image {
+    pub(crate) fn new(width: usize) -> Self {
+        Self {
+            width,
+            height: 0,
+            pixels: Vec::new(),
+        }
+    }
+
+    /// Appends `rows` rows of `other` starting at `from` to the bottom of this image.
+    pub(crate) fn append_rows(
+        &mut self,
+        other: &Image,
+        from: usize,
+        rows: usize,
+    ) -> Result<(), Error> {
+        if other.width != self.width || from + rows > other.height {
+            return Err(Error::InvalidPng);
+        }
+        let stride = self.width * 4;
+        self.pixels
+            .extend_from_slice(&other.pixels[from * stride..(from + rows) * stride]);
+        self.height += rows;
+        Ok(())
+    }
+}
+
+pub(crate) fn decode(png: &[u8]) -> Result<Image, Error> {
+    let mut decoder = Decoder::new(png);
+    // palettes and transparency chunks are expanded, 16 bit samples are cut to 8 bit
+    decoder.set_transformations(Transformations::EXPAND | Transformations::STRIP_16);
+    let mut reader = decoder.read_info().map_err(|_| Error::InvalidPng)?;
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buf).map_err(|_| Error::InvalidPng)?;
+    let (width, height) = (info.width as usize, info.height as usize);
+    let channels = match info.color_type {
+        ColorType::Grayscale => 1,
+        ColorType::GrayscaleAlpha => 2,
+        ColorType::Rgb => 3,
+        ColorType::Rgba => 4,
+        ColorType::Indexed => return Err(Error::InvalidPng),
+    };
+    let mut pixels = Vec::with_capacity(width * height * 4);
+    for row in buf.chunks_exact(info.line_size).take(height) {
+        for px in row[..width * channels].chunks_exact(channels) {
+            match channels {
+                1 => pixels.extend_from_slice(&[px[0], px[0], px[0], 255]),
+                2 => pixels.extend_from_slice(&[px[0], px[0], px[0], px[1]]),
+                3 => pixels.extend_from_slice(&[px[0], px[1], px[2], 255]),
+                _ => pixels.extend_from_slice(px),
+            }
+        }
+    }
+    Ok(Image {
+        width,
+        height,
+        pixels,
+    })
+}
+
+pub(crate) fn encode(image: &Image) -> Result<Vec<u8>, Error> {
+    let mut png = Vec::new();
+    let mut encoder = Encoder::new(&mut png, image.width as u32, image.height as u32);
+    encoder.set_color(ColorType::Rgba);
+    encoder.set_depth(BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(|_| Error::InvalidPng)?;
+    writer
+        .write_image_data(&image.pixels)
+        .map_err(|_| Error::InvalidPng)?;
+    writer.finish().map_err(|_| Error::InvalidPng)?;
+    Ok(png)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2x2 8 bit RGB, Adam7 interlaced: red, green / blue, white
+    const INTERLACED: &[u8] = &[
+        0x89, 0x50, 0x4e, 0x47, 0x0d, 0x0a, 0x1a, 0x0a, 0x00, 0x00, 0x00, 0x0d, 0x49, 0x48, 0x44,
+        0x52, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x08, 0x02, 0x00, 0x00, 0x01, 0x8a,
+        0xd3, 0xaa, 0xe5, 0x00, 0x00, 0x00, 0x0f, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9c, 0x63, 0xf8,
+        0xcf, 0x00, 0x04, 0x10, 0x02, 0x08, 0x00, 0x20, 0xee, 0x05, 0xfb, 0xf5, 0x2b, 0xe9, 0xca,
+        0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4e, 0x44, 0xae, 0x42, 0x60, 0x82,
+    ];
+
+    #[test]
+    fn round_trips() {
+        let image = Image {
+            width: 3,
+            height: 2,
+            pixels: (0..24).collect(),
+        };
+        assert_eq!(decode(&encode(&image).unwrap()).unwrap(), image);
+    }
+
+    #[test]
+    fn decodes_interlaced() {
+        let image = decode(INTERLACED).unwrap();
+        assert_eq!((image.width, image.height), (2, 2));
+        assert_eq!(
+            image.pixels,
+            vec![255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255, 255, 255, 255, 255]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed() {
+        let image = Image {
+            width: 3,
+            height: 2,
+            pixels: (0..24).collect(),
+        };
+        let png = encode(&image).unwrap();
+        assert!(decode(b"").is_err());
+        assert!(decode(b"GIF89a").is_err());
+        // header only, no image data
+        assert!(decode(&png[..33]).is_err());
+        assert!(decode(&png[..png.len() - 20]).is_err());
+        let mut corrupt = png;
+        // first byte of the zlib stream
+        corrupt[41] ^= 0xff;
+        assert!(decode(&corrupt).is_err());
+    }
+
+    #[test]
+    fn stitches_rows() {
+        let top = Image {
+            width: 1,
+            height: 2,
+            pixels: vec![1, 1, 1, 1, 2, 2, 2, 2],
+        };
+        let bottom = Image {
+            width: 1,
+            height: 2,
+            pixels: vec![3, 3, 3, 3, 4, 4, 4, 4],
+        };
+        let mut image = Image::new(1);
+        image.append_rows(&top, 0, 2).unwrap();
+        image.append_rows(&bottom, 1, 1).unwrap();
+        assert_eq!(image.height, 3);
+        assert_eq!(image.pixels, vec![1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4]);
+        assert!(image.append_rows(&bottom, 1, 2).is_err());
+    }
+}
@@ -102,6 +102,10 @@ impl Request {
             .ok()
     }
 
+    pub(crate) fn post_data_base64(&self) -> Option<&str> {
+        self.post_data.as_deref()
+    }
+
     pub(crate) fn post_data_as_string(&self) -> Option<String> {
         let bytes = self.post_data()?;
         let s = String::from_utf8(bytes).ok()?;
@@ -2,7 +2,7 @@ use crate::imp::{
     core::*,
     prelude::*,
     request::Request,
-    utils::{Header, ResponseTiming},
+    utils::{Header, RemoteAddress, ResponseTiming, SecurityDetails},
 };
 use base64::Engine;
 
@@ -12,6 +12,7 @@ pub(crate) struct Response {
     url: String,
     status: i32,
     status_text: String,
+    headers: Vec<Header>,
     request: Weak<Request>,
 }
 
@@ -21,9 +22,17 @@ impl Response {
             url,
             status,
             status_text,
+            headers,
             request,
             timing,
         } = serde_json::from_value(channel.initializer.clone())?;
+        let headers = headers
+            .into_iter()
+            .map(|mut h: Header| {
+                h.name.make_ascii_lowercase();
+                h
+            })
+            .collect();
         let request = get_object!(ctx, &request.guid, Request)?;
         upgrade(&request)?.set_response_timing(timing);
         Ok(Self {
@@ -31,6 +40,7 @@ impl Response {
             url,
             status,
             status_text,
+            headers,
             request,
         })
     }
@@ -72,15 +82,28 @@ impl Response {
         self.request.clone()
     }
 
-    pub(crate) async fn headers(&self) -> ArcResult<Vec<Header>> {
-        let v = send_message!(self, "body", Map::new());
-        let first = first(&v).ok_or(Error::InvalidParams)?;
-        let mut headers: Vec<Header> =
-            serde_json::from_value((*first).clone()).map_err(Error::Serde)?;
-        for h in headers.iter_mut() {
-            h.name.make_ascii_lowercase();
-        }
-        Ok(headers)
+    pub(crate) fn headers(&self) -> &[Header] {
+        &self.headers
+    }
+
+    pub(crate) async fn security_details(&self) -> ArcResult<Option<SecurityDetails>> {
+        let v = send_message!(self, "securityDetails", Map::new());
+        let first = match first(&v) {
+            Some(x) => x,
+            None => return Ok(None),
+        };
+        let details = serde_json::from_value((*first).clone()).map_err(Error::Serde)?;
+        Ok(Some(details))
+    }
+
+    pub(crate) async fn server_addr(&self) -> ArcResult<Option<RemoteAddress>> {
+        let v = send_message!(self, "serverAddr", Map::new());
+        let first = match first(&v) {
+            Some(x) => x,
+            None => return Ok(None),
+        };
+        let addr = serde_json::from_value((*first).clone()).map_err(Error::Serde)?;
+        Ok(Some(addr))
     }
 }
 
@@ -99,6 +122,8 @@ struct Initializer {
     url: String,
     status: i32,
     status_text: String,
+    #[serde(default)]
+    headers: Vec<Header>,
     request: OnlyGuid,
     timing: ResponseTiming,
 }
@@ -1,4 +1,9 @@
-use crate::imp::{core::*, prelude::*, request::Request, utils::Header};
+use crate::imp::{
+    api_request_context::FetchArgs as RequestFetchArgs, browser_context::BrowserContext, core::*,
+    prelude::*, request::Request, utils::Header,
+};
+use base64::Engine;
+use regex::Regex;
 
 #[derive(Debug)]
 pub(crate) struct Route {
@@ -20,21 +25,159 @@ impl Route {
     pub(crate) async fn abort(&self, err_code: Option<&str>) -> Result<(), Arc<Error>> {
         let mut args = HashMap::new();
         if let Some(x) = err_code {
-            args.insert("errCode", x);
+            args.insert("errorCode", x);
         }
         let _ = send_message!(self, "abort", args);
         Ok(())
     }
 
     pub(crate) async fn fulfill(&self, args: FulfillArgs<'_, '_>) -> ArcResult<()> {
+        #[skip_serializing_none]
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Args {
+            status: Option<i32>,
+            headers: Option<Vec<Header>>,
+            body: Option<String>,
+            is_base64: bool,
+            fetch_response_uid: Option<String>,
+        }
+        let FulfillArgs {
+            body,
+            is_base64,
+            status,
+            mut headers,
+            content_type,
+            path,
+            fetch_response_uid,
+        } = args;
+        let (body, is_base64) = match path {
+            Some(path) => {
+                let bytes = std::fs::read(&path).map_err(Error::from)?;
+                let body = base64::engine::general_purpose::STANDARD.encode(bytes);
+                (body, true)
+            }
+            None => (body.to_owned(), is_base64),
+        };
+        if let Some(content_type) = content_type {
+            let headers = headers.get_or_insert_with(Vec::new);
+            headers.retain(|h| !h.name.eq_ignore_ascii_case("content-type"));
+            headers.push(Header {
+                name: "content-type".into(),
+                value: content_type.into(),
+            });
+        }
+        let args = Args {
+            status,
+            headers,
+            body: Some(body),
+            is_base64,
+            fetch_response_uid,
+        };
         let _ = send_message!(self, "fulfill", args);
         Ok(())
     }
 
     pub(crate) async fn r#continue(&self, args: ContinueArgs<'_, '_, '_>) -> ArcResult<()> {
+        self.continue_with(args, false).await
+    }
+
+    async fn continue_with(
+        &self,
+        args: ContinueArgs<'_, '_, '_>,
+        is_fallback: bool,
+    ) -> ArcResult<()> {
+        #[skip_serializing_none]
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Args<'a, 'b> {
+            url: Option<&'a str>,
+            method: Option<&'b str>,
+            headers: Option<Vec<Header>>,
+            post_data: Option<String>,
+            is_fallback: bool,
+        }
+        let ContinueArgs {
+            url,
+            method,
+            headers,
+            post_data,
+        } = args;
+        let args = Args {
+            url,
+            method,
+            headers,
+            post_data: post_data.map(|s| base64::engine::general_purpose::STANDARD.encode(s)),
+            is_fallback,
+        };
         let _ = send_message!(self, "continue", args);
         Ok(())
     }
+
+    pub(crate) async fn redirect_navigation_request(&self, url: &str) -> ArcResult<()> {
+        let mut args = HashMap::new();
+        args.insert("url", url);
+        let _ = send_message!(self, "redirectNavigationRequest", args);
+        Ok(())
+    }
+
+    /// Lets the request through when no handler matched it.
+    pub(crate) fn fallback(route: Weak<Route>) {
+        spawn(async move {
+            if let Some(r) = route.upgrade() {
+                let _ = r.continue_with(ContinueArgs::default(), true).await;
+            }
+        });
+    }
+
+    pub(crate) async fn fetch(&self, args: FetchArgs<'_, '_, '_>) -> ArcResult<RouteResponse> {
+        let request = upgrade(&self.request)?;
+        let browser_context = Self::browser_context(&request)?;
+        let request_context = upgrade(&browser_context.request_context()?)?;
+        let FetchArgs {
+            url,
+            method,
+            headers,
+            post_data,
+            max_redirects,
+            timeout,
+        } = args;
+        let headers = headers.unwrap_or_else(|| {
+            request
+                .headers()
+                .iter()
+                .map(|(k, v)| Header::from((k.clone(), v.clone())))
+                .collect()
+        });
+        let post_data = match post_data {
+            Some(s) => Some(base64::engine::general_purpose::STANDARD.encode(s)),
+            None => request.post_data_base64().map(ToOwned::to_owned),
+        };
+        let args = RequestFetchArgs {
+            url: url.unwrap_or_else(|| request.url()),
+            method: Some(method.unwrap_or_else(|| request.method())),
+            headers: Some(headers),
+            post_data,
+            timeout: Some(timeout.unwrap_or_else(|| browser_context.default_timeout())),
+            max_redirects,
+        };
+        let res = request_context.fetch(args).await?;
+        let body = request_context.fetch_response_body(&res.fetch_uid).await?;
+        Ok(RouteResponse {
+            url: res.url,
+            status: res.status,
+            status_text: res.status_text,
+            headers: res.headers,
+            body,
+            fetch_uid: res.fetch_uid,
+        })
+    }
+
+    fn browser_context(request: &Request) -> Result<Arc<BrowserContext>, Error> {
+        let frame = upgrade(&request.frame())?;
+        let page = upgrade(&frame.page().ok_or(Error::ObjectNotFound)?)?;
+        upgrade(&page.browser_context())
+    }
 }
 
 impl RemoteObject for Route {
@@ -52,15 +195,14 @@ struct Initializer {
     request: OnlyGuid,
 }
 
-#[skip_serializing_none]
-#[derive(Serialize)]
-#[serde(rename_all = "camelCase")]
 pub(crate) struct FulfillArgs<'a, 'b> {
     body: &'a str,
     is_base64: bool,
     pub(crate) status: Option<i32>,
     pub(crate) headers: Option<Vec<Header>>,
     pub(crate) content_type: Option<&'b str>,
+    pub(crate) path: Option<PathBuf>,
+    pub(crate) fetch_response_uid: Option<String>,
 }
 
 impl<'a, 'b> FulfillArgs<'a, 'b> {
@@ -71,16 +213,205 @@ impl<'a, 'b> FulfillArgs<'a, 'b> {
             status: None,
             headers: None,
             content_type: None,
+            path: None,
+            fetch_response_uid: None,
         }
     }
 }
 
-#[skip_serializing_none]
-#[derive(Serialize, Default)]
-#[serde(rename_all = "camelCase")]
+#[derive(Default)]
 pub(crate) struct ContinueArgs<'a, 'b, 'c> {
     pub(crate) url: Option<&'a str>,
     pub(crate) method: Option<&'b str>,
     pub(crate) headers: Option<Vec<Header>>,
     pub(crate) post_data: Option<&'c str>,
 }
+
+#[derive(Default)]
+pub(crate) struct FetchArgs<'a, 'b, 'c> {
+    pub(crate) url: Option<&'a str>,
+    pub(crate) method: Option<&'b str>,
+    pub(crate) headers: Option<Vec<Header>>,
+    pub(crate) post_data: Option<&'c str>,
+    pub(crate) max_redirects: Option<i32>,
+    pub(crate) timeout: Option<f64>,
+}
+
+/// Response of [`Route::fetch_builder`](crate::api::Route::fetch_builder). Pass it to
+/// [`FulfillBuilder::response`](crate::api::route::FulfillBuilder::response) to fulfill the route with it.
+#[derive(Debug, Clone)]
+pub struct RouteResponse {
+    pub url: String,
+    pub status: i32,
+    pub status_text: String,
+    pub headers: Vec<Header>,
+    pub body: Vec<u8>,
+    pub(crate) fetch_uid: String,
+}
+
+impl RouteResponse {
+    /// Contains a boolean stating whether the response was successful (status in the range 200-299) or not.
+    pub fn ok(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Returns the text representation of response body.
+    pub fn text(&self) -> Result<String, Error> {
+        String::from_utf8(self.body.clone()).map_err(Error::InvalidUtf8)
+    }
+
+    /// Returns the JSON representation of response body.
+    pub fn json<T>(&self) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        serde_json::from_slice(&self.body).map_err(Error::Serde)
+    }
+}
+
+/// Options of [`Page::unroute_all`](crate::api::Page::unroute_all) and
+/// [`BrowserContext::unroute_all`](crate::api::BrowserContext::unroute_all)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct UnrouteAllOptions {
+    pub behavior: Option<UnrouteBehavior>,
+}
+
+/// What to do with route handlers that are still running when they are removed.
+///
+/// Handlers are called synchronously when a request is routed and any async work they start is owned by them, so
+/// there is never a running handler to wait for and every behavior simply removes the routes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnrouteBehavior {
+    Wait,
+    IgnoreErrors,
+    Default,
+}
+
+pub(crate) type RouteHandlerFn = Arc<dyn Fn(Weak<Route>) + Send + Sync>;
+
+#[derive(Clone)]
+pub(crate) struct RouteHandler {
+    pub(crate) url: String,
+    regex: Regex,
+    pub(crate) handler: RouteHandlerFn,
+}
+
+impl std::fmt::Debug for RouteHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RouteHandler")
+            .field("url", &self.url)
+            .finish()
+    }
+}
+
+impl RouteHandler {
+    pub(crate) fn new(url: &str, handler: RouteHandlerFn) -> Self {
+        Self {
+            url: url.to_owned(),
+            regex: glob_to_regex(url),
+            handler,
+        }
+    }
+
+    pub(crate) fn matches(&self, url: &str) -> bool {
+        self.regex.is_match(url)
+    }
+}
+
+/// Argument of `setNetworkInterceptionPatterns`
+#[derive(Serialize)]
+pub(crate) struct InterceptionPatterns<'a> {
+    patterns: Vec<Pattern<'a>>,
+}
+
+#[derive(Serialize)]
+struct Pattern<'a> {
+    glob: &'a str,
+}
+
+impl<'a> InterceptionPatterns<'a> {
+    pub(crate) fn new(handlers: &'a [RouteHandler]) -> Self {
+        let patterns = handlers.iter().map(|h| Pattern { glob: &h.url }).collect();
+        Self { patterns }
+    }
+}
+
+/// `**` matches any characters, `*` matches any characters except `/` and `{a,b}` matches any of the alternatives.
+///
+/// The glob is compiled to an anchored regex once, so matching a url is linear in its length.
+fn glob_to_regex(glob: &str) -> Regex {
+    let mut re = String::from("^");
+    let mut in_group = false;
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(escaped) => re.push_str(&regex::escape(&escaped.to_string())),
+                None => re.push_str(r"\\"),
+            },
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                re.push_str(".*");
+            }
+            '*' => re.push_str("[^/]*"),
+            '{' if !in_group => {
+                in_group = true;
+                re.push_str("(?:");
+            }
+            '}' if in_group => {
+                in_group = false;
+                re.push(')');
+            }
+            ',' if in_group => re.push('|'),
+            c => re.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    if in_group {
+        re.push(')');
+    }
+    re.push('$');
+    Regex::new(&re).expect("escaped glob is a valid regex")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glob_matches(glob: &str, url: &str) -> bool {
+        glob_to_regex(glob).is_match(url)
+    }
+
+    #[test]
+    fn glob() {
+        assert!(glob_matches("**/*", "https://example.com/foo"));
+        assert!(glob_matches(
+            "**/*.json",
+            "https://example.com/api/data.json"
+        ));
+        assert!(!glob_matches(
+            "**/*.json",
+            "https://example.com/api/data.js"
+        ));
+        assert!(glob_matches(
+            "https://example.com/*",
+            "https://example.com/foo"
+        ));
+        assert!(!glob_matches(
+            "https://example.com/*",
+            "https://example.com/foo/bar"
+        ));
+        assert!(glob_matches("**/*.{png,jpg}", "http://localhost/a.jpg"));
+        assert!(!glob_matches("**/*.{png,jpg}", "http://localhost/a.gif"));
+        assert!(glob_matches("**/a\\*b", "http://localhost/a*b"));
+        assert!(!glob_matches("**/a\\*b", "http://localhost/axb"));
+        assert!(glob_matches("**/a.b?c", "http://localhost/a.b?c"));
+        assert!(!glob_matches("**/a.b", "http://localhost/axb"));
+    }
+
+    #[test]
+    fn glob_does_not_backtrack() {
+        let glob = "**".repeat(32) + "x";
+        let url = "a".repeat(4096);
+        assert!(!glob_matches(&glob, &url));
+    }
+}
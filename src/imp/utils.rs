@@ -29,20 +29,47 @@ pub struct Geolocation {
     pub accuracy: Option<f64>,
 }
 
+#[skip_serializing_none]
 #[derive(Debug, Deserialize, Clone, Serialize)]
 pub struct HttpCredentials {
     pub username: String,
     pub password: String,
+    /// Restrain sending http credentials on specific origin (scheme://host:port).
+    #[serde(default)]
+    pub origin: Option<String>,
+}
+
+impl HttpCredentials {
+    pub fn new(username: &str, password: &str) -> Self {
+        Self {
+            username: username.to_owned(),
+            password: password.to_owned(),
+            origin: None,
+        }
+    }
+
+    pub fn with_origin(mut self, origin: &str) -> Self {
+        self.origin = Some(origin.to_owned());
+        self
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
-#[serde(rename_all = "snake_case")]
+#[serde(rename_all = "kebab-case")]
 pub enum ColorScheme {
     Dark,
     Light,
     NoPreference,
 }
 
+/// Whether pages of a context may register [Service Workers](https://developer.mozilla.org/en-US/docs/Web/API/Service_Worker_API)
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ServiceWorkerPolicy {
+    Allow,
+    Block,
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Deserialize, Serialize)]
 pub struct StorageState {
@@ -96,6 +123,18 @@ impl Cookie {
     }
 }
 
+/// Selects the cookies removed by [`BrowserContext::clear_cookies_filter`](crate::api::BrowserContext::clear_cookies_filter).
+/// A cookie is removed when it matches every given field exactly, as returned by
+/// [`BrowserContext::cookies`](crate::api::BrowserContext::cookies); an empty filter matches all cookies.
+#[skip_serializing_none]
+#[derive(Debug, Serialize, Default, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CookieFilter {
+    pub name: Option<String>,
+    pub domain: Option<String>,
+    pub path: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
 pub enum SameSite {
     Lax,
@@ -163,6 +202,10 @@ pub struct FloatRect {
     pub height: f64,
 }
 
+/// Position and size of an element relative to the main frame viewport, as returned by
+/// [`ElementHandle::bounding_box`](crate::api::ElementHandle::bounding_box).
+pub type BoundingBox = FloatRect;
+
 #[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
 #[serde(rename_all = "lowercase")]
 pub enum ScreenshotType {
@@ -170,6 +213,15 @@ pub enum ScreenshotType {
     Png,
 }
 
+#[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum ScreenshotAnimations {
+    Allow,
+    /// Finite animations are fast-forwarded to completion, infinite animations are cancelled to their initial state, and
+    /// transitions are finished
+    Disabled,
+}
+
 #[derive(Debug, Deserialize, Serialize, Eq, PartialEq, Clone, Copy)]
 #[serde(rename_all = "lowercase")]
 pub enum ElementState {
@@ -227,6 +279,52 @@ impl<'a> From<&'a str> for Length<'a> {
     }
 }
 
+/// Named paper size of [`PdfBuilder::format`](crate::api::page::PdfBuilder::format)
+#[derive(Debug, Serialize, PartialEq, Eq, Clone, Copy)]
+pub enum PaperFormat {
+    /// 8.5in x 11in
+    Letter,
+    /// 8.5in x 14in
+    Legal,
+    /// 11in x 17in
+    Tabloid,
+    /// 17in x 11in
+    Ledger,
+    /// 33.1in x 46.8in
+    A0,
+    /// 23.4in x 33.1in
+    A1,
+    /// 16.54in x 23.4in
+    A2,
+    /// 11.7in x 16.54in
+    A3,
+    /// 8.27in x 11.7in
+    A4,
+    /// 5.83in x 8.27in
+    A5,
+    /// 4.13in x 5.83in
+    A6,
+}
+
+impl PaperFormat {
+    /// Width and height the driver uses for the format
+    pub(crate) fn size(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Letter => ("8.5in", "11in"),
+            Self::Legal => ("8.5in", "14in"),
+            Self::Tabloid => ("11in", "17in"),
+            Self::Ledger => ("17in", "11in"),
+            Self::A0 => ("33.1in", "46.8in"),
+            Self::A1 => ("23.4in", "33.1in"),
+            Self::A2 => ("16.54in", "23.4in"),
+            Self::A3 => ("11.7in", "16.54in"),
+            Self::A4 => ("8.27in", "11.7in"),
+            Self::A5 => ("5.83in", "8.27in"),
+            Self::A6 => ("4.13in", "5.83in"),
+        }
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Serialize, PartialEq, Clone)]
 pub struct PdfMargins<'a, 'b, 'c, 'd> {
@@ -296,3 +394,75 @@ pub struct ResponseTiming {
     /// Time immediately after the browser starts requesting the resource from the server, cache, or local resource. The value\nis given in milliseconds relative to `startTime`, -1 if not available.
     pub response_start: f64,
 }
+
+#[skip_serializing_none]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SecurityDetails {
+    /// Common Name component of the Issuer field from the certificate.
+    pub issuer: Option<String>,
+    /// The specific TLS protocol used. (e.g. `TLS 1.3`).
+    pub protocol: Option<String>,
+    /// Common Name component of the Subject field from the certificate.
+    pub subject_name: Option<String>,
+    /// Unix timestamp (in seconds) specifying when this cert becomes valid.
+    pub valid_from: Option<f64>,
+    /// Unix timestamp (in seconds) specifying when this cert becomes invalid.
+    pub valid_to: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteAddress {
+    /// IPv4 or IPV6 address of the server.
+    pub ip_address: String,
+    pub port: u16,
+}
+
+/// Argument that is passed to an evaluated function as `undefined`, for calls that take no argument.
+///
+/// ```ignore
+/// let title: String = page.evaluate_expression("() => document.title", NoArg).await?;
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct NoArg;
+
+/// Script passed to [`Frame::evaluate_source`](crate::api::Frame::evaluate_source).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalSource {
+    Expression(String),
+    /// A file whose content is read when the script is evaluated. Relative paths are resolved against the current
+    /// directory of the process.
+    File(PathBuf),
+}
+
+impl EvalSource {
+    pub(crate) async fn into_expression(self) -> Result<String, crate::Error> {
+        match self {
+            Self::Expression(x) => Ok(x),
+            Self::File(path) => Ok(fs::read_to_string(path).await?),
+        }
+    }
+}
+
+/// `acceptDownloads` is `"accept"` or `"deny"` on the wire
+pub(crate) fn serialize_accept_downloads<S>(
+    x: &Option<bool>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match x {
+        Some(true) => serializer.serialize_str("accept"),
+        Some(false) => serializer.serialize_str("deny"),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Argument of `updateSubscription`
+#[derive(Serialize)]
+pub(crate) struct UpdateSubscriptionArgs<'a> {
+    pub(crate) event: &'a str,
+    pub(crate) enabled: bool,
+}
@@ -7,8 +7,9 @@ extern crate serde_with;
 
 pub mod api;
 mod imp;
+pub mod utils;
 
-pub use crate::imp::core::{Driver, Error};
+pub use crate::imp::core::{Driver, DriverErrorKind, Error};
 pub use api::playwright::Playwright;
 
 #[doc(hidden)]
@@ -0,0 +1,8 @@
+use crate::imp::prelude::*;
+
+/// Waits for the given `ms` milliseconds on the current runtime.
+///
+/// Use [`Page::wait_for_timeout`](crate::api::Page::wait_for_timeout) when a page is available.
+pub async fn wait_for_timeout(ms: f64) {
+    sleep(Duration::from_millis(ms as u64)).await
+}
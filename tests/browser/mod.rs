@@ -3,10 +3,12 @@ use playwright::api::{Browser, BrowserType};
 
 pub async fn all(t: &BrowserType, which: Which) -> Browser {
     launch_close_browser(t).await;
+    disconnected_should_work(t, which).await;
     let b = launch(t).await;
     assert!(b.exists());
     version_should_work(&b, which);
     contexts_should_work(&b).await;
+    new_page_should_work(&b).await;
     b
 }
 
@@ -27,6 +29,45 @@ async fn launch_close_browser(t: &BrowserType) {
     assert!(!b1.exists());
 }
 
+async fn disconnected_should_work(t: &BrowserType, which: Which) {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+    async fn count_disconnected(b: &Browser) -> Arc<AtomicUsize> {
+        let fired = Arc::new(AtomicUsize::new(0));
+        let f = fired.clone();
+        b.on_disconnected(move || {
+            f.fetch_add(1, Ordering::SeqCst);
+        })
+        .await
+        .unwrap();
+        fired
+    }
+    // closed by the user
+    let b = launch(t).await;
+    assert!(b.is_connected());
+    let fired = count_disconnected(&b).await;
+    b.close().await.unwrap();
+    playwright::utils::wait_for_timeout(1000.).await;
+    assert!(!b.is_connected());
+    assert_eq!(fired.load(Ordering::SeqCst), 1);
+    // the connection to the driver goes away
+    let pw = super::playwright_with_driver().await;
+    let t = match which {
+        Which::Webkit => pw.webkit(),
+        Which::Firefox => pw.firefox(),
+        Which::Chromium => pw.chromium(),
+    };
+    let b = launch(&t).await;
+    assert!(b.is_connected());
+    let fired = count_disconnected(&b).await;
+    drop(pw);
+    playwright::utils::wait_for_timeout(1000.).await;
+    assert!(!b.is_connected());
+    assert_eq!(fired.load(Ordering::SeqCst), 1);
+}
+
 // 'version should work'
 fn version_should_work(b: &Browser, which: Which) {
     let version = b.version().unwrap();
@@ -59,3 +100,16 @@ async fn contexts_should_work(b: &Browser) {
     context.close().await.unwrap();
     assert_eq!(b.contexts().unwrap().len(), len);
 }
+
+async fn new_page_should_work(b: &Browser) {
+    let len = b.contexts().unwrap().len();
+    let page = b.new_page().await.unwrap();
+    assert_eq!(b.contexts().unwrap().len(), len + 1);
+    let context = page.context();
+    assert_eq!(context.pages().unwrap(), vec![page.clone()]);
+    assert_eq!(page.eval::<i32>("() => 1 + 2").await.unwrap(), 3);
+    let other = context.new_page().await.unwrap();
+    assert_eq!(other.eval::<i32>("() => 1 + 2").await.unwrap(), 3);
+    page.close(None).await.unwrap();
+    assert_eq!(b.contexts().unwrap().len(), len);
+}
@@ -1,28 +1,51 @@
 use super::Which;
 use playwright::api::{
-    browser::RecordVideo, Browser, BrowserContext, BrowserType, Cookie, LocalStorageEntry,
-    OriginState, StorageState,
+    browser::RecordVideo, browser_context::RouteFromHarOptions, Browser, BrowserContext,
+    BrowserType, Cookie, CookieFilter, LocalStorageEntry, OriginState, Page, StorageState,
 };
 
 pub async fn all(
     browser: &Browser,
     persistent: &BrowserContext,
     port: u16,
-    _which: Which,
+    which: Which,
 ) -> BrowserContext {
     let c = launch(browser).await;
     assert_ne!(persistent, &c);
     assert!(c.browser().unwrap().is_some());
     storage_state(&c, port).await;
+    new_context_with_storage_state_should_work(browser, &c).await;
     set_offline_should_work(browser, port).await;
     set_timeout(&c).await;
+    default_timeout_should_work(browser).await;
+    device_scale_factor_should_work(browser).await;
+    screen_should_work(browser).await;
+    user_agent_should_work(&c, port).await;
+    color_scheme_should_work(browser).await;
+    http_credentials_should_work(browser, port).await;
+    #[cfg(any(feature = "rt-tokio", feature = "rt-actix"))]
+    ignore_https_errors_should_work(browser).await;
     cookies_should_work(&c).await;
+    clear_cookies_filter_should_work(&c).await;
     add_init_script_should_work(&c).await;
     pages_should_work(&c).await;
+    pages_should_track_close(browser).await;
+    request_failed_should_work(&c, port).await;
+    on_console_should_work(browser).await;
+    on_close_should_work(browser).await;
+    close_with_options_should_work(browser).await;
+    route_from_har_should_work(browser, port).await;
+    page_route_from_har_should_work(browser, port).await;
+    service_workers_block_should_work(browser, port).await;
+    if which == Which::Chromium {
+        on_service_worker_should_work(browser, port).await;
+    }
+    new_cdp_session_should_work(browser, port, which).await;
     c
 }
 
-pub async fn persistent(t: &BrowserType, _port: u16, which: Which) -> BrowserContext {
+pub async fn persistent(t: &BrowserType, port: u16, which: Which) -> BrowserContext {
+    downloads_path_should_work(t, port).await;
     let c = launch_persistent_context(t).await;
     if Which::Firefox != which {
         // XXX: launch with permissions not work on firefox
@@ -83,9 +106,214 @@ async fn pages_should_work(c: &BrowserContext) {
     assert_eq!(c.pages().unwrap().len(), len);
 }
 
+async fn pages_should_track_close(browser: &Browser) {
+    let c = browser.context_builder().build().await.unwrap();
+    let mut pages = Vec::new();
+    for _ in 0..3 {
+        pages.push(c.new_page().await.unwrap());
+    }
+    let listed = c.pages().unwrap();
+    assert_eq!(listed.len(), 3);
+    assert!(pages.iter().all(|p| listed.contains(p)));
+    pages[1].close(None).await.unwrap();
+    let listed = c.pages().unwrap();
+    assert_eq!(listed.len(), 2);
+    assert!(!listed.contains(&pages[1]));
+    c.close().await.unwrap();
+}
+
 async fn set_timeout(c: &BrowserContext) {
-    c.set_default_navigation_timeout(10000).await.unwrap();
-    c.set_default_timeout(10000).await.unwrap();
+    c.set_default_navigation_timeout(10000.).await.unwrap();
+    c.set_default_timeout(10000.).await.unwrap();
+}
+
+async fn default_timeout_should_work(b: &Browser) {
+    let c = b.context_builder().build().await.unwrap();
+    c.set_default_timeout(500.).await.unwrap();
+    let page = c.new_page().await.unwrap();
+    let start = std::time::Instant::now();
+    let result = page
+        .wait_for_selector_builder("#nonexistent")
+        .wait_for_selector()
+        .await;
+    assert!(result.is_err());
+    assert!(start.elapsed() < std::time::Duration::from_secs(1));
+    c.close().await.unwrap();
+}
+
+async fn device_scale_factor_should_work(b: &Browser) {
+    let c = b
+        .context_builder()
+        .device_scale_factor(2.)
+        .is_mobile(false)
+        .has_touch(true)
+        .build()
+        .await
+        .unwrap();
+    let page = c.new_page().await.unwrap();
+    let ratio: f64 = page.eval("() => window.devicePixelRatio").await.unwrap();
+    assert_eq!(ratio, 2.);
+    let touch: bool = page.eval("() => 'ontouchstart' in window").await.unwrap();
+    assert!(touch);
+    c.close().await.unwrap();
+}
+
+async fn screen_should_work(b: &Browser) {
+    let c = b
+        .context_builder()
+        .with_screen(1920, 1080)
+        .device_scale_factor(2.)
+        .is_mobile(false)
+        .build()
+        .await
+        .unwrap();
+    let page = c.new_page().await.unwrap();
+    let screen: (i32, i32) = page
+        .eval("() => [window.screen.width, window.screen.height]")
+        .await
+        .unwrap();
+    assert_eq!(screen, (1920, 1080));
+    c.close().await.unwrap();
+}
+
+async fn user_agent_should_work(c: &BrowserContext, port: u16) {
+    let page = c.new_page().await.unwrap();
+    page.goto_builder(&super::url_static(port, "/empty.html"))
+        .goto()
+        .await
+        .unwrap();
+    let ua: String = page.eval("() => navigator.userAgent").await.unwrap();
+    assert_eq!(ua, "asdf");
+    page.close(None).await.unwrap();
+}
+
+async fn color_scheme_should_work(b: &Browser) {
+    use playwright::api::ColorScheme;
+    let c = b
+        .context_builder()
+        .color_scheme(ColorScheme::Dark)
+        .build()
+        .await
+        .unwrap();
+    let page = c.new_page().await.unwrap();
+    page.set_content_builder(
+        "<style>@media (prefers-color-scheme: dark) { body { color: rgb(255, 0, 0); } }</style>",
+    )
+    .set_content()
+    .await
+    .unwrap();
+    let color = || async {
+        page.eval::<String>("() => getComputedStyle(document.body).color")
+            .await
+            .unwrap()
+    };
+    assert_eq!(color().await, "rgb(255, 0, 0)");
+    page.emulate_media_builder()
+        .color_scheme(ColorScheme::Light)
+        .emulate_media()
+        .await
+        .unwrap();
+    assert_ne!(color().await, "rgb(255, 0, 0)");
+    page.emulate_media_builder()
+        .color_scheme(ColorScheme::NoPreference)
+        .emulate_media()
+        .await
+        .unwrap();
+    assert_ne!(color().await, "rgb(255, 0, 0)");
+    c.close().await.unwrap();
+}
+
+async fn http_credentials_should_work(b: &Browser, port: u16) {
+    use playwright::api::HttpCredentials;
+    let url = super::url_auth(port);
+    let status = |c: BrowserContext| {
+        let url = url.clone();
+        async move {
+            let page = c.new_page().await.unwrap();
+            let response = page.goto_builder(&url).goto().await.unwrap().unwrap();
+            let status = response.status().unwrap();
+            c.close().await.unwrap();
+            status
+        }
+    };
+    let c = b
+        .context_builder()
+        .with_http_credentials("user", "pass")
+        .build()
+        .await
+        .unwrap();
+    assert_eq!(status(c).await, 200);
+    let wrong = HttpCredentials::new("user", "wrong");
+    let c = b
+        .context_builder()
+        .http_credentials(&wrong)
+        .build()
+        .await
+        .unwrap();
+    assert_eq!(status(c).await, 401);
+    let other_origin = HttpCredentials::new("user", "pass").with_origin("https://example.com");
+    let c = b
+        .context_builder()
+        .http_credentials(&other_origin)
+        .build()
+        .await
+        .unwrap();
+    assert_eq!(status(c).await, 401);
+}
+
+#[cfg(any(feature = "rt-tokio", feature = "rt-actix"))]
+async fn ignore_https_errors_should_work(b: &Browser) {
+    let port = super::free_local_port().unwrap();
+    super::start_https_test_server(port).await;
+    let url = format!("https://localhost:{}/static/empty.html", port);
+    let c = b.context_builder().build().await.unwrap();
+    let page = c.new_page().await.unwrap();
+    assert!(page.goto_builder(&url).goto().await.is_err());
+    c.close().await.unwrap();
+    let c = b
+        .context_builder()
+        .ignore_https_errors(true)
+        .build()
+        .await
+        .unwrap();
+    let page = c.new_page().await.unwrap();
+    let response = page.goto_builder(&url).goto().await.unwrap().unwrap();
+    assert!(response.ok().unwrap());
+    assert_eq!(page.url().unwrap(), url);
+    c.close().await.unwrap();
+}
+
+async fn downloads_path_should_work(t: &BrowserType, port: u16) {
+    use playwright::api::page;
+    let dir = super::temp_dir().join("downloads");
+    let _ = std::fs::remove_dir_all(&dir);
+    let user_data_dir = super::temp_dir().join("downloads-profile");
+    let c = t
+        .persistent_context_launcher(&user_data_dir)
+        .with_accept_downloads(dir.clone())
+        .launch()
+        .await
+        .unwrap();
+    let p = c.new_page().await.unwrap();
+    p.set_content_builder(&format!(
+        r#"<a href="{}">download</a>"#,
+        super::url_download(port, "/worker.html")
+    ))
+    .set_content()
+    .await
+    .unwrap();
+    let (d, _) = tokio::join!(
+        p.expect_event(page::EventType::Download),
+        p.click_builder("a").click()
+    );
+    let download = match d.unwrap() {
+        page::Event::Download(d) => d,
+        _ => unreachable!(),
+    };
+    let path = download.path().await.unwrap().unwrap();
+    assert!(path.starts_with(&dir));
+    assert!(path.is_file());
+    c.close().await.unwrap();
 }
 
 async fn cookies_should_work(c: &BrowserContext) {
@@ -109,6 +337,52 @@ async fn cookies_should_work(c: &BrowserContext) {
     ensure_cookies_are_cleared(c).await;
 }
 
+async fn clear_cookies_filter_should_work(c: &BrowserContext) {
+    ensure_cookies_are_cleared(c).await;
+    c.add_cookies(&[
+        Cookie::with_domain_path("a", "1", "example.com", "/"),
+        Cookie::with_domain_path("b", "2", "example.org", "/"),
+        Cookie::with_domain_path("c", "3", "example.net", "/"),
+    ])
+    .await
+    .unwrap();
+    // browsers may store the domain with a leading dot, and the filter compares it exactly
+    let domain = c
+        .cookies(&[])
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|c| c.name == "b")
+        .and_then(|c| c.domain)
+        .unwrap();
+    assert!(domain.ends_with("example.org"));
+    c.clear_cookies_filter(CookieFilter {
+        domain: Some(domain),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    let mut names: Vec<String> = c
+        .cookies(&[])
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|c| c.name)
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["a".to_owned(), "c".to_owned()]);
+    c.clear_cookies_filter(CookieFilter {
+        name: Some("c".into()),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    let cookies = c.cookies(&[]).await.unwrap();
+    assert_eq!(cookies.len(), 1);
+    assert_eq!(&cookies[0].name, "a");
+    ensure_cookies_are_cleared(c).await;
+}
+
 async fn ensure_cookies_are_cleared(c: &BrowserContext) {
     c.clear_cookies().await.unwrap();
     let cs = c.cookies(&[]).await.unwrap();
@@ -135,11 +409,24 @@ async fn get_permission(c: &BrowserContext, name: &str) -> String {
 }
 
 async fn add_init_script_should_work(c: &BrowserContext) {
-    c.add_init_script("HOGE = 2").await.unwrap();
-    let p = c.new_page().await.unwrap();
-    let x: i32 = p.eval("() => HOGE").await.unwrap();
-    assert_eq!(x, 2);
-    p.close(None).await.unwrap();
+    c.add_init_script("HOGE = 2", None).await.unwrap();
+    let path = super::temp_dir().join("init_script.js");
+    std::fs::write(&path, "window.ORDER = ['context']").unwrap();
+    c.add_init_script("", Some(&path)).await.unwrap();
+    let p1 = c.new_page().await.unwrap();
+    let p2 = c.new_page().await.unwrap();
+    p2.add_init_script("window.ORDER.push('page')")
+        .await
+        .unwrap();
+    p2.reload_builder().reload().await.unwrap();
+    for p in &[&p1, &p2] {
+        let x: i32 = p.eval("() => HOGE").await.unwrap();
+        assert_eq!(x, 2);
+    }
+    let order: Vec<String> = p2.eval("() => window.ORDER").await.unwrap();
+    assert_eq!(order, vec!["context", "page"]);
+    p1.close(None).await.unwrap();
+    p2.close(None).await.unwrap();
 }
 
 async fn set_offline_should_work(browser: &Browser, port: u16) {
@@ -156,9 +443,33 @@ async fn set_offline_should_work(browser: &Browser, port: u16) {
     c.set_offline(false).await.unwrap();
     let response = page.goto_builder(&url).goto().await.unwrap();
     assert_eq!(response.unwrap().status().unwrap(), 200);
+    c.set_offline(true).await.unwrap();
+    let other = super::url_static(port, "/form.html");
+    assert!(page.goto_builder(&other).goto().await.is_err());
+    c.set_offline(false).await.unwrap();
+    let response = page.goto_builder(&other).goto().await.unwrap();
+    assert_eq!(response.unwrap().status().unwrap(), 200);
     c.close().await.unwrap();
 }
 
+async fn new_context_with_storage_state_should_work(browser: &Browser, c: &BrowserContext) {
+    let path = super::temp_dir().join("storage_state.json");
+    let state = c.storage_state().await.unwrap();
+    std::fs::write(&path, serde_json::to_vec(&state).unwrap()).unwrap();
+    let restored = browser.new_context_with_storage_state(&path).await.unwrap();
+    let cookies = restored.cookies(&[]).await.unwrap();
+    assert!(cookies
+        .iter()
+        .any(|c| c.name == "name1" && c.value == "value1"));
+    restored.close().await.unwrap();
+    let missing = super::temp_dir().join("missing_storage_state.json");
+    let err = browser
+        .new_context_with_storage_state(&missing)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, playwright::Error::Io(_)));
+}
+
 async fn storage_state(c: &BrowserContext, port: u16) {
     let page = c.new_page().await.unwrap();
     let url = super::url_static(port, "/empty.html");
@@ -192,3 +503,301 @@ async fn storage_state(c: &BrowserContext, port: u16) {
         ]
     );
 }
+
+async fn request_failed_should_work(c: &BrowserContext, port: u16) {
+    use std::sync::{Arc, Mutex};
+    let failures = Arc::new(Mutex::new(Vec::new()));
+    let finished = Arc::new(Mutex::new(Vec::new()));
+    {
+        let failures = failures.clone();
+        c.on_request_failed(move |req| {
+            failures.lock().unwrap().push(req.failure().unwrap());
+        })
+        .await
+        .unwrap();
+        let finished = finished.clone();
+        c.on_request_finished(move |req| {
+            finished.lock().unwrap().push(req.url().unwrap());
+        })
+        .await
+        .unwrap();
+    }
+    c.route("**/empty2.html", |route| {
+        super::spawn(async move { route.abort(None).await.unwrap() });
+    })
+    .await
+    .unwrap();
+    let page = c.new_page().await.unwrap();
+    let url = super::url_static(port, "/empty.html");
+    page.goto_builder(&url).goto().await.unwrap();
+    let err = page
+        .goto_builder(&super::url_static(port, "/empty2.html"))
+        .goto()
+        .await;
+    assert!(err.is_err());
+    playwright::utils::wait_for_timeout(100.).await;
+    assert!(finished.lock().unwrap().contains(&url));
+    let failures = failures.lock().unwrap();
+    assert_eq!(failures.len(), 1);
+    assert!(failures[0]
+        .as_deref()
+        .map(|s| !s.is_empty())
+        .unwrap_or_default());
+    drop(failures);
+    c.unroute("**/empty2.html").await.unwrap();
+    page.close(None).await.unwrap();
+}
+
+async fn on_console_should_work(browser: &Browser) {
+    use std::sync::{Arc, Mutex};
+    let c = browser.context_builder().build().await.unwrap();
+    let messages = Arc::new(Mutex::new(Vec::new()));
+    {
+        let messages = messages.clone();
+        c.on_console(move |m| messages.lock().unwrap().push(m.text().unwrap()))
+            .await
+            .unwrap();
+    }
+    let p1 = c.new_page().await.unwrap();
+    let p2 = c.new_page().await.unwrap();
+    let () = p1.eval("() => console.log('a')").await.unwrap();
+    let () = p2.eval("() => console.log('b')").await.unwrap();
+    playwright::utils::wait_for_timeout(100.).await;
+    let mut messages = messages.lock().unwrap().clone();
+    messages.sort();
+    assert_eq!(messages, vec!["a".to_owned(), "b".to_owned()]);
+    c.close().await.unwrap();
+}
+
+async fn on_close_should_work(browser: &Browser) {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+    let c = browser.context_builder().build().await.unwrap();
+    let page = c.new_page().await.unwrap();
+    let page_closed = Arc::new(AtomicUsize::new(0));
+    let context_closed = Arc::new(AtomicUsize::new(0));
+    {
+        let page_closed = page_closed.clone();
+        let context_closed = context_closed.clone();
+        page.on_close(move || {
+            page_closed.fetch_add(1, Ordering::SeqCst);
+        })
+        .await
+        .unwrap();
+        c.on_close(move || {
+            context_closed.fetch_add(1, Ordering::SeqCst);
+        })
+        .await
+        .unwrap();
+    }
+    page.close(None).await.unwrap();
+    playwright::utils::wait_for_timeout(100.).await;
+    assert_eq!(page_closed.load(Ordering::SeqCst), 1);
+    assert_eq!(context_closed.load(Ordering::SeqCst), 0);
+    c.close().await.unwrap();
+    playwright::utils::wait_for_timeout(100.).await;
+    assert_eq!(page_closed.load(Ordering::SeqCst), 1);
+    assert_eq!(context_closed.load(Ordering::SeqCst), 1);
+}
+
+async fn close_with_options_should_work(browser: &Browser) {
+    use playwright::api::browser_context::ContextCloseOptions;
+    let c = browser.context_builder().build().await.unwrap();
+    let page = c.new_page().await.unwrap();
+    assert!(!c.is_closed());
+    assert!(browser.contexts().unwrap().contains(&c));
+    c.close_with_options(ContextCloseOptions {
+        reason: Some("test is over".into()),
+    })
+    .await
+    .unwrap();
+    assert!(c.is_closed());
+    assert!(page.is_closed());
+    assert!(c.pages().unwrap().is_empty());
+    assert!(!browser.contexts().unwrap().contains(&c));
+    assert!(page.title().await.is_err());
+    assert!(c.new_page().await.is_err());
+}
+
+async fn route_from_har_should_work(browser: &Browser, port: u16) {
+    let path = super::temp_dir().join("route_from_har.har");
+    let url = super::url_static(port, "/form.html");
+    let html = |p: Page| async move {
+        let s: String = p
+            .eval("() => document.documentElement.outerHTML")
+            .await
+            .unwrap();
+        s
+    };
+    let c = browser
+        .context_builder()
+        .with_record_har_path(&path)
+        .with_record_har_url_filter("**/static/**")
+        .build()
+        .await
+        .unwrap();
+    let p = c.new_page().await.unwrap();
+    p.goto_builder(&url).goto().await.unwrap();
+    let recorded = html(p).await;
+    c.close().await.unwrap();
+    assert!(path.is_file());
+
+    let c = browser.context_builder().build().await.unwrap();
+    c.route_from_har(&path, RouteFromHarOptions::default())
+        .await
+        .unwrap();
+    c.set_offline(true).await.unwrap();
+    let p = c.new_page().await.unwrap();
+    p.goto_builder(&url).goto().await.unwrap();
+    assert_eq!(html(p.clone()).await, recorded);
+    // not in the HAR
+    let missing = super::url_static(port, "/empty2.html");
+    assert!(p.goto_builder(&missing).goto().await.is_err());
+    c.close().await.unwrap();
+}
+
+/// Writes a HAR with a single `text/html` entry for `url`.
+fn write_har(name: &str, url: &str, body: &str) -> std::path::PathBuf {
+    let har = serde_json::json!({
+        "log": {
+            "version": "1.2",
+            "creator": { "name": "playwright-rust", "version": "0" },
+            "entries": [{
+                "startedDateTime": "2021-01-01T00:00:00.000Z",
+                "time": 0,
+                "request": {
+                    "method": "GET", "url": url, "httpVersion": "HTTP/1.1", "cookies": [],
+                    "headers": [], "queryString": [], "headersSize": -1, "bodySize": 0
+                },
+                "response": {
+                    "status": 200, "statusText": "OK", "httpVersion": "HTTP/1.1", "cookies": [],
+                    "headers": [{ "name": "content-type", "value": "text/html" }],
+                    "content": { "size": body.len(), "mimeType": "text/html", "text": body },
+                    "redirectURL": "", "headersSize": -1, "bodySize": body.len()
+                },
+                "cache": {},
+                "timings": { "send": 0, "wait": 0, "receive": 0 }
+            }]
+        }
+    });
+    let path = super::temp_dir().join(name);
+    std::fs::write(&path, har.to_string()).unwrap();
+    path
+}
+
+async fn page_route_from_har_should_work(browser: &Browser, port: u16) {
+    let url = format!("{}/har.html", super::origin(port));
+    let body = |p: Page| {
+        let url = url.clone();
+        async move {
+            p.goto_builder(&url).goto().await.unwrap();
+            let s: String = p.eval("() => document.body.textContent").await.unwrap();
+            s
+        }
+    };
+    let c = browser.context_builder().build().await.unwrap();
+    c.route_from_har(
+        &write_har("context.har", &url, "context"),
+        Default::default(),
+    )
+    .await
+    .unwrap();
+    let p1 = c.new_page().await.unwrap();
+    p1.route_from_har(&write_har("page1.har", &url, "page1"), Default::default())
+        .await
+        .unwrap();
+    let p2 = c.new_page().await.unwrap();
+    p2.route_from_har(&write_har("page2.har", &url, "page2"), Default::default())
+        .await
+        .unwrap();
+    let p3 = c.new_page().await.unwrap();
+    assert_eq!(body(p1).await, "page1");
+    assert_eq!(body(p2).await, "page2");
+    assert_eq!(body(p3).await, "context");
+    c.close().await.unwrap();
+}
+
+async fn service_workers_block_should_work(browser: &Browser, port: u16) {
+    use playwright::api::ServiceWorkerPolicy;
+    let c = browser
+        .context_builder()
+        .service_workers(ServiceWorkerPolicy::Block)
+        .build()
+        .await
+        .unwrap();
+    let page = c.new_page().await.unwrap();
+    let url = super::url_static(port, "/sw.html");
+    page.goto_builder(&url).goto().await.unwrap();
+    let () = page
+        .eval("async () => { await window.registration.catch(() => {}); }")
+        .await
+        .unwrap();
+    page.reload_builder().reload().await.unwrap();
+    assert!(c.service_workers().unwrap().is_empty());
+    c.close().await.unwrap();
+}
+
+async fn on_service_worker_should_work(browser: &Browser, port: u16) {
+    use playwright::api::ServiceWorker;
+    use std::sync::{Arc, Mutex};
+    let c = browser.context_builder().build().await.unwrap();
+    let workers: Arc<Mutex<Vec<ServiceWorker>>> = Arc::default();
+    {
+        let workers = workers.clone();
+        c.on_service_worker(move |w| workers.lock().unwrap().push(w))
+            .await
+            .unwrap();
+    }
+    let page = c.new_page().await.unwrap();
+    page.goto_builder(&super::url_static(port, "/sw.html"))
+        .goto()
+        .await
+        .unwrap();
+    let () = page
+        .eval("async () => { await window.registration; }")
+        .await
+        .unwrap();
+    for _ in 0..50 {
+        if !workers.lock().unwrap().is_empty() {
+            break;
+        }
+        playwright::utils::wait_for_timeout(100.).await;
+    }
+    let worker = workers.lock().unwrap().first().cloned().unwrap();
+    assert_eq!(worker.url().unwrap(), super::url_static(port, "/sw.js"));
+    assert_eq!(c.service_workers().unwrap(), vec![worker.clone()]);
+    let two: i32 = worker.evaluate("x => x + 1", 1).await.unwrap();
+    assert_eq!(two, 2);
+    c.close().await.unwrap();
+}
+
+async fn new_cdp_session_should_work(browser: &Browser, port: u16, which: Which) {
+    use playwright::{api::Cookie, Error};
+    let c = browser.context_builder().build().await.unwrap();
+    let page = c.new_page().await.unwrap();
+    if which != Which::Chromium {
+        let r = c.new_cdp_session(&page).await;
+        assert!(matches!(r, Err(Error::NotSupported { .. })), "{:?}", r);
+        c.close().await.unwrap();
+        return;
+    }
+    let origin = format!("http://localhost:{}", port);
+    let url = format!("{}/", origin);
+    c.add_cookies(&[Cookie::with_url("foo", "bar", url.as_str())])
+        .await
+        .unwrap();
+    assert_eq!(c.cookies(&[]).await.unwrap().len(), 1);
+    let session = c.new_cdp_session(&page).await.unwrap();
+    session
+        .send(
+            "Storage.clearDataForOrigin",
+            Some(serde_json::json!({ "origin": origin, "storageTypes": "cookies" })),
+        )
+        .await
+        .unwrap();
+    assert!(c.cookies(&[]).await.unwrap().is_empty());
+    session.detach().await.unwrap();
+    c.close().await.unwrap();
+}
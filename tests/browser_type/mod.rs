@@ -11,6 +11,8 @@ pub async fn all(playwright: &Playwright, which: Which) -> BrowserType {
     executable_should_exist(&t);
     should_handle_timeout(&t).await;
     should_fire_close(&t).await;
+    executable_path_should_work(&t).await;
+    args_should_work(&t, which).await;
     t
 }
 
@@ -34,7 +36,7 @@ async fn should_handle_timeout(t: &BrowserType) {
     assert!(result.is_err());
     let err = result.err().unwrap();
     match &*err {
-        playwright::Error::ErrorResponded(_) => {}
+        playwright::Error::ProtocolError { method, .. } => assert_eq!(method, "launch"),
         e => {
             dbg!(e);
             unreachable!();
@@ -51,3 +53,47 @@ async fn should_fire_close(t: &BrowserType) {
     close.unwrap();
     assert_eq!(wait.unwrap(), Event::Close);
 }
+
+async fn executable_path_should_work(t: &BrowserType) {
+    let executable = t.executable().unwrap();
+    let browser = t.launcher().executable(&executable).launch().await.unwrap();
+    assert!(!browser.version().unwrap().is_empty());
+    browser.close().await.unwrap();
+}
+
+async fn args_should_work(t: &BrowserType, which: Which) {
+    let launched = t
+        .launcher()
+        .with_args(vec!["--disable-extensions".into()])
+        .arg("--allow-insecure-localhost")
+        .launch()
+        .await;
+    if which == Which::Chromium {
+        launched.unwrap().close().await.unwrap();
+        return;
+    }
+    let err = launched.err().unwrap();
+    assert!(
+        matches!(*err, playwright::Error::InvalidArgument { .. }),
+        "{:?}",
+        err
+    );
+    let flags = vec!["--disable-web-security".to_owned()];
+    let err = t
+        .persistent_context_launcher("./target".as_ref())
+        .args(&flags)
+        .launch()
+        .await
+        .err()
+        .unwrap();
+    assert!(
+        matches!(*err, playwright::Error::InvalidArgument { .. }),
+        "{:?}",
+        err
+    );
+    // other `--` flags are left for the browser to interpret
+    if which == Which::Firefox {
+        let b = t.launcher().arg("--headless").launch().await.unwrap();
+        b.close().await.unwrap();
+    }
+}
@@ -26,6 +26,8 @@ pub async fn all(c: &BrowserContext, port: u16, which: Which) {
     concurrent!(
         which,
         set_extra_http_headers(c, port),
+        goto_referer_should_work(c, port),
+        response_server_addr_should_work(c, port),
         focus_should_work(c),
         reload_should_worker(c),
         screenshot_should_work(&page),
@@ -37,13 +39,68 @@ pub async fn all(c: &BrowserContext, port: u16, which: Which) {
         workers_should_work(c, port, which),
         accessibility(c),
         query_selector_and_eval(c),
-        input(c)
+        input(c),
+        wait_for_function_should_work(c),
+        wait_for_timeout_should_work(c),
+        locator_get_by_role_should_work(c),
+        locator_get_by_text_should_work(c),
+        set_content_should_work(c),
+        wait_for_selector_strict_should_work(c),
+        wait_for_selector_state_should_work(c),
+        evaluate_expression_should_work(c),
+        locator_input_value_should_work(c),
+        locator_count_should_work(c),
+        locator_nth_should_work(c),
+        locator_with_options_should_work(c),
+        locator_with_options_in_frame_should_work(c),
+        locator_or_should_work(c),
+        frame_locator_should_work(c),
+        frame_get_by_should_work(c),
+        query_selector_strict_should_work(c),
+        bounding_box_should_work(c),
+        element_handle_evaluate_should_work(c),
+        evaluate_with_timeout_should_work(c),
+        locator_fill_should_select_option(c),
+        add_init_script_with_arg_should_work(c),
+        get_by_role_include_hidden_should_work(c),
+        locator_trial_should_work(c),
+        locator_hover_trial_should_work(c),
+        locator_hover_trial_should_not_open_dropdown(c),
+        locator_check_strict_should_work(c),
+        locator_strict_actions_should_work(c),
+        locator_inner_text_and_html_should_work(c),
+        locator_nth_out_of_bounds_should_fail(c),
+        locator_select_option_element_handles_should_work(c),
+        locator_select_all_should_work(c),
+        locator_input_values_should_work(c),
+        xpath_fallback_should_work(c),
+        locator_clone_should_outlive_original(c),
+        locator_client_side_fallbacks_should_work(c),
+        locator_press_should_wait_for_element(c),
+        locator_type_text_should_work(c),
+        evaluate_file_should_work(c),
+        screenshot_mask_should_work(c),
+        screenshot_full_page_max_height_should_work(c),
+        screenshot_animations_disabled_should_work(c),
+        screenshot_clip_locator_should_work(c),
+        protocol_error_should_name_method(c),
+        error_variants_should_work(c)
     );
-    // TODO
-    // file_chooser(c, port).await;
+    file_chooser(c, port).await;
+    route_should_work(c, port).await;
+    request_post_data_should_work(c, port).await;
+    unroute_all_should_work(c, port).await;
+    page_error_should_work(c, port).await;
+    frame_events_should_work(c, port).await;
+    close_run_before_unload_should_work(c).await;
     if which != Which::Firefox {
         pdf_should_work(&page).await;
     }
+    if which == Which::Chromium {
+        pdf_a_should_work(&page).await;
+        pdf_format_should_work(&page).await;
+        crash_should_work(c).await;
+    }
     video(&page).await;
     emulate_media(&page).await;
 }
@@ -56,6 +113,7 @@ macro_rules! done {
 
 async fn eq_context_close(c: &BrowserContext, p1: &Page) {
     let p2 = new(c).await;
+    assert!(!p2.is_closed());
     assert_ne!(p1, &p2);
     assert_eq!(&p1.context(), c);
     assert_eq!(&p2.context(), c);
@@ -81,6 +139,7 @@ async fn ensure_close(page: &Page) {
     );
     result.unwrap();
     assert!(received);
+    assert!(page.is_closed());
     match wait_result.unwrap() {
         page::Event::Close => (),
         _ => unreachable!(),
@@ -160,11 +219,12 @@ async fn navigations(page: &Page, port: u16) {
 }
 
 async fn set_timeout(page: &Page) {
-    page.set_default_navigation_timeout(10000).await.unwrap();
-    page.set_default_timeout(10000).await.unwrap();
+    page.set_default_navigation_timeout(10000.).await.unwrap();
+    page.set_default_timeout(10000.).await.unwrap();
 }
 
 async fn workers_should_work(c: &BrowserContext, port: u16, which: Which) {
+    use std::sync::{Arc, Mutex};
     let page = new(c).await;
     let url = super::url_static(port, "/worker.html");
     let js = super::url_static(port, "/worker.js");
@@ -190,15 +250,35 @@ async fn workers_should_work(c: &BrowserContext, port: u16, which: Which) {
             .unwrap(),
         "worker function result"
     );
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    {
+        let seen = seen.clone();
+        page.on_worker(page::OnWorkerOptions { immediate: true }, move |w| {
+            seen.lock().unwrap().push(w)
+        })
+        .await
+        .unwrap();
+    }
+    assert!(*seen.lock().unwrap() == workers());
     page.goto_builder(&empty).goto().await.unwrap();
     assert_eq!(workers().len(), 0);
+    assert_eq!(seen.lock().unwrap().len(), 1);
+    // a worker spawned after registering is reported exactly once
+    let (_, _) = tokio::join!(
+        page.expect_event(page::EventType::Worker),
+        page.goto_builder(&url).goto()
+    );
+    playwright::utils::wait_for_timeout(500.).await;
+    let seen = seen.lock().unwrap().clone();
+    assert_eq!(seen.len(), 2);
+    assert!(seen[1] == workers()[0]);
     close(&page).await;
 }
 
 async fn ensure_timeout(page: &Page) {
-    page.set_default_timeout(500).await.unwrap();
+    page.set_default_timeout(500.).await.unwrap();
     match page.expect_event(page::EventType::Load).await {
-        Err(playwright::Error::Timeout) => {}
+        Err(playwright::Error::Timeout { .. }) => {}
         _ => panic!("Not expected"),
     }
 }
@@ -267,6 +347,7 @@ async fn viewport(c: &BrowserContext) {
 }
 
 async fn download(c: &BrowserContext, port: u16) {
+    use playwright::api::Download;
     let p = new(c).await;
     p.set_content_builder(&format!(
         r#"<a href="{}">download</a>"#,
@@ -293,6 +374,22 @@ async fn download(c: &BrowserContext, port: u16) {
     let tmp = super::temp_dir().join(download.suggested_filename());
     download.save_as(tmp).await.unwrap();
     download.delete().await.unwrap();
+    let (d, _) = tokio::join!(
+        p.wait_for_event::<Download>(None, Some(5000.)),
+        p.click_builder("a").click()
+    );
+    assert_eq!(d.unwrap().url(), download.url());
+    let (d, _) = tokio::join!(
+        p.wait_for_event::<Download>(Some(&|_: &Download| false), Some(500.)),
+        p.click_builder("a").click()
+    );
+    assert!(matches!(d, Err(playwright::Error::Timeout { .. })));
+    let link = p.locator("a").unwrap();
+    let (d, ()) = done!(p.expect_event_with::<Download, _>(
+        async { link.click_builder().click().await.unwrap() },
+        Some(5000.)
+    ));
+    assert_eq!(d.url(), download.url());
     close(&p).await;
 }
 
@@ -308,7 +405,7 @@ async fn video(p: &Page) {
 
 async fn accessibility(c: &BrowserContext) {
     let p = new(c).await;
-    use playwright::api::accessibility::SnapshotResponse;
+    use playwright::api::accessibility::{AXNode, AccessibilitySnapshotOptions};
     let ac = &p.accessibility;
     p.set_content_builder(
         r#"<div>\
@@ -329,7 +426,7 @@ async fn accessibility(c: &BrowserContext) {
         .snapshot()
         .await
         .unwrap();
-    let input_response = Some(SnapshotResponse {
+    let input_response = Some(AXNode {
         role: "textbox".into(),
         name: "Empty input".into(),
         value: None,
@@ -349,8 +446,9 @@ async fn accessibility(c: &BrowserContext) {
         checked: None,
         pressed: None,
         level: None,
-        valuemin: None,
-        valuemax: None,
+        value_min: None,
+        value_max: None,
+        value_now: None,
         autocomplete: None,
         haspopup: None,
         invalid: None,
@@ -368,6 +466,35 @@ async fn accessibility(c: &BrowserContext) {
         .await
         .unwrap();
     assert_ne!(snapshot, input_response);
+    p.set_content_builder(
+        r#"<form>
+            <label for="name">Name</label><input id="name" />
+            <label for="email">Email</label><input id="email" />
+            <label><input type="checkbox" checked /> Subscribe</label>
+        </form>"#,
+    )
+    .set_content()
+    .await
+    .unwrap();
+    let snapshot = p
+        .accessibility()
+        .snapshot(AccessibilitySnapshotOptions::default())
+        .await
+        .unwrap()
+        .unwrap();
+    fn flatten(node: &AXNode, acc: &mut Vec<(String, String)>) {
+        acc.push((node.role.clone(), node.name.clone()));
+        node.children.iter().for_each(|c| flatten(c, acc));
+    }
+    let mut nodes = Vec::new();
+    flatten(&snapshot, &mut nodes);
+    for (role, name) in &[
+        ("textbox", "Name"),
+        ("textbox", "Email"),
+        ("checkbox", "Subscribe"),
+    ] {
+        assert!(nodes.contains(&(role.to_string(), name.to_string())));
+    }
     close(&p).await;
 }
 
@@ -382,6 +509,27 @@ async fn screenshot_should_work(p: &Page) {
         .await
         .unwrap();
     assert!(path.is_file());
+    let viewport = p.viewport_size().unwrap().unwrap();
+    let jpeg = p
+        .screenshot_builder()
+        .r#type(ScreenshotType::Jpeg)
+        .screenshot()
+        .await
+        .unwrap();
+    assert_eq!(jpeg.format, ScreenshotType::Jpeg);
+    assert_eq!(
+        (jpeg.width, jpeg.height),
+        (Some(viewport.width as u32), Some(viewport.height as u32))
+    );
+    let png = p.screenshot_builder().screenshot().await.unwrap();
+    assert_eq!(png.format, ScreenshotType::Png);
+    assert_eq!(
+        (png.width, png.height),
+        (Some(viewport.width as u32), Some(viewport.height as u32))
+    );
+    let copy = super::temp_dir().join("screenshot_copy.png");
+    png.save_to(&copy).unwrap();
+    assert_eq!(std::fs::read(&copy).unwrap(), png.bytes);
 }
 
 async fn pdf_should_work(p: &Page) {
@@ -390,6 +538,63 @@ async fn pdf_should_work(p: &Page) {
     assert!(path.is_file());
 }
 
+async fn pdf_a_should_work(p: &Page) {
+    let path = super::temp_dir().join("pdf_a.pdf");
+    p.pdf_builder()
+        .path(path.clone())
+        .pdf_a(true)
+        .pdf()
+        .await
+        .unwrap();
+    let bytes = std::fs::read(&path).unwrap();
+    assert!(bytes.starts_with(b"%PDF-1.4"));
+    let text = String::from_utf8_lossy(&bytes);
+    assert!(text.contains("<pdfaid:part>1</pdfaid:part>"));
+    assert!(text.contains("/GTS_PDFA1"));
+    let trailer = &text[text.rfind("trailer").unwrap()..];
+    assert!(trailer.contains("/ID [<"));
+    assert!(trailer.contains("/Info "));
+    // the producer Chromium recorded is mirrored into the XMP metadata
+    let producer = text
+        .split("<pdf:Producer>")
+        .nth(1)
+        .and_then(|s| s.split("</pdf:Producer>").next())
+        .unwrap();
+    assert!(text.contains(&format!("/Producer ({})", producer)));
+}
+
+async fn pdf_format_should_work(p: &Page) {
+    use playwright::api::PaperFormat;
+    fn media_box(path: &std::path::Path) -> (f64, f64) {
+        let bytes = std::fs::read(path).unwrap();
+        let text = String::from_utf8_lossy(&bytes);
+        let start = text.find("/MediaBox [").unwrap() + "/MediaBox [".len();
+        let end = start + text[start..].find(']').unwrap();
+        let v: Vec<f64> = text[start..end]
+            .split_whitespace()
+            .map(|x| x.parse().unwrap())
+            .collect();
+        ((v[2] - v[0]).round(), (v[3] - v[1]).round())
+    }
+    let path = super::temp_dir().join("pdf_a4.pdf");
+    p.pdf_builder()
+        .path(path.clone())
+        .format(PaperFormat::A4)
+        .pdf()
+        .await
+        .unwrap();
+    assert_eq!(media_box(&path), (595., 842.));
+    let path = super::temp_dir().join("pdf_a4_wide.pdf");
+    p.pdf_builder()
+        .path(path.clone())
+        .format(PaperFormat::A4)
+        .width("11.7in".into())
+        .pdf()
+        .await
+        .unwrap();
+    assert_eq!(media_box(&path), (842., 842.));
+}
+
 async fn emulate_media(p: &Page) {
     use playwright::api::page::Media;
     let screen = || async {
@@ -493,6 +698,725 @@ async fn input(c: &BrowserContext) {
     close(&p).await;
 }
 
+async fn wait_for_function_should_work(c: &BrowserContext) {
+    use playwright::api::frame::WaitForFunctionOptions;
+    let p = new(c).await;
+    done!(p.eval::<()>("() => { setTimeout(() => { window.ready = true; }, 200); }"));
+    let options = WaitForFunctionOptions {
+        timeout: Some(5000.),
+        ..Default::default()
+    };
+    let mut handle = done!(p.wait_for_function("() => window.ready", None::<()>, options));
+    assert!(handle.json_value::<bool>().await.unwrap());
+    close(&p).await;
+}
+
+async fn wait_for_timeout_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.wait_for_timeout(100.));
+    playwright::utils::wait_for_timeout(100.).await;
+    let f = p.main_frame();
+    done!(f.wait_for_timeout(100.));
+    let (waited, frame_waited, _) = tokio::join!(
+        p.wait_for_timeout(10000.),
+        f.wait_for_timeout(10000.),
+        p.close(None)
+    );
+    match waited {
+        Err(playwright::Error::PageClosed) => {}
+        _ => panic!("Not expected"),
+    }
+    match frame_waited {
+        Err(playwright::Error::PageClosed) => {}
+        _ => panic!("Not expected"),
+    }
+}
+
+async fn set_content_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content("<button id='x'>ok</button>", Default::default()));
+    assert!(done!(p.content()).contains("<button id=\"x\">ok</button>"));
+    let () = done!(p.eval(
+        "() => document.getElementById('x').addEventListener('click', () => window.clicked = true)"
+    ));
+    done!(p.locator("#x").unwrap().click_builder().click());
+    assert!(done!(p.eval::<bool>("() => window.clicked === true")));
+    close(&p).await;
+}
+
+async fn wait_for_selector_strict_should_work(c: &BrowserContext) {
+    use playwright::api::frame::WaitForSelectorOptions;
+    let p = new(c).await;
+    done!(p.set_content("<button>a</button><button>b</button>", Default::default()));
+    let first = done!(p.wait_for_selector("button", Default::default()));
+    assert!(first.is_some());
+    let options = WaitForSelectorOptions {
+        strict: Some(true),
+        ..Default::default()
+    };
+    match p.wait_for_selector("button", options).await {
+        Err(playwright::Error::AmbiguousMatch { selector, count }) => {
+            assert_eq!(selector, "button");
+            assert_eq!(count, 2);
+        }
+        x => panic!("expected AmbiguousMatch, got {:?}", x.map(|x| x.is_some())),
+    }
+    close(&p).await;
+}
+
+async fn wait_for_selector_state_should_work(c: &BrowserContext) {
+    use playwright::api::frame::{FrameState, WaitForSelectorOptions};
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<div id="hidden" style="display: none">hidden</div><div id="doomed">doomed</div>"#,
+        Default::default()
+    ));
+    let wait = |state| WaitForSelectorOptions {
+        state: Some(state),
+        timeout: Some(5000.),
+        ..Default::default()
+    };
+    let () = done!(p.eval(
+        r#"() => {
+            setTimeout(() => document.getElementById('hidden').style.display = 'block', 100);
+        }"#
+    ));
+    let shown = done!(p.wait_for_selector("#hidden", wait(FrameState::Visible)));
+    assert!(shown.is_some());
+    let () = done!(p.eval(
+        r#"() => {
+            setTimeout(() => document.getElementById('doomed').remove(), 100);
+        }"#
+    ));
+    let removed = done!(p.wait_for_selector("#doomed", wait(FrameState::Detached)));
+    assert!(removed.is_none());
+    let () = done!(p.eval(
+        r#"() => {
+            setTimeout(() => document.getElementById('hidden').style.display = 'none', 100);
+        }"#
+    ));
+    let hidden = done!(p.wait_for_selector("#hidden", wait(FrameState::Hidden)));
+    assert!(hidden.is_none());
+    close(&p).await;
+}
+
+async fn evaluate_expression_should_work(c: &BrowserContext) {
+    use playwright::api::NoArg;
+    let p = new(c).await;
+    let sum: i32 = done!(p.evaluate_expression("([a, b]) => a + b", (1, 2)));
+    assert_eq!(sum, 3);
+    let undefined: bool = done!(p.evaluate_expression("x => x === undefined", NoArg));
+    assert!(undefined);
+    let title: String = done!(p.eval_script("() => 'a' + 'b'"));
+    assert_eq!(title, "ab");
+    let frame_sum: i32 = done!(p.main_frame().eval_script("() => 40 + 2"));
+    assert_eq!(frame_sum, 42);
+    assert_eq!(done!(p.eval_value("1 + 1")), serde_json::json!(2));
+    assert_eq!(
+        done!(p.eval_value("() => ({ a: [1, 'b'], c: null })")),
+        serde_json::json!({ "a": [1, "b"], "c": null })
+    );
+    assert_eq!(
+        done!(p.eval_value_with_arg("x => x * 2", 21)),
+        serde_json::json!(42)
+    );
+    close(&p).await;
+}
+
+async fn locator_get_by_role_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    p.set_content_builder(
+        r#"<a href="/">Logo</a>
+        <nav><a href="/home">Home</a><a href="/about">About</a></nav>
+        <footer><a href="/contact">Contact</a></footer>"#,
+    )
+    .set_content()
+    .await
+    .unwrap();
+    let nav = p.locator("nav").unwrap();
+    let links = nav.get_by_role("link", page::GetByRoleOptions::default());
+    assert_eq!(done!(links.count()), 2);
+    let home = nav.get_by_role(
+        "link",
+        page::GetByRoleOptions {
+            name: Some("home".into()),
+            ..Default::default()
+        },
+    );
+    assert_eq!(done!(home.count()), 1);
+    let contact = nav.get_by_role(
+        "link",
+        page::GetByRoleOptions {
+            name: Some("Contact".into()),
+            ..Default::default()
+        },
+    );
+    assert_eq!(done!(contact.count()), 0);
+    p.set_content_builder("<button>Submit</button><button>Cancel</button>")
+        .set_content()
+        .await
+        .unwrap();
+    let submit = p
+        .get_by_role(
+            "button",
+            page::GetByRoleOptions {
+                name: Some(page::RoleName::Regex("Submit|Send".into())),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(done!(submit.count()), 1);
+    assert_eq!(done!(submit.text_content(None)).as_deref(), Some("Submit"));
+    close(&p).await;
+}
+
+async fn locator_get_by_text_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    p.set_content_builder(
+        r#"<label for="newsletter">Email</label><input id="newsletter" />
+        <form>
+            <label for="email">Email</label><input id="email" value="in form" />
+            <input placeholder="Password" title="Secret" />
+            <img alt="Logo" /><span data-testid="note">Hello world</span>
+        </form>"#,
+    )
+    .set_content()
+    .await
+    .unwrap();
+    let form = p.locator("form").unwrap();
+    let email = form.get_by_label("Email", None);
+    assert_eq!(done!(email.count()), 1);
+    assert_eq!(done!(email.input_value(None)), "in form");
+    assert_eq!(done!(form.get_by_text("hello", None).count()), 1);
+    assert_eq!(done!(form.get_by_text("hello", Some(true)).count()), 0);
+    assert_eq!(done!(form.get_by_placeholder("Password", None).count()), 1);
+    assert_eq!(done!(form.get_by_title("Secret", Some(true)).count()), 1);
+    assert_eq!(done!(form.get_by_alt_text("logo", None).count()), 1);
+    assert_eq!(done!(form.get_by_test_id("note").count()), 1);
+    close(&p).await;
+}
+
+async fn locator_input_value_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<input id="i" value="text" />
+        <select id="s"><option value="a">A</option><option value="b" selected>B</option></select>
+        <textarea id="t">area</textarea>
+        <div id="d" contenteditable>editable</div>"#,
+        Default::default()
+    ));
+    let value = |selector: &'static str| {
+        let p = p.clone();
+        async move { p.locator(selector).unwrap().input_value(None).await }
+    };
+    assert_eq!(value("#i").await.unwrap(), "text");
+    assert_eq!(value("#s").await.unwrap(), "b");
+    assert_eq!(value("#t").await.unwrap(), "area");
+    done!(p.locator("#t").unwrap().fill_builder("changed").fill());
+    assert_eq!(value("#t").await.unwrap(), "changed");
+    // Like Playwright, inputValue throws for elements that are not form controls
+    assert!(value("#d").await.is_err());
+    close(&p).await;
+}
+
+async fn locator_count_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    let () = done!(p.eval(
+        "() => { document.body.innerHTML = '<ul>' + '<li>x</li>'.repeat(1000) + '</ul><p>x</p>'; }"
+    ));
+    assert_eq!(done!(p.locator("li").unwrap().count()), 1000);
+    assert_eq!(done!(p.locator("li, p").unwrap().count()), 1001);
+    assert_eq!(done!(p.locator("ol").unwrap().count()), 0);
+    close(&p).await;
+}
+
+async fn locator_nth_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<div><button>b0</button><a>a0</a></div><div><button>b1</button><a>a1</a></div>"#,
+        Default::default()
+    ));
+    let text = |selector: &'static str, index: i32| {
+        let p = p.clone();
+        async move {
+            let nth = done!(p.locator(selector).unwrap().nth(index));
+            done!(nth.text_content(None))
+        }
+    };
+    // same-tag list
+    assert_eq!(text("button", 1).await.as_deref(), Some("b1"));
+    // mixed-tag list is indexed in document order, not per tag
+    assert_eq!(text("button, a", 1).await.as_deref(), Some("a0"));
+    assert_eq!(text("button, a", 2).await.as_deref(), Some("b1"));
+    // first element
+    assert_eq!(text("a", 0).await.as_deref(), Some("a0"));
+    close(&p).await;
+}
+
+async fn locator_with_options_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<ul>
+            <li class="item">home <b>active</b></li>
+            <li class="item">about</li>
+            <li class="item">Active users</li>
+        </ul>"#,
+        Default::default()
+    ));
+    let active = p
+        .locator_with_options(
+            "li",
+            page::LocatorOptions {
+                has_text: Some("active".into()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(done!(active.count()), 2);
+    let inactive = p
+        .locator_with_options(
+            "li",
+            page::LocatorOptions {
+                has_not_text: Some("active".into()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(done!(inactive.text_content(None)).as_deref(), Some("about"));
+    let bold = p.locator("b").unwrap();
+    let with_bold = p
+        .locator_with_options(
+            "li",
+            page::LocatorOptions {
+                has: Some(&bold),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(done!(with_bold.count()), 1);
+    let without_bold = p
+        .locator_with_options(
+            "li",
+            page::LocatorOptions {
+                has_not: Some(&bold),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(done!(without_bold.count()), 2);
+    close(&p).await;
+}
+
+async fn bounding_box_should_work(c: &BrowserContext) {
+    use playwright::api::BoundingBox;
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<div style="position: absolute; left: 10px; top: 20px; width: 100px; height: 50px"></div>
+        <span style="display: none">hidden</span>"#,
+        Default::default()
+    ));
+    let div = done!(p.query_selector("div")).unwrap();
+    let bounding_box = done!(div.bounding_box());
+    assert_eq!(
+        bounding_box,
+        Some(BoundingBox {
+            x: 10.,
+            y: 20.,
+            width: 100.,
+            height: 50.
+        })
+    );
+    let hidden = done!(p.query_selector("span")).unwrap();
+    assert_eq!(done!(hidden.bounding_box()), None);
+    close(&p).await;
+}
+
+async fn locator_or_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<button>cancel</button>
+        <button class="cta-button">buy</button>
+        <a class="cta-button">more</a>
+        <iframe srcdoc="<button>inside</button>"></iframe>"#,
+        Default::default()
+    ));
+    let buttons = p.locator("button").unwrap();
+    let cta = p.locator(".cta-button").unwrap();
+    let either = buttons.or(&cta).unwrap();
+    assert_eq!(done!(either.count()), 3);
+    let both = p
+        .locator(".cta-button")
+        .unwrap()
+        .or(&p.locator("button.cta-button").unwrap())
+        .unwrap();
+    assert_eq!(done!(both.count()), 2);
+    let overlap = p
+        .locator("button >> text=buy")
+        .unwrap()
+        .or(&p.locator("button.cta-button").unwrap())
+        .unwrap();
+    assert_eq!(done!(overlap.count()), 1);
+    let frame = p.main_frame().child_frames().unwrap().remove(0);
+    let inside = frame.locator("button").unwrap();
+    assert!(matches!(
+        buttons.or(&inside),
+        Err(playwright::Error::InvalidArgument { .. })
+    ));
+    close(&p).await;
+}
+
+async fn locator_with_options_in_frame_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<li>outside active</li>
+        <iframe srcdoc="<ul><li>home active</li><li>about</li><li>settings active</li></ul>"></iframe>"#,
+        Default::default()
+    ));
+    let frame = p.main_frame().child_frames().unwrap().remove(0);
+    let active = frame
+        .locator_with_options(
+            "li",
+            page::LocatorOptions {
+                has_text: Some("active".into()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(done!(active.count()), 2);
+    assert_eq!(
+        done!(done!(active.last()).text_content(None)).as_deref(),
+        Some("settings active")
+    );
+    let outside = p.locator("li").unwrap();
+    let err = frame
+        .locator_with_options(
+            "ul",
+            page::LocatorOptions {
+                has: Some(&outside),
+                ..Default::default()
+            },
+        )
+        .err()
+        .unwrap();
+    assert!(matches!(err, playwright::Error::InvalidArgument { .. }));
+    close(&p).await;
+}
+
+async fn frame_locator_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<button type="submit">outside</button>
+        <iframe id="auth-widget" srcdoc="<button type=submit onclick=&quot;this.textContent = 'signed in'&quot;>sign in</button>"></iframe>"#,
+        Default::default()
+    ));
+    let submit = p
+        .frame_locator("iframe#auth-widget")
+        .locator("button[type=submit]");
+    assert_eq!(done!(submit.count()), 1);
+    done!(submit.click_builder().click());
+    assert_eq!(
+        done!(submit.text_content(None)).as_deref(),
+        Some("signed in")
+    );
+    let outside = p.locator("button[type=submit]").unwrap();
+    assert_eq!(
+        done!(outside.text_content(None)).as_deref(),
+        Some("outside")
+    );
+    close(&p).await;
+}
+
+async fn frame_get_by_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<button>outside</button>
+        <iframe srcdoc="<button>inside</button><img alt='logo'><span title='tip'>t</span>"></iframe>"#,
+        Default::default()
+    ));
+    let frame = p.main_frame().child_frames().unwrap().remove(0);
+    let button = frame.get_by_role("button", Default::default()).unwrap();
+    assert_eq!(done!(button.count()), 1);
+    assert_eq!(done!(button.text_content(None)).as_deref(), Some("inside"));
+    assert_eq!(done!(frame.get_by_text("inside").unwrap().count()), 1);
+    assert_eq!(done!(frame.get_by_text("outside").unwrap().count()), 0);
+    assert_eq!(done!(frame.get_by_alt_text("logo").unwrap().count()), 1);
+    assert_eq!(done!(frame.get_by_title("tip").unwrap().count()), 1);
+    assert_eq!(done!(p.get_by_title("tip").unwrap().count()), 0);
+    close(&p).await;
+}
+
+async fn query_selector_strict_should_work(c: &BrowserContext) {
+    use playwright::Error;
+    let p = new(c).await;
+    done!(p.set_content("<p>a</p><p>b</p><span>c</span>", Default::default()));
+    match p.query_selector_strict("p").await {
+        Err(Error::AmbiguousMatch { count, .. }) => assert_eq!(count, 2),
+        r => panic!("expected AmbiguousMatch, got {:?}", r.map(|e| e.is_some())),
+    }
+    assert!(done!(p.query_selector_strict("span")).is_some());
+    assert!(done!(p.query_selector_strict("div")).is_none());
+    close(&p).await;
+}
+
+async fn element_handle_evaluate_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(r#"<input value="v">"#, Default::default()));
+    let input = done!(p.query_selector("input")).unwrap();
+    let r#type: String = done!(input.eval("el => el.type"));
+    assert_eq!(r#type, "text");
+    let value: String = done!(input.evaluate("(el, suffix) => el.value + suffix", Some("!")));
+    assert_eq!(value, "v!");
+    let mut tag = done!(input.evaluate_handle("(el, _) => el.tagName", None::<()>));
+    assert_eq!(done!(tag.json_value::<String>()), "INPUT");
+    close(&p).await;
+}
+
+async fn evaluate_with_timeout_should_work(c: &BrowserContext) {
+    use playwright::{api::NoArg, Error};
+    use std::time::{Duration, Instant};
+    let p = new(c).await;
+    let sum: i32 = done!(p.evaluate_with_timeout("([a, b]) => a + b", (1, 2), 1000.));
+    assert_eq!(sum, 3);
+    let start = Instant::now();
+    let r = p
+        .evaluate_with_timeout::<_, ()>("() => { while (true) {} }", NoArg, 500.)
+        .await;
+    assert!(matches!(r, Err(Error::Timeout { .. })), "{:?}", r);
+    assert!(start.elapsed() < Duration::from_secs(1));
+    // the page is still running the loop, so skip beforeunload which would wait for it
+    done!(p.close(Some(false)));
+    assert!(p.is_closed());
+}
+
+async fn locator_fill_should_select_option(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content_builder(
+        r#"<select><option value="option1">One</option><option value="option2">Two</option></select>"#
+    )
+    .set_content());
+    let select = p.locator("select").unwrap();
+    done!(select.fill_builder("option2").fill());
+    assert_eq!(done!(select.input_value(None)), "option2");
+    // waits for the element like any other fill
+    let () = done!(p.eval(
+        "() => setTimeout(() => document.body.insertAdjacentHTML('beforeend', \
+         '<select id=late><option>a</option><option>b</option></select>'), 200)"
+    ));
+    let late = p.locator("#late").unwrap();
+    done!(late.fill_builder("b").fill());
+    assert_eq!(done!(late.input_value(None)), "b");
+    let r = p
+        .locator("select")
+        .unwrap()
+        .fill_builder("b")
+        .strict(true)
+        .fill()
+        .await;
+    assert!(
+        matches!(r, Err(playwright::Error::AmbiguousMatch { .. })),
+        "{:?}",
+        r
+    );
+    close(&p).await;
+}
+
+async fn add_init_script_with_arg_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    let flags = serde_json::json!({ "feature_x": true, "label": "</script>\"'" });
+    done!(p.add_init_script_with_arg("flags => { window.flags = flags; }", &flags));
+    done!(p.reload_builder().reload());
+    assert!(done!(p.eval::<bool>("() => window.flags.feature_x")));
+    let label: String = done!(p.eval("() => window.flags.label"));
+    assert_eq!(label, "</script>\"'");
+    close(&p).await;
+}
+
+async fn get_by_role_include_hidden_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<button aria-hidden="true">hidden</button>"#,
+        Default::default()
+    ));
+    let visible = p.get_by_role("button", Default::default()).unwrap();
+    assert_eq!(done!(visible.count()), 0);
+    let all = p
+        .get_by_role(
+            "button",
+            page::GetByRoleOptions {
+                include_hidden: Some(true),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+    assert_eq!(done!(all.count()), 1);
+    close(&p).await;
+}
+
+async fn frame_events_should_work(c: &BrowserContext, port: u16) {
+    use std::sync::{Arc, Mutex};
+    let p = new(c).await;
+    p.goto_builder(&super::url_static(port, "/empty.html"))
+        .goto()
+        .await
+        .unwrap();
+    let attached = Arc::new(Mutex::new(Vec::new()));
+    let a = attached.clone();
+    p.on_frame_attached(move |f| a.lock().unwrap().push(f))
+        .await
+        .unwrap();
+    let src = super::url_static(port, "/empty2.html");
+    let () = done!(p.evaluate(
+        "src => new Promise(resolve => {
+            const frame = document.createElement('iframe');
+            frame.src = src;
+            frame.onload = () => resolve();
+            document.body.appendChild(frame);
+        })",
+        &src
+    ));
+    let attached = attached.lock().unwrap();
+    assert_eq!(attached.len(), 1);
+    assert_eq!(attached[0].url().unwrap(), src);
+    drop(attached);
+    close(&p).await;
+}
+
+async fn close_run_before_unload_should_work(c: &BrowserContext) {
+    use std::sync::{Arc, Mutex};
+    const BEFORE_UNLOAD: &str = "<div>beforeunload</div>
+        <script>
+        window.addEventListener('beforeunload', event => {
+            event.preventDefault();
+            event.returnValue = '';
+        });
+        </script>";
+    async fn setup(c: &BrowserContext) -> (Page, Arc<Mutex<Vec<playwright::api::Dialog>>>) {
+        let p = new(c).await;
+        done!(p.set_content_builder(BEFORE_UNLOAD).set_content());
+        // beforeunload dialogs are shown only after a user gesture
+        done!(p.click_builder("body").click());
+        let dialogs = Arc::new(Mutex::new(Vec::new()));
+        let d = dialogs.clone();
+        done!(p.on_dialog(move |x| d.lock().unwrap().push(x)));
+        (p, dialogs)
+    }
+
+    let (p, dialogs) = setup(c).await;
+    done!(p.close(Some(true)));
+    let mut dialog = None;
+    for _ in 0..10 {
+        dialog = dialogs.lock().unwrap().pop();
+        if dialog.is_some() {
+            break;
+        }
+        playwright::utils::wait_for_timeout(100.).await;
+    }
+    let dialog = dialog.expect("beforeunload dialog");
+    assert_eq!(dialog.r#type().unwrap(), "beforeunload");
+    assert_eq!(dialog.page().unwrap().as_ref(), Some(&p));
+    done!(dialog.accept(None));
+
+    let (p, dialogs) = setup(c).await;
+    done!(p.close(Some(false)));
+    playwright::utils::wait_for_timeout(300.).await;
+    assert!(dialogs.lock().unwrap().is_empty());
+}
+
+async fn route_should_work(c: &BrowserContext, port: u16) {
+    let p = new(c).await;
+    p.route("**/data.json", |route| {
+        super::spawn(async move {
+            let response = route.fetch_builder().fetch().await.unwrap();
+            let mut json: serde_json::Value = response.json().unwrap();
+            json["name"] = "bar".into();
+            let body = json.to_string();
+            route
+                .fulfill_builder(&body, false)
+                .await
+                .response(&response)
+                .fulfill()
+                .await
+                .unwrap();
+        });
+    })
+    .await
+    .unwrap();
+    p.route("**/empty2.html", |route| {
+        super::spawn(async move { route.abort(None).await.unwrap() });
+    })
+    .await
+    .unwrap();
+    let url = super::url_static(port, "/empty.html");
+    p.goto_builder(&url).goto().await.unwrap();
+    let name: String =
+        done!(p.eval("() => fetch('/static/data.json').then(r => r.json()).then(j => j.name)"));
+    assert_eq!(name, "bar");
+    let aborted = p
+        .goto_builder(&super::url_static(port, "/empty2.html"))
+        .goto()
+        .await;
+    assert!(aborted.is_err());
+    p.unroute("**/data.json").await.unwrap();
+    let name: String =
+        done!(p.eval("() => fetch('/static/data.json').then(r => r.json()).then(j => j.name)"));
+    assert_eq!(name, "foo");
+    close(&p).await;
+}
+
+async fn request_post_data_should_work(c: &BrowserContext, port: u16) {
+    use std::sync::{Arc, Mutex};
+    let p = new(c).await;
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    {
+        let requests = requests.clone();
+        p.route("**/api/**", move |route| {
+            let request = route.request();
+            let is_json = request.url().unwrap().ends_with("/json");
+            requests.lock().unwrap().push(request);
+            super::spawn(async move {
+                let body = if is_json {
+                    r#"{"ok":true}"#
+                } else {
+                    "not json"
+                };
+                route
+                    .fulfill_builder(body, false)
+                    .await
+                    .fulfill()
+                    .await
+                    .unwrap();
+            });
+        })
+        .await
+        .unwrap();
+    }
+    let origin = super::url_static(port, "/empty.html");
+    p.goto_builder(&origin).goto().await.unwrap();
+    let () = done!(p.eval(
+        r#"async () => {
+            await fetch('/api/binary', { method: 'POST', body: new Uint8Array([0, 255, 1]) });
+            await fetch('/api/json', { method: 'POST', body: JSON.stringify({ a: 1, b: "x" }) });
+        }"#
+    ));
+    let (binary, json) = {
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        (requests[0].clone(), requests[1].clone())
+    };
+    assert_eq!(done!(binary.post_data_buffer()), Some(vec![0u8, 255, 1]));
+    assert!(binary.post_data_json::<serde_json::Value>().is_err());
+    assert_eq!(
+        json.post_data_json::<serde_json::Value>().unwrap(),
+        Some(serde_json::json!({ "a": 1, "b": "x" }))
+    );
+    let response = done!(p.goto_builder(&super::url_static(port, "/api/json")).goto()).unwrap();
+    let body: serde_json::Value = done!(response.json());
+    assert_eq!(body, serde_json::json!({ "ok": true }));
+    let response = done!(p.goto_builder(&super::url_static(port, "/api/text")).goto()).unwrap();
+    assert!(response.json::<serde_json::Value>().await.is_err());
+    close(&p).await;
+}
+
 async fn set_extra_http_headers(c: &BrowserContext, port: u16) {
     let p = new(c).await;
     p.set_extra_http_headers(vec![("hoge".into(), "hoge".into())])
@@ -513,6 +1437,41 @@ async fn set_extra_http_headers(c: &BrowserContext, port: u16) {
     close(&p).await;
 }
 
+async fn response_server_addr_should_work(c: &BrowserContext, port: u16) {
+    let p = new(c).await;
+    let response = done!(p
+        .goto_builder(&super::url_static(port, "/empty.html"))
+        .goto())
+    .unwrap();
+    let addr = done!(response.server_addr()).unwrap();
+    // the test server only listens on IPv4 loopback
+    assert_eq!(addr.ip_address, "127.0.0.1");
+    assert_eq!(addr.port, port);
+    assert_eq!(done!(response.security_details()), None);
+    close(&p).await;
+}
+
+async fn goto_referer_should_work(c: &BrowserContext, port: u16) {
+    use playwright::api::DocumentLoadState;
+    let p = new(c).await;
+    let url = super::url_static(port, "/empty.html");
+    let referer = "http://google.com/";
+    let (maybe_request, response) = tokio::join!(
+        p.expect_event(page::EventType::Request),
+        p.goto_builder(&url)
+            .referer(referer)
+            .wait_until(DocumentLoadState::DomContentLoaded)
+            .goto()
+    );
+    response.unwrap();
+    let req = match maybe_request.unwrap() {
+        page::Event::Request(req) => req,
+        _ => unreachable!(),
+    };
+    assert_eq!(req.headers().unwrap().get("referer").unwrap(), referer);
+    close(&p).await;
+}
+
 async fn query_selector_and_eval(c: &BrowserContext) {
     let p = new(c).await;
     p.set_content_builder(r#"<div><h1>foo</h1><div class="foo">bar</div></div>"#)
@@ -570,38 +1529,686 @@ async fn query_selector_and_eval(c: &BrowserContext) {
     close(&p).await;
 }
 
-// async fn file_chooser(c: &BrowserContext, port: u16) {
-//    let p = new(c).await;
-//    let url = super::url_static(port, "/form.html");
-//    p.goto_builder(&url).goto().await.unwrap();
-//    let (maybe_file_chooser, _) = tokio::join!(
-//        p.expect_event(page::EventType::FileChooser),
-//        p.click_builder("input[type=file]").click()
-//    );
-//    let file_chooser = match maybe_file_chooser.unwrap() {
-//        page::Event::FileChooser(file_chooser) => file_chooser,
-//        _ => unreachable!()
-//    };
-//    assert_eq!(file_chooser.page(), p);
-//    assert!(file_chooser.is_multiple());
-//    assert_eq!(
-//        file_chooser.element(),
-//        p.query_selector("input[type=file]").await.unwrap().unwrap()
-//    );
-//    file_chooser
-//        .set_input_files_builder(File {
-//            name: "a".into(),
-//            mime: "text/plain".into(),
-//            buffer: "a\n".into()
-//        })
-//        .add_file(File {
-//            name: "b".into(),
-//            mime: "text/plain".into(),
-//            buffer: "b\n".into()
-//        })
-//        .set_input_files()
-//        .await
-//        .unwrap();
-//    tokio::time::sleep(std::time::Duration::from_secs(5)).await;
-//    close(&p).await;
-//}
+async fn file_chooser(c: &BrowserContext, port: u16) {
+    use playwright::api::{file_chooser::SetFilesOptions, File};
+    let p = new(c).await;
+    let url = super::url_static(port, "/form.html");
+    p.goto_builder(&url).goto().await.unwrap();
+    let file_chooser = p
+        .wait_for_file_chooser(p.click_builder("input[type=file]").click(), None)
+        .await
+        .unwrap();
+    assert_eq!(file_chooser.page(), p);
+    assert!(file_chooser.is_multiple());
+    let files = vec![
+        File::new("a.txt".into(), "text/plain".into(), b"a\n"),
+        File::new("b.txt".into(), "text/plain".into(), b"b\n"),
+    ];
+    file_chooser
+        .set_files(files, SetFilesOptions::default())
+        .await
+        .unwrap();
+    let len: i32 = done!(p.eval("() => document.querySelector('input[type=file]').files.length"));
+    assert_eq!(len, 2);
+    close(&p).await;
+}
+
+async fn locator_trial_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<button onclick="window.clicked = true">b</button><input type="checkbox">"#,
+        Default::default()
+    ));
+    let button = p.locator("button").unwrap();
+    done!(button.click_builder().trial(true).click());
+    done!(button.dblclick_builder().trial(true).dblclick());
+    let clicked: bool = done!(p.eval("() => window.clicked === true"));
+    assert!(!clicked);
+    let checkbox = p.locator("input").unwrap();
+    done!(checkbox.check_builder().trial(true).check());
+    assert!(!done!(checkbox.is_checked(None)));
+    close(&p).await;
+}
+
+async fn locator_hover_trial_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<span id="trigger" onmouseover="tooltip.hidden = false">?</span>
+        <div id="tooltip" hidden>help</div>"#,
+        Default::default()
+    ));
+    let trigger = p.locator("#trigger").unwrap();
+    let tooltip = p.locator("#tooltip").unwrap();
+    done!(trigger
+        .hover_builder()
+        .trial(true)
+        .no_wait_after(true)
+        .hover());
+    assert!(!done!(tooltip.is_visible(None)));
+    done!(trigger.hover_builder().hover());
+    assert!(done!(tooltip.is_visible(None)));
+    close(&p).await;
+}
+
+async fn locator_hover_trial_should_not_open_dropdown(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<style>
+            .dropdown { display: none; }
+            .item:hover .dropdown { display: block; }
+        </style>
+        <nav><ul>
+            <li class="item">Products
+                <ul class="dropdown"><li>Item</li></ul>
+            </li>
+        </ul></nav>"#,
+        Default::default()
+    ));
+    let item = p.locator(".item").unwrap();
+    let dropdown = p.locator(".dropdown").unwrap();
+    done!(item.hover_builder().trial(true).hover());
+    assert!(!done!(dropdown.is_visible(None)));
+    done!(item.hover_builder().hover());
+    assert!(done!(dropdown.is_visible(None)));
+    close(&p).await;
+}
+
+async fn locator_check_strict_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<input id="text" type="text"><input id="checkbox" type="checkbox">"#,
+        Default::default()
+    ));
+    let text = p.locator("#text").unwrap();
+    let err = text.check_builder().strict(true).check().await.unwrap_err();
+    assert!(matches!(err, playwright::Error::ElementNotCheckable { .. }));
+    let err = text
+        .uncheck_builder()
+        .strict(true)
+        .uncheck()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, playwright::Error::ElementNotCheckable { .. }));
+    let checkbox = p.locator("#checkbox").unwrap();
+    done!(checkbox.check_builder().strict(true).check());
+    assert!(done!(checkbox.is_checked(None)));
+    close(&p).await;
+}
+
+async fn locator_strict_actions_should_work(c: &BrowserContext) {
+    use playwright::Error;
+    let p = new(c).await;
+    done!(p.set_content(r#"<input id="a"><input id="b">"#, Default::default()));
+    let inputs = p.locator("input").unwrap();
+    let assert_ambiguous = |r: Result<(), Error>| match r {
+        Err(Error::AmbiguousMatch { count, .. }) => assert_eq!(count, 2),
+        r => panic!("expected AmbiguousMatch, got {:?}", r),
+    };
+    assert_ambiguous(inputs.fill_builder("x").strict(true).fill().await);
+    assert_ambiguous(inputs.click_builder().strict(true).click().await);
+    assert_ambiguous(inputs.hover_builder().strict(true).hover().await);
+    assert_ambiguous(inputs.press_builder("a").strict(true).press().await);
+    assert_ambiguous(inputs.type_text_builder("a").strict(true).type_text().await);
+    // without strict the first match is used
+    done!(inputs.fill_builder("x").fill());
+    let first = p.locator("#a").unwrap();
+    assert_eq!(done!(first.input_value(None)), "x");
+    close(&p).await;
+}
+
+async fn locator_inner_text_and_html_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content("<p><b>bold</b> normal</p>", Default::default()));
+    let paragraph = p.locator("p").unwrap();
+    assert_eq!(done!(paragraph.inner_html(None)), "<b>bold</b> normal");
+    assert_eq!(done!(paragraph.inner_text(None)), "bold normal");
+    let bold = p.locator("p > b").unwrap();
+    assert_eq!(done!(bold.inner_text(None)), "bold");
+    close(&p).await;
+}
+
+async fn locator_nth_out_of_bounds_should_fail(c: &BrowserContext) {
+    use playwright::{api::File, Error};
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<input type="file"><input value="a">"#,
+        Default::default()
+    ));
+    let missing = done!(p.locator("input").unwrap().nth(5));
+    let not_found = |e: Error| assert!(matches!(e, Error::ElementNotFound { .. }), "{:?}", e);
+    not_found(
+        missing
+            .get_attribute("value", Some(100.))
+            .await
+            .unwrap_err(),
+    );
+    not_found(missing.text_content(Some(100.)).await.unwrap_err());
+    not_found(missing.input_value(Some(100.)).await.unwrap_err());
+    not_found(
+        missing
+            .fill_builder("x")
+            .timeout(100.)
+            .fill()
+            .await
+            .unwrap_err(),
+    );
+    let file = File::new("a.txt".into(), "text/plain".into(), b"a");
+    not_found(
+        missing
+            .set_input_files_builder(file)
+            .timeout(100.)
+            .set_input_files()
+            .await
+            .unwrap_err(),
+    );
+    close(&p).await;
+}
+
+async fn locator_select_option_element_handles_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<select>
+            <option value="red">Red</option>
+            <option value="green">Green</option>
+            <option value="blue">Blue</option>
+        </select>"#,
+        Default::default()
+    ));
+    let mut options = done!(p.query_selector_all("option"));
+    let blue = options.remove(2);
+    let select = p.locator("select").unwrap();
+    let selected = done!(select
+        .select_option_builder()
+        .element_handles(vec![blue])
+        .select_option());
+    assert_eq!(selected, vec!["blue".to_owned()]);
+    assert_eq!(done!(select.input_value(None)), "blue");
+    close(&p).await;
+}
+
+async fn locator_select_all_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<select multiple>
+            <option value="red">Red</option>
+            <option value="green">Green</option>
+            <option value="blue">Blue</option>
+        </select>"#,
+        Default::default()
+    ));
+    let select = p.locator("select").unwrap();
+    let selected = done!(select.select_option_builder().select_all().select_option());
+    assert_eq!(selected, vec!["red", "green", "blue"]);
+    let count: usize =
+        done!(p.eval("() => document.querySelector('select').selectedOptions.length"));
+    assert_eq!(count, 3);
+    close(&p).await;
+}
+
+async fn locator_input_values_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<select id="single">
+            <option value="red">Red</option>
+            <option value="green" selected>Green</option>
+        </select>
+        <select id="multi" multiple>
+            <option value="red" selected>Red</option>
+            <option value="green">Green</option>
+            <option value="blue" selected>Blue</option>
+        </select>
+        <input value="text">"#,
+        Default::default()
+    ));
+    let single = p.locator("#single").unwrap();
+    assert_eq!(done!(single.input_value(None)), "green");
+    assert_eq!(done!(single.input_values(None)), vec!["green"]);
+    let multi = p.locator("#multi").unwrap();
+    assert_eq!(done!(multi.input_value(None)), "red");
+    assert_eq!(done!(multi.input_values(None)), vec!["red", "blue"]);
+    let input = p.locator("input").unwrap();
+    assert_eq!(done!(input.input_values(None)), vec!["text"]);
+    close(&p).await;
+}
+
+async fn xpath_fallback_should_work(c: &BrowserContext) {
+    use playwright::api::XPathFallbackMode;
+    let p = new(c).await;
+    done!(p.set_content(
+        "<h1>title</h1><h2>subtitle</h2><p>text</p>",
+        Default::default()
+    ));
+    // a union of three clauses takes the fallback
+    let union = p.locator("xpath=//h1 | //h2 | //p").unwrap();
+    assert_eq!(done!(union.text_content(None)).as_deref(), Some("title"));
+    let err = union
+        .with_xpath_fallback(XPathFallbackMode::Error)
+        .text_content(None)
+        .await;
+    assert!(matches!(
+        err,
+        Err(playwright::Error::XPathFallback { selector }) if selector == "xpath=//h1 | //h2 | //p"
+    ));
+    close(&p).await;
+}
+
+async fn evaluate_file_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    let path = super::temp_dir().join("evaluate_file_sum.js");
+    std::fs::write(&path, "([a, b]) => a + b").unwrap();
+    let sum: i32 = done!(p.evaluate_file(&path, Some((1, 2))));
+    assert_eq!(sum, 3);
+    let err = p
+        .evaluate_file::<(), i32>(&path.with_extension("missing"), None)
+        .await;
+    assert!(matches!(err, Err(playwright::Error::Io(_))));
+    close(&p).await;
+}
+
+async fn screenshot_mask_should_work(c: &BrowserContext) {
+    use base64::Engine;
+    use playwright::api::FloatRect;
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<input type="password" value="secret"
+            style="position: absolute; left: 10px; top: 10px; width: 100px; height: 30px">"#,
+        Default::default()
+    ));
+    let field = p.locator("input").unwrap();
+    let png = done!(p
+        .screenshot_builder()
+        .mask(&[field])
+        .mask_color("#00FF00".into())
+        .clip(FloatRect {
+            x: 10.,
+            y: 10.,
+            width: 100.,
+            height: 30.
+        })
+        .screenshot());
+    let src = format!(
+        "data:image/png;base64,{}",
+        base64::engine::general_purpose::STANDARD.encode(&png)
+    );
+    // decode the image with a canvas of another page
+    let checker = new(c).await;
+    let masked: bool = done!(checker.evaluate(
+        r#"async src => {
+            const img = new Image();
+            img.src = src;
+            await img.decode();
+            const canvas = document.createElement('canvas');
+            canvas.width = img.width;
+            canvas.height = img.height;
+            const ctx = canvas.getContext('2d');
+            ctx.drawImage(img, 0, 0);
+            const { data } = ctx.getImageData(0, 0, img.width, img.height);
+            for (let i = 0; i < data.length; i += 4) {
+                if (data[i] !== 0 || data[i + 1] !== 255 || data[i + 2] !== 0) return false;
+            }
+            return true;
+        }"#,
+        src
+    ));
+    assert!(masked);
+    close(&checker).await;
+    close(&p).await;
+}
+
+async fn screenshot_full_page_max_height_should_work(c: &BrowserContext) {
+    use base64::Engine;
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<body style="margin: 0">
+            <div style="height: 1500px; background: #FF0000"></div>
+            <div style="height: 1500px; width: 2000px; background: #0000FF"></div>
+        </body>"#,
+        Default::default()
+    ));
+    let png = done!(p
+        .screenshot_builder()
+        .full_page(true)
+        .full_page_max_height(1000)
+        .screenshot());
+    // wider than the viewport
+    assert_eq!(png.width, Some(2000));
+    assert_eq!(png.height, Some(3000));
+    let scroll_y: f64 = done!(p.eval("() => window.scrollY"));
+    assert_eq!(scroll_y, 0.);
+    let src = format!(
+        "data:image/png;base64,{}",
+        base64::engine::general_purpose::STANDARD.encode(&png)
+    );
+    let checker = new(c).await;
+    let colors: Vec<Vec<u8>> = done!(checker.evaluate(
+        r#"async src => {
+            const img = new Image();
+            img.src = src;
+            await img.decode();
+            const canvas = document.createElement('canvas');
+            canvas.width = img.width;
+            canvas.height = img.height;
+            const ctx = canvas.getContext('2d');
+            ctx.drawImage(img, 0, 0);
+            return [[5, 10], [5, 1490], [5, 1510], [1990, 2990]]
+                .map(([x, y]) => Array.from(ctx.getImageData(x, y, 1, 1).data.slice(0, 3)));
+        }"#,
+        src
+    ));
+    assert_eq!(
+        colors,
+        vec![
+            vec![255, 0, 0],
+            vec![255, 0, 0],
+            vec![0, 0, 255],
+            vec![0, 0, 255]
+        ]
+    );
+    close(&checker).await;
+    close(&p).await;
+}
+
+async fn screenshot_animations_disabled_should_work(c: &BrowserContext) {
+    use playwright::api::ScreenshotAnimations;
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<style>
+            @keyframes spin { from { transform: rotate(0deg); } to { transform: rotate(360deg); } }
+            div { width: 50px; height: 50px; background: red; animation: spin 2s linear infinite; }
+        </style>
+        <div></div>"#,
+        Default::default()
+    ));
+    let first = done!(p
+        .screenshot_builder()
+        .animations(ScreenshotAnimations::Disabled)
+        .screenshot());
+    playwright::utils::wait_for_timeout(1000.).await;
+    let second = done!(p
+        .screenshot_builder()
+        .animations(ScreenshotAnimations::Disabled)
+        .screenshot());
+    assert_eq!(first, second);
+    close(&p).await;
+}
+
+async fn page_error_should_work(c: &BrowserContext, port: u16) {
+    use std::sync::{Arc, Mutex};
+    let p = new(c).await;
+    done!(p.add_init_script(r#"setTimeout(() => { throw new Error("test error"); }, 50)"#));
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let e = errors.clone();
+    done!(p.on_page_error(move |x| e.lock().unwrap().push(x)));
+    done!(p
+        .goto_builder(&super::url_static(port, "/empty.html"))
+        .goto());
+    let mut error = None;
+    for _ in 0..10 {
+        error = errors.lock().unwrap().pop();
+        if error.is_some() {
+            break;
+        }
+        playwright::utils::wait_for_timeout(100.).await;
+    }
+    let error = error.expect("pageerror");
+    assert_eq!(error.name, "Error");
+    assert_eq!(error.message, "test error");
+    assert!(error.stack.is_some());
+    close(&p).await;
+}
+
+async fn crash_should_work(c: &BrowserContext) {
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
+    let p = new(c).await;
+    let crashed = Arc::new(AtomicBool::new(false));
+    let flag = crashed.clone();
+    done!(p.on_crash(move || flag.store(true, Ordering::SeqCst)));
+    assert!(!p.is_crashed());
+    let _ = p.goto_builder("chrome://crash").goto().await;
+    for _ in 0..20 {
+        if p.is_crashed() {
+            break;
+        }
+        playwright::utils::wait_for_timeout(100.).await;
+    }
+    assert!(p.is_crashed());
+    assert!(crashed.load(Ordering::SeqCst));
+    let err = p.eval::<i32>("() => 1").await.unwrap_err();
+    assert!(matches!(*err, playwright::Error::PageCrashed), "{:?}", err);
+    let err = p.bring_to_front().await.unwrap_err();
+    assert!(matches!(*err, playwright::Error::PageCrashed), "{:?}", err);
+    close(&p).await;
+}
+
+async fn protocol_error_should_name_method(c: &BrowserContext) {
+    let p = new(c).await;
+    let err = p
+        .click_builder("#missing")
+        .timeout(10.)
+        .click()
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string().starts_with("protocol error in 'click': "),
+        "{}",
+        err
+    );
+    match &*err {
+        playwright::Error::ProtocolError { method, .. } => assert_eq!(method, "click"),
+        e => panic!("{:?}", e),
+    }
+    close(&p).await;
+}
+
+async fn error_variants_should_work(c: &BrowserContext) {
+    use playwright::Error;
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<button hidden>hidden</button><li>a</li><li>b</li><p>p</p>"#,
+        Default::default()
+    ));
+    let missing = p.locator("#missing").unwrap();
+    match missing.click_builder().timeout(10.).click().await {
+        Err(Error::ElementNotFound { selector }) => assert_eq!(selector, "#missing"),
+        x => panic!("{:?}", x),
+    }
+    let hidden = p.locator("button").unwrap();
+    match hidden.click_builder().timeout(100.).click().await {
+        Err(Error::Timeout { .. }) => {}
+        x => panic!("{:?}", x),
+    }
+    let li = p.locator("li").unwrap();
+    match li.click_builder().click().await {
+        Err(Error::AmbiguousMatch { count, .. }) => assert_eq!(count, 2),
+        x => panic!("{:?}", x),
+    }
+    let handle = done!(p.query_selector("p")).unwrap();
+    done!(p.eval::<()>("() => { document.querySelector('p').remove(); }"));
+    let err = handle.click_builder().click().await.unwrap_err();
+    assert!(matches!(*err, Error::ElementDetached), "{:?}", err);
+    let err = p
+        .goto_builder("http://localhost:1/")
+        .goto()
+        .await
+        .unwrap_err();
+    assert!(matches!(*err, Error::NavigationFailed { .. }), "{:?}", err);
+    close(&p).await;
+}
+
+async fn unroute_all_should_work(c: &BrowserContext, port: u16) {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+    let p = new(c).await;
+    let intercepted = Arc::new(AtomicUsize::new(0));
+    for url in &["**/empty.html", "**/data.json", "**/*"] {
+        let intercepted = intercepted.clone();
+        done!(p.route(url, move |route| {
+            intercepted.fetch_add(1, Ordering::SeqCst);
+            super::spawn(async move { route.abort(None).await.unwrap() });
+        }));
+    }
+    let url = super::url_static(port, "/empty.html");
+    assert!(p.goto_builder(&url).goto().await.is_err());
+    assert_eq!(intercepted.load(Ordering::SeqCst), 1);
+    done!(p.unroute_all(Default::default()));
+    done!(p.goto_builder(&url).goto());
+    let name: String =
+        done!(p.eval("() => fetch('/static/data.json').then(r => r.json()).then(j => j.name)"));
+    assert_eq!(name, "foo");
+    assert_eq!(intercepted.load(Ordering::SeqCst), 1);
+    close(&p).await;
+}
+
+async fn locator_clone_should_outlive_original(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<input><input><button onclick="this.textContent = 'clicked'">go</button>"#,
+        Default::default()
+    ));
+    let first = done!(p.locator("input").unwrap().nth(1));
+    let cloned = first.clone();
+    assert_eq!(cloned, first);
+    drop(first);
+    done!(cloned.fill_builder("second").fill());
+    assert_eq!(done!(cloned.input_value(None)), "second");
+    let button = p.locator("button").unwrap();
+    let cloned = button.clone();
+    drop(button);
+    done!(cloned.click_builder().click());
+    assert_eq!(done!(cloned.text_content(None)), Some("clicked".into()));
+    close(&p).await;
+}
+
+async fn locator_client_side_fallbacks_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(
+        "<input><ul><li>a</li><li><b>b</b></li><li>c</li></ul>",
+        Default::default()
+    ));
+    let items = p.locator("li").unwrap();
+    assert_eq!(
+        done!(done!(items.first()).text_content(None)),
+        Some("a".into())
+    );
+    assert_eq!(
+        done!(done!(items.last()).text_content(None)),
+        Some("c".into())
+    );
+    let with_text = done!(items.filter_builder().has_text("c".into()).filter());
+    assert_eq!(done!(with_text.count()), 1);
+    let without_text = done!(items.filter_builder().has_not_text("c".into()).filter());
+    assert_eq!(done!(without_text.count()), 2);
+    let with_bold = done!(items.filter_builder().has("b".into()).filter());
+    assert_eq!(done!(with_bold.text_content(None)), Some("b".into()));
+    let without_bold = done!(items.filter_builder().has_not("b".into()).filter());
+    assert_eq!(done!(without_bold.count()), 2);
+    let bold = p.locator("b").unwrap();
+    let with_bold = done!(items.filter_builder().has_locator(&bold).filter());
+    assert_eq!(done!(with_bold.count()), 1);
+    assert_eq!(done!(with_bold.text_content(None)), Some("b".into()));
+    let without_bold = done!(items.filter_builder().has_not_locator(&bold).filter());
+    assert_eq!(done!(without_bold.count()), 2);
+    // the builder keeps the inner locator alive until the filter is applied
+    let builder = items.filter_builder().has_locator(&p.locator("b").unwrap());
+    let with_bold = done!(builder.filter());
+    assert_eq!(done!(with_bold.count()), 1);
+    let both = items
+        .filter_builder()
+        .has("b".into())
+        .has_locator(&bold)
+        .filter()
+        .await;
+    assert!(matches!(
+        both,
+        Err(playwright::Error::InvalidArgument { .. })
+    ));
+    let input = p.locator("input").unwrap();
+    done!(input.focus(None));
+    assert!(done!(p.eval::<bool>(
+        "() => document.activeElement.tagName === 'INPUT'"
+    )));
+    done!(input.blur(None));
+    assert!(done!(
+        p.eval::<bool>("() => document.activeElement === document.body")
+    ));
+    close(&p).await;
+}
+
+async fn locator_type_text_should_work(c: &BrowserContext) {
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<input onkeydown="window.keys = (window.keys || []).concat(event.key)">"#,
+        Default::default()
+    ));
+    let input = p.locator("input").unwrap();
+    done!(input.type_text_builder("hello").delay(10.).type_text());
+    assert_eq!(done!(input.input_value(None)), "hello");
+    let keys: Vec<String> = done!(p.eval("() => window.keys"));
+    assert_eq!(keys, vec!["h", "e", "l", "l", "o"]);
+    close(&p).await;
+}
+
+async fn locator_press_should_wait_for_element(c: &BrowserContext) {
+    use playwright::api::Key;
+    let p = new(c).await;
+    let () = done!(p.eval(
+        r#"() => {
+            setTimeout(() => (document.body.innerHTML = '<input id="late">'), 100);
+        }"#
+    ));
+    let input = p.locator("#late").unwrap();
+    done!(input.press_builder("a").press());
+    done!(input.press_builder(Key::END).press());
+    done!(input.press_sequentially_builder("bc").press_sequentially());
+    let () = done!(p.eval(
+        r#"() => {
+            setTimeout(() => document.body.insertAdjacentHTML('beforeend', '<textarea>'), 100);
+        }"#
+    ));
+    let textarea = p.locator("textarea").unwrap();
+    done!(textarea.type_text_builder("bc").type_text());
+    assert_eq!(done!(input.input_value(None)), "abc");
+    assert_eq!(done!(textarea.input_value(None)), "bc");
+    close(&p).await;
+}
+
+async fn screenshot_clip_locator_should_work(c: &BrowserContext) {
+    use playwright::{api::FloatRect, Error};
+    let p = new(c).await;
+    done!(p.set_content(
+        r#"<header style="position: absolute; left: 20px; top: 30px; width: 200px; height: 50px">
+            title
+        </header>"#,
+        Default::default()
+    ));
+    let header = p.locator("header").unwrap();
+    let png = done!(p.screenshot_builder().clip_locator(&header).screenshot());
+    assert_eq!((png.width, png.height), (Some(200), Some(50)));
+    let missing = p.locator("footer").unwrap();
+    let err = p
+        .screenshot_builder()
+        .clip_locator(&missing)
+        .screenshot()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::ElementNotFound { .. }), "{:?}", err);
+    let err = p
+        .screenshot_builder()
+        .clip_locator(&header)
+        .clip(FloatRect {
+            x: 0.,
+            y: 0.,
+            width: 10.,
+            height: 10.,
+        })
+        .screenshot()
+        .await
+        .unwrap_err();
+    assert!(matches!(err, Error::InvalidArgument { .. }), "{:?}", err);
+    close(&p).await;
+}
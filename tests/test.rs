@@ -107,12 +107,42 @@ async fn start_test_server(port: u16) {
     let download = warp::path("download")
         .and(warp::fs::dir("tests/server"))
         .with(warp::reply::with::headers(headers));
-    let route = r#static.or(download);
+    let auth = warp::path("auth")
+        .and(warp::header::optional::<String>("authorization"))
+        .map(|authorization: Option<String>| {
+            let builder = warp::http::Response::builder();
+            let res = if authorization.as_deref() == Some(BASIC_AUTH) {
+                builder.status(200).body("authorized")
+            } else {
+                builder
+                    .status(401)
+                    .header("WWW-Authenticate", "Basic realm=\"test\"")
+                    .body("unauthorized")
+            };
+            res.unwrap()
+        });
+    let route = r#static.or(download).or(auth);
     spawn(async move {
         warp::serve(route).run(([127, 0, 0, 1], port)).await;
     });
 }
 
+/// Serves `tests/server` over HTTPS with a self-signed certificate for `localhost`
+#[cfg(any(feature = "rt-tokio", feature = "rt-actix"))]
+async fn start_https_test_server(port: u16) {
+    use warp::Filter;
+    let rcgen::CertifiedKey { cert, key_pair } =
+        rcgen::generate_simple_self_signed(vec!["localhost".into()]).unwrap();
+    let route = warp::path("static").and(warp::fs::dir("tests/server"));
+    let server = warp::serve(route)
+        .tls()
+        .cert(cert.pem())
+        .key(key_pair.serialize_pem());
+    spawn(async move {
+        server.run(([127, 0, 0, 1], port)).await;
+    });
+}
+
 #[cfg(feature = "rt-async-std")]
 async fn start_test_server(port: u16) {
     use tide::Server;
@@ -126,11 +156,29 @@ async fn start_test_server(port: u16) {
         }))
         .serve_dir("tests/server/")
         .unwrap();
+    app.at("/auth").get(|req: tide::Request<()>| async move {
+        let authorized = req
+            .header("Authorization")
+            .map(|v| v.last().as_str() == BASIC_AUTH)
+            .unwrap_or_default();
+        let res = if authorized {
+            tide::Response::builder(200).body("authorized").build()
+        } else {
+            tide::Response::builder(401)
+                .header("WWW-Authenticate", "Basic realm=\"test\"")
+                .body("unauthorized")
+                .build()
+        };
+        Ok(res)
+    });
     spawn(async move {
         app.listen(format!("127.0.0.1:{}", port)).await.unwrap();
     });
 }
 
+/// `Authorization` header of user `user` with password `pass`
+const BASIC_AUTH: &str = "Basic dXNlcjpwYXNz";
+
 // XXX: non thread safe
 fn free_local_port() -> Option<u16> {
     let socket = std::net::SocketAddrV4::new(std::net::Ipv4Addr::LOCALHOST, 0);
@@ -148,6 +196,10 @@ fn url_download(port: u16, path: &str) -> String {
     format!("http://localhost:{}/download{}", port, path)
 }
 
+fn url_auth(port: u16) -> String {
+    format!("http://localhost:{}/auth", port)
+}
+
 fn origin(port: u16) -> String {
     format!("http://localhost:{}", port)
 }